@@ -1,5 +1,5 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, BytesN, Map};
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, xdr::ToXdr, Address, Env, BytesN, Symbol, Vec};
 
 #[derive(Clone)]
 #[contracttype]
@@ -13,7 +13,12 @@ pub struct Game {
     pub is_active: bool,
 }
 
-const GAMES: &str = "GAMES";
+/// Ledgers a proposed winner can be disputed before `finalize_game`.
+const DISPUTE_WINDOW_LEDGERS: u32 = 100;
+/// Default cap on concurrently active games.
+const DEFAULT_MAX_GAMES: u32 = 100;
+/// Maximum page size for `get_active_games`.
+const MAX_PAGE_SIZE: u32 = 50;
 
 #[contract]
 pub struct GameHubContract;
@@ -28,13 +33,11 @@ impl GameHubContract {
     ) -> BytesN<32> {
         player1.require_auth();
         
-        // Generate game ID from players and timestamp
+        // Generate game ID from players and timestamp: SHA-256 of their XDR
         let ledger = env.ledger();
         let timestamp = ledger.timestamp();
-        
-        let mut game_id_data = [0u8; 32];
-        game_id_data[0..8].copy_from_slice(&timestamp.to_be_bytes());
-        let game_id = BytesN::from_array(&env, &game_id_data);
+        let seed = (timestamp, player1.clone(), player2.clone()).to_xdr(&env);
+        let game_id: BytesN<32> = env.crypto().sha256(&seed).into();
         
         let game = Game {
             game_id: game_id.clone(),
@@ -46,33 +49,185 @@ impl GameHubContract {
             is_active: true,
         };
         
-        let mut games: Map<BytesN<32>, Game> = env
-            .storage()
-            .persistent()
-            .get(&GAMES)
-            .unwrap_or(Map::new(&env));
+        assert!(
+            Self::active_ids(&env).len() < Self::get_max_games(env.clone()),
+            "Active game limit reached"
+        );
+        // Same players in the same ledger would reuse the ID
+        let key = Self::game_key(&game_id);
+        assert!(!env.storage().persistent().has(&key), "Game already started in this ledger");
         
-        games.set(game_id.clone(), game);
-        env.storage().persistent().set(&GAMES, &games);
+        env.storage().persistent().set(&key, &game);
+
+        // Index of active game IDs, in start order
+        let mut active = Self::active_ids(&env);
+        active.push_back(game_id.clone());
+        env.storage().persistent().set(&symbol_short!("ACTIVE"), &active);
+        env.events().publish((symbol_short!("hub"), symbol_short!("start")), game);
         
         game_id
     }
     
-    /// End a game and record winner
+    /// End a game and record the winner immediately, bypassing the dispute
+    /// window. Admin only, and the only way to settle a disputed game;
+    /// players close games through `propose_winner` → `finalize_game`.
     pub fn end_game(
         env: Env,
+        admin: Address,
         game_id: BytesN<32>,
         winner: Address,
     ) {
+        Self::require_admin(&env, &admin);
+        assert!(Self::get_game(env.clone(), game_id.clone()).is_active, "Game is not active");
+        env.storage().persistent().remove(&(symbol_short!("PROPOSAL"), game_id.clone()));
+        env.storage().persistent().remove(&(symbol_short!("DISPUTED"), game_id.clone()));
+        Self::close_game(&env, game_id, winner);
+    }
+
+    /// Propose `winner` for a game, opening a dispute window of
+    /// DISPUTE_WINDOW_LEDGERS ledgers. The result only takes effect once
+    /// `finalize_game` is called after the window. A disputed game takes no
+    /// further proposals.
+    pub fn propose_winner(env: Env, game_id: BytesN<32>, winner: Address) {
         winner.require_auth();
-        
-        let mut games: Map<BytesN<32>, Game> = env
-            .storage()
-            .persistent()
-            .get(&GAMES)
-            .unwrap();
-        
-        let mut game = games.get(game_id.clone()).unwrap();
+
+        let game = Self::get_game(env.clone(), game_id.clone());
+        assert!(game.is_active, "Game is not active");
+        assert!(
+            !env.storage().persistent().has(&(symbol_short!("DISPUTED"), game_id.clone())),
+            "Game is disputed; only the admin can settle it"
+        );
+        assert!(
+            game.player1 == winner || game.player2 == winner,
+            "Winner must be a player in the game"
+        );
+        let key = (symbol_short!("PROPOSAL"), game_id.clone());
+        assert!(!env.storage().persistent().has(&key), "Winner already proposed");
+
+        env.storage().persistent().set(&key, &(winner.clone(), env.ledger().sequence()));
+        env.events().publish((symbol_short!("hub"), symbol_short!("propose")), (game_id, winner));
+    }
+
+    /// Dispute a proposed winner. Only a player in the game may dispute, and
+    /// only while the window is open; the proposal is discarded and the game
+    /// is marked disputed, so from then on only the admin's `end_game` can
+    /// settle it.
+    pub fn dispute_game(env: Env, game_id: BytesN<32>, player: Address) {
+        player.require_auth();
+
+        let game = Self::get_game(env.clone(), game_id.clone());
+        assert!(game.is_active, "Game is not active");
+        assert!(
+            game.player1 == player || game.player2 == player,
+            "Only a player in the game can dispute"
+        );
+        let key = (symbol_short!("PROPOSAL"), game_id.clone());
+        let (_, proposed_at): (Address, u32) = env.storage().persistent()
+            .get(&key)
+            .expect("No winner proposed");
+        assert!(
+            env.ledger().sequence() <= proposed_at.saturating_add(DISPUTE_WINDOW_LEDGERS),
+            "Dispute window has closed"
+        );
+
+        env.storage().persistent().remove(&key);
+        env.storage().persistent().set(&(symbol_short!("DISPUTED"), game_id.clone()), &true);
+        env.events().publish((symbol_short!("hub"), symbol_short!("dispute")), (game_id, player));
+    }
+
+    /// Record the proposed winner once the dispute window has elapsed
+    /// without a dispute. Anyone may call this.
+    pub fn finalize_game(env: Env, game_id: BytesN<32>) {
+        let key = (symbol_short!("PROPOSAL"), game_id.clone());
+        let (winner, proposed_at): (Address, u32) = env.storage().persistent()
+            .get(&key)
+            .expect("No winner proposed");
+        assert!(
+            env.ledger().sequence() > proposed_at.saturating_add(DISPUTE_WINDOW_LEDGERS),
+            "Dispute window still open"
+        );
+        assert!(Self::get_game(env.clone(), game_id.clone()).is_active, "Game is not active");
+
+        env.storage().persistent().remove(&key);
+        Self::close_game(&env, game_id, winner);
+    }
+    
+    /// Get game information
+    pub fn get_game(env: Env, game_id: BytesN<32>) -> Game {
+        env.storage().persistent()
+            .get(&Self::game_key(&game_id))
+            .expect("Game not found")
+    }
+    
+    /// Get active games `[start, start + limit)` in the order they started.
+    ///
+    /// `limit` is capped at MAX_PAGE_SIZE (50). Fewer than `limit` games
+    /// (possibly none) means the end has been reached.
+    pub fn get_active_games(env: Env, start: u32, limit: u32) -> Vec<Game> {
+        let active = Self::active_ids(&env);
+        let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(active.len());
+
+        let mut page = Vec::new(&env);
+        for i in start..end {
+            page.push_back(Self::get_game(env.clone(), active.get(i).unwrap()));
+        }
+        page
+    }
+
+    /// Configure the hub admin. Called once, right after deployment.
+    pub fn init(env: Env, admin: Address) {
+        assert!(!env.storage().instance().has(&symbol_short!("ADMIN")), "Already initialized");
+        admin.require_auth();
+        env.storage().instance().set(&symbol_short!("ADMIN"), &admin);
+    }
+
+    /// Hand the admin role to `new_admin`. Only the current admin can call this.
+    pub fn set_admin(env: Env, admin: Address, new_admin: Address) {
+        Self::require_admin(&env, &admin);
+        new_admin.require_auth();
+        env.storage().instance().set(&symbol_short!("ADMIN"), &new_admin);
+    }
+
+    /// Cap the number of concurrently active games. Ended games do not
+    /// count; `prune_ended_games` reclaims their storage.
+    pub fn set_max_games(env: Env, admin: Address, max: u32) {
+        Self::require_admin(&env, &admin);
+        assert!(max > 0, "Maximum must be positive");
+        env.storage().instance().set(&symbol_short!("MAX_GAMES"), &max);
+    }
+
+    /// Current cap on concurrently active games.
+    pub fn get_max_games(env: Env) -> u32 {
+        env.storage().instance().get(&symbol_short!("MAX_GAMES")).unwrap_or(DEFAULT_MAX_GAMES)
+    }
+
+    /// Remove the stored record of every ended game. Returns the number of
+    /// games removed; pruned games can no longer be fetched with `get_game`.
+    pub fn prune_ended_games(env: Env, admin: Address) -> u32 {
+        Self::require_admin(&env, &admin);
+        let ended = Self::ended_ids(&env);
+        for game_id in ended.iter() {
+            env.storage().persistent().remove(&Self::game_key(&game_id));
+        }
+        env.storage().persistent().remove(&symbol_short!("ENDED"));
+        let removed = ended.len();
+        env.events().publish((symbol_short!("hub"), symbol_short!("prune")), removed);
+        removed
+    }
+
+    /// Helper: require `admin` to be the configured hub admin and to authorize.
+    fn require_admin(env: &Env, admin: &Address) {
+        let stored: Address = env.storage().instance()
+            .get(&symbol_short!("ADMIN"))
+            .expect("Admin not configured");
+        assert!(stored == *admin, "Caller is not the admin");
+        admin.require_auth();
+    }
+
+    /// Helper: record `winner`, mark the game inactive and move it from
+    /// the active index to the ended one.
+    fn close_game(env: &Env, game_id: BytesN<32>, winner: Address) {
+        let mut game = Self::get_game(env.clone(), game_id.clone());
         
         // Verify winner is one of the players
         assert!(
@@ -85,18 +240,39 @@ impl GameHubContract {
         game.winner = Some(winner);
         game.is_active = false;
         
-        games.set(game_id, game);
-        env.storage().persistent().set(&GAMES, &games);
+        env.storage().persistent().set(&Self::game_key(&game_id), &game);
+
+        let mut active = Self::active_ids(env);
+        if let Some(i) = active.first_index_of(&game_id) {
+            active.remove(i);
+            env.storage().persistent().set(&symbol_short!("ACTIVE"), &active);
+        }
+        let mut ended = Self::ended_ids(env);
+        ended.push_back(game_id);
+        env.storage().persistent().set(&symbol_short!("ENDED"), &ended);
+        env.events().publish((symbol_short!("hub"), symbol_short!("end")), game);
     }
-    
-    /// Get game information
-    pub fn get_game(env: Env, game_id: BytesN<32>) -> Game {
-        let games: Map<BytesN<32>, Game> = env
-            .storage()
-            .persistent()
-            .get(&GAMES)
-            .unwrap();
-        
-        games.get(game_id).unwrap()
+
+    /// Helper: storage key for one game's record; each game has its own
+    /// entry, so no call loads every game.
+    fn game_key(game_id: &BytesN<32>) -> (Symbol, BytesN<32>) {
+        (symbol_short!("GAME"), game_id.clone())
+    }
+
+    /// Helper: IDs of games that have started and not yet ended.
+    fn active_ids(env: &Env) -> Vec<BytesN<32>> {
+        env.storage().persistent()
+            .get(&symbol_short!("ACTIVE"))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Helper: IDs of ended games whose records `prune_ended_games` has not
+    /// yet removed.
+    fn ended_ids(env: &Env) -> Vec<BytesN<32>> {
+        env.storage().persistent()
+            .get(&symbol_short!("ENDED"))
+            .unwrap_or(Vec::new(env))
     }
 }
+
+mod test;
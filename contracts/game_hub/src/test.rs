@@ -0,0 +1,277 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::{Address as _, Events, Ledger}, symbol_short, Address, Env, IntoVal, Symbol, TryFromVal, Val, Vec};
+
+fn setup(env: &Env) -> (GameHubContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, GameHubContract);
+    let client = GameHubContractClient::new(env, &contract_id);
+    (client, Address::generate(env), Address::generate(env))
+}
+
+/// Helper: the `Game` carried by the most recent event with the given hub topic.
+fn last_hub_event(env: &Env, action: Symbol) -> Game {
+    let topics: Vec<Val> = (symbol_short!("hub"), action).into_val(env);
+    let (_, _, data) = env.events().all().iter()
+        .filter(|(_, t, _)| *t == topics)
+        .last()
+        .expect("no hub event");
+    Game::try_from_val(env, &data).unwrap()
+}
+
+#[test]
+fn test_start_game_emits_event() {
+    let env = Env::default();
+    let (client, player1, player2) = setup(&env);
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+
+    let game_id = client.start_game(&player1, &player2);
+
+    let game = last_hub_event(&env, symbol_short!("start"));
+    assert_eq!(game.game_id, game_id);
+    assert_eq!(game.player1, player1);
+    assert_eq!(game.player2, player2);
+    assert_eq!(game.started_at, 1_000);
+    assert!(game.is_active);
+    assert!(game.winner.is_none());
+}
+
+#[test]
+fn test_end_game_emits_event() {
+    let env = Env::default();
+    let (client, player1, player2) = setup(&env);
+
+    let admin = Address::generate(&env);
+    client.init(&admin);
+    let game_id = client.start_game(&player1, &player2);
+    env.ledger().with_mut(|l| l.timestamp = 2_000);
+
+    // Players cannot skip the dispute window
+    assert!(client.try_end_game(&player2, &game_id, &player2).is_err());
+    client.end_game(&admin, &game_id, &player2);
+
+    let game = last_hub_event(&env, symbol_short!("end"));
+    assert_eq!(game.game_id, game_id);
+    assert_eq!(game.player1, player1);
+    assert_eq!(game.player2, player2);
+    assert_eq!(game.winner, Some(player2));
+    assert_eq!(game.ended_at, 2_000);
+    assert!(!game.is_active);
+}
+
+#[test]
+fn test_ended_game_leaves_active_list() {
+    let env = Env::default();
+    let (client, player1, player2) = setup(&env);
+    let admin = Address::generate(&env);
+    client.init(&admin);
+
+    let mut ids = Vec::new(&env);
+    for ts in [10, 20, 30] {
+        env.ledger().with_mut(|l| l.timestamp = ts);
+        ids.push_back(client.start_game(&player1, &player2));
+    }
+    assert_eq!(client.get_active_games(&0, &10).len(), 3);
+
+    client.end_game(&admin, &ids.get_unchecked(1), &player1);
+    let active = client.get_active_games(&0, &10);
+    assert_eq!(active.len(), 2);
+    assert_eq!(active.get(0).unwrap().game_id, ids.get_unchecked(0));
+    assert_eq!(active.get(1).unwrap().game_id, ids.get_unchecked(2));
+
+    // Paging
+    let second = client.get_active_games(&1, &1);
+    assert_eq!(second.len(), 1);
+    assert_eq!(second.get(0).unwrap().game_id, ids.get_unchecked(2));
+    assert_eq!(client.get_active_games(&2, &10).len(), 0);
+
+    // Ended games are still retrievable by ID
+    assert!(!client.get_game(&ids.get_unchecked(1)).is_active);
+}
+
+#[test]
+fn test_games_started_in_same_ledger_get_distinct_ids() {
+    let env = Env::default();
+    let (client, player1, player2) = setup(&env);
+    let player3 = Address::generate(&env);
+    env.ledger().with_mut(|l| l.timestamp = 10);
+
+    let first = client.start_game(&player1, &player2);
+    let second = client.start_game(&player1, &player3);
+    assert_ne!(first, second);
+    assert_eq!(client.get_active_games(&0, &10).len(), 2);
+
+    // The same pairing twice in one ledger would collide, so it is rejected
+    assert!(client.try_start_game(&player1, &player2).is_err());
+    assert_eq!(client.get_active_games(&0, &10).len(), 2);
+    assert_eq!(client.get_game(&first).player2, player2);
+}
+
+#[test]
+fn test_finalize_before_dispute_window_rejected() {
+    let env = Env::default();
+    let (client, player1, player2) = setup(&env);
+    let game_id = client.start_game(&player1, &player2);
+
+    env.ledger().with_mut(|l| l.sequence_number = 500);
+    client.propose_winner(&game_id, &player1);
+    assert!(client.try_propose_winner(&game_id, &player2).is_err());
+
+    env.ledger().with_mut(|l| l.sequence_number = 600);
+    assert!(client.try_finalize_game(&game_id).is_err());
+    assert!(client.get_game(&game_id).is_active);
+
+    env.ledger().with_mut(|l| l.sequence_number = 601);
+    client.finalize_game(&game_id);
+    let game = client.get_game(&game_id);
+    assert!(!game.is_active);
+    assert_eq!(game.winner, Some(player1));
+    assert_eq!(client.get_active_games(&0, &10).len(), 0);
+}
+
+#[test]
+fn test_dispute_blocks_finalization() {
+    let env = Env::default();
+    let (client, player1, player2) = setup(&env);
+    let game_id = client.start_game(&player1, &player2);
+
+    env.ledger().with_mut(|l| l.sequence_number = 500);
+    client.propose_winner(&game_id, &player1);
+
+    // Outsiders cannot dispute
+    assert!(client.try_dispute_game(&game_id, &Address::generate(&env)).is_err());
+
+    env.ledger().with_mut(|l| l.sequence_number = 550);
+    client.dispute_game(&game_id, &player2);
+
+    env.ledger().with_mut(|l| l.sequence_number = 700);
+    assert!(client.try_finalize_game(&game_id).is_err());
+    let game = client.get_game(&game_id);
+    assert!(game.is_active);
+    assert!(game.winner.is_none());
+}
+
+#[test]
+fn test_disputed_game_is_settled_by_admin_only() {
+    let env = Env::default();
+    let (client, player1, player2) = setup(&env);
+    let admin = Address::generate(&env);
+    client.init(&admin);
+    let game_id = client.start_game(&player1, &player2);
+
+    env.ledger().with_mut(|l| l.sequence_number = 500);
+    client.propose_winner(&game_id, &player1);
+    client.dispute_game(&game_id, &player2);
+
+    // No new proposal can reopen the dispute cycle
+    assert!(client.try_propose_winner(&game_id, &player1).is_err());
+    assert!(client.try_propose_winner(&game_id, &player2).is_err());
+    assert!(client.try_dispute_game(&game_id, &player1).is_err());
+
+    client.end_game(&admin, &game_id, &player2);
+    let game = client.get_game(&game_id);
+    assert!(!game.is_active);
+    assert_eq!(game.winner, Some(player2));
+}
+
+#[test]
+fn test_dispute_of_ended_game_rejected() {
+    let env = Env::default();
+    let (client, player1, player2) = setup(&env);
+    let admin = Address::generate(&env);
+    client.init(&admin);
+    let game_id = client.start_game(&player1, &player2);
+
+    env.ledger().with_mut(|l| l.sequence_number = 500);
+    client.propose_winner(&game_id, &player1);
+    client.end_game(&admin, &game_id, &player2);
+    assert!(client.try_dispute_game(&game_id, &player1).is_err());
+    assert_eq!(client.get_game(&game_id).winner, Some(player2));
+}
+
+#[test]
+fn test_dispute_after_window_rejected() {
+    let env = Env::default();
+    let (client, player1, player2) = setup(&env);
+    let game_id = client.start_game(&player1, &player2);
+
+    env.ledger().with_mut(|l| l.sequence_number = 500);
+    client.propose_winner(&game_id, &player1);
+    env.ledger().with_mut(|l| l.sequence_number = 601);
+    assert!(client.try_dispute_game(&game_id, &player2).is_err());
+}
+
+#[test]
+fn test_start_game_rejected_at_active_cap() {
+    let env = Env::default();
+    let (client, player1, player2) = setup(&env);
+    let admin = Address::generate(&env);
+    client.init(&admin);
+    client.set_max_games(&admin, &2);
+
+    env.ledger().with_mut(|l| l.timestamp = 10);
+    let first = client.start_game(&player1, &player2);
+    env.ledger().with_mut(|l| l.timestamp = 20);
+    client.start_game(&player1, &player2);
+
+    env.ledger().with_mut(|l| l.timestamp = 30);
+    assert!(client.try_start_game(&player1, &player2).is_err());
+
+    // Ending a game frees its slot; pruning is not needed
+    client.end_game(&admin, &first, &player1);
+    client.start_game(&player1, &player2);
+    assert_eq!(client.get_active_games(&0, &10).len(), 2);
+
+    assert_eq!(client.prune_ended_games(&admin), 1);
+    assert!(client.try_get_game(&first).is_err());
+}
+
+#[test]
+fn test_prune_keeps_active_games() {
+    let env = Env::default();
+    let (client, player1, player2) = setup(&env);
+    let admin = Address::generate(&env);
+    client.init(&admin);
+
+    env.ledger().with_mut(|l| l.timestamp = 10);
+    let ended = client.start_game(&player1, &player2);
+    env.ledger().with_mut(|l| l.timestamp = 20);
+    let active = client.start_game(&player1, &player2);
+    client.end_game(&admin, &ended, &player2);
+
+    assert_eq!(client.prune_ended_games(&admin), 1);
+    assert!(client.try_get_game(&ended).is_err());
+    assert!(client.get_game(&active).is_active);
+
+    // Already pruned games are not counted again
+    assert_eq!(client.prune_ended_games(&admin), 0);
+}
+
+#[test]
+fn test_admin_set_once_at_init() {
+    let env = Env::default();
+    let (client, _player1, _player2) = setup(&env);
+    let admin = Address::generate(&env);
+    let other = Address::generate(&env);
+    client.init(&admin);
+
+    assert!(client.try_init(&other).is_err());
+    assert!(client.try_set_admin(&other, &other).is_err());
+    assert!(client.try_prune_ended_games(&other).is_err());
+
+    client.set_admin(&admin, &other);
+    assert_eq!(client.prune_ended_games(&other), 0);
+    assert!(client.try_prune_ended_games(&admin).is_err());
+}
+
+#[test]
+fn test_prune_requires_admin() {
+    let env = Env::default();
+    let (client, _player1, _player2) = setup(&env);
+    let admin = Address::generate(&env);
+    client.init(&admin);
+
+    assert!(client.try_prune_ended_games(&Address::generate(&env)).is_err());
+    assert_eq!(client.prune_ended_games(&admin), 0);
+}
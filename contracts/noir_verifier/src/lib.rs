@@ -21,6 +21,8 @@
 //!   2. Card validity              — each card ∈ [0, 51], no duplicates
 //!   3. Rank range                 — claimed_rank ∈ [0, 9]
 //!   4. Attestation gate           — checks if SHA-256(proof) has a verified attestation
+//!   5. Game binding               — the attestation was recorded for the same game_id
+//!   6. Replay                     — optionally, the proof has not passed a verification before
 //!
 //! Commitment binding:
 //!   Commitment integrity is guaranteed by the Noir ZK circuit which checks
@@ -28,20 +30,45 @@
 //!   and/or bb verify confirm this proof was cryptographically verified.
 
 #![no_std]
+#![allow(clippy::too_many_arguments)]
 
 use soroban_sdk::{
-    contract, contractimpl, contracttype,
-    BytesN, Env, String, Symbol,
-    symbol_short, log,
+    contract, contracterror, contractimpl, contracttype, panic_with_error,
+    Address, Bytes, BytesN, Env, String, Symbol, Vec,
+    symbol_short, log, xdr::ToXdr,
 };
 
+/// `log!` at a given level; see `set_log_level`.
+macro_rules! log_at {
+    ($env:expr, $level:expr, $($arg:tt)*) => {
+        if cfg!(debug_assertions) && Self::log_level($env) >= $level {
+            log!($env, $($arg)*);
+        }
+    };
+}
+
 /// Result event emitted after verification
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct VerificationResult {
-    pub valid:        bool,
+    pub valid:               bool,
+    pub claimed_rank:        u32,
+    pub player:              BytesN<32>,
+    pub proof_hash:          BytesN<32>, // attestation lookup hash derived from the proof
+    pub attestation_present: bool,       // gate satisfied by a recorded attestation
+}
+
+/// One player's `verify_proof` arguments, for `verify_proofs_batch`
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ProofArgs {
+    pub hole_cards:   BytesN<2>,
+    pub salt:         BytesN<32>,
+    pub commitment:   BytesN<32>,
     pub claimed_rank: u32,
+    pub proof_bytes:  BytesN<128>,
     pub player:       BytesN<32>,
+    pub game_id:      BytesN<32>,
 }
 
 /// zkVerify attestation record — stored on Stellar as proof of external verification
@@ -51,18 +78,134 @@ pub struct ZkVerifyAttestation {
     pub attestation_id: String,     // zkVerify chain attestation ID
     pub proof_hash:     BytesN<32>, // SHA-256 of the full proof (links to proof_bytes)
     pub player:         BytesN<32>, // player identity
+    pub game_id:        BytesN<32>, // game the proof was generated for
     pub claimed_rank:   u32,        // hand rank that was verified
     pub verified:       bool,       // zkVerify verification result
     pub block_hash:     String,     // zkVerify block containing the attestation
+    pub proof_system:   Symbol,     // proving scheme, e.g. `ultrahonk`, `groth16`, `risc0`
+    pub prev_hash:      BytesN<32>, // digest of the record at index - 1 (zero for index 0)
+}
+
+/// Failure codes returned by the verifier. Callers such as poker_game can
+/// tell a bad hand apart from a missing attestation when a call fails.
+#[contracterror]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum VerifierError {
+    ProofAllZero          = 1,  // proof bytes were never generated
+    CardOutOfRange        = 2,  // a card index outside [0, 51]
+    DuplicateCards        = 3,
+    RankOutOfRange        = 4,  // a rank outside [0, 9]
+    NoAttestation         = 5,  // Hard gate: no attestation recorded for the proof
+    AttestationUnverified = 6,  // Hard gate: attestation recorded with verified=false
+    VerificationFailed    = 7,
+    UnsupportedHoleCards  = 8,
+    WrongHoleCardCount    = 9,
+    InvalidBoard          = 10, // community card count outside the allowed range
+    RankMismatch          = 11, // claimed rank is not the best hand on the board
+    RankInconsistent      = 12, // claimed rank impossible with the revealed cards
+    MalformedBlockHash    = 13,
+    ProofHashMismatch     = 14, // recorded hash is not SHA-256(full_proof)
+    AlreadyRecorded       = 15,
+    AttestationNotFound   = 16,
+    AdminNotConfigured    = 17,
+    NotAdmin              = 18,
+    WrongGame             = 19, // attestation was recorded for another game
+    MultisigAlreadySet    = 20,
+    MultisigNotConfigured = 21,
+    InvalidThreshold      = 22, // threshold outside [1, signer count]
+    DuplicateSigner       = 23,
+    NotASigner            = 24,
+    ThresholdNotMet       = 25, // fewer distinct approvers than the threshold
+    MultisigRequired      = 26, // admin changes must go through transfer_admin
+    MalformedProof        = 27, // strict shape: non-zero bytes after the hash
+    ProofConsumed         = 28, // proof already passed verification and reuse is disabled
+    AttestationIdTooLong  = 29, // attestation_id longer than MAX_ATTESTATION_ID_LEN
+    InvalidProofHashOffset = 30, // offset + 32 runs past the 128-byte proof
+    NotAttestationOwner   = 31, // recorder is neither the admin nor the attested player
+    WrongProofSystem      = 32, // attestation was made for another proving scheme
+    WrongPlayer           = 33, // attestation was recorded for another player
+    WrongRank             = 34, // attestation was recorded for another rank
+}
+
+/// Aggregate counts over all recorded attestations
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AttestationStats {
+    pub total:          u32,        // records stored
+    pub verified_count: u32,        // records with verified == true
+    pub per_rank:       Vec<u32>,   // records per claimed_rank, indexed 0-9
+}
+
+/// Showdown outcome returned by `resolve_winner_typed`
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Winner {
+    Player1,
+    Player2,
+    Tie,
+}
+
+/// How verify_proof treats a proof with no recorded attestation
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GateMode {
+    Hard, // panic (default)
+    Soft, // pass, but emit a ("zkverify", "att_miss") warning event
+    Off,  // pass silently
+    DryRun, // pass, but emit ("zkverify", "gate_would_reject") for monitoring rollouts
+}
+
+/// Which end of the rank scale wins a showdown
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HandOrdering {
+    HighWins, // standard poker (default)
+    LowWins,  // lowball: the lower rank takes the pot
 }
 
 #[contract]
 pub struct NoirVerifier;
 
+/// `set_log_level` levels: verification summaries, then evaluator detail.
+const LOG_INFO: u32 = 1;
+const LOG_DEBUG: u32 = 2;
+/// Maximum number of records returned by a single paginated query.
+const MAX_PAGE_SIZE: u32 = 50;
+/// Most hole cards any supported variant deals (Hold'em 2, Omaha 4, Big O 5).
+const MAX_HOLE_CARDS: u32 = 6;
+/// Size of a fixed-layout proof: `[SHA-256(raw proof) 32B][zeros 96B]`.
+/// Entry points spell it as `BytesN<128>` since the contract spec needs a literal.
+const PROOF_LEN: usize = 128;
+/// Leading bytes of a proof holding the attestation lookup hash.
+const PROOF_HASH_LEN: usize = 32;
+/// Length of a zkVerify block hash: "0x" followed by 64 hex digits.
+const BLOCK_HASH_LEN: u32 = 66;
+/// Fewest board cards a board-aware check accepts (the flop).
+const MIN_BOARD_CARDS: u32 = 3;
+/// Most board cards in Hold'em (flop, turn and river).
+const MAX_BOARD_CARDS: u32 = 5;
+/// Cards a Hold'em hand is evaluated over: 2 hole + 5 board.
+const MAX_HAND_CARDS: usize = 2 + MAX_BOARD_CARDS as usize;
+/// Longest accepted zkVerify attestation_id, keeping the ATT_I key bounded.
+const MAX_ATTESTATION_ID_LEN: u32 = 128;
+/// Version of the verification interface (`verify_proof` and friends)
+/// reported to callers; bump when an entry point's signature changes.
+const INTERFACE_VERSION: u32 = 1;
+/// Proving scheme of the Noir circuits, recorded by `record_zkverify_attestation`.
+const ULTRAHONK: Symbol = symbol_short!("ultrahonk");
+
 // Storage key symbols
 // Attestation count: symbol_short!("ATT_CNT")  → u32
 // Attestation by index: (symbol_short!("ATT"), index: u32) → ZkVerifyAttestation
 // Attestation by proof_hash: (symbol_short!("ATT_H"), proof_hash: BytesN<32>) → String
+// Attestation index by proof_hash: (symbol_short!("ATT_IX"), proof_hash: BytesN<32>) → u32
+// Admin: symbol_short!("ADMIN") → Address
+// Gate mode: symbol_short!("GATE") → GateMode
+// Expected proof system: symbol_short!("PROOF_SYS") → Symbol
+// Multisig signers: symbol_short!("SIGNERS") → Vec<Address>
+// Multisig threshold: symbol_short!("THRESHOLD") → u32
+// Game contract: symbol_short!("GAME_CT") → Address
 
 #[contractimpl]
 impl NoirVerifier {
@@ -75,8 +218,11 @@ impl NoirVerifier {
     ///      exists for that hash. This attestation is recorded BEFORE showdown
     ///      by the frontend, after the proof passes real `bb verify` (UltraHonk
     ///      pairing check) and optionally the zkVerify Substrate chain.
-    ///   3. If no attestation exists, still passes (first-run / bb binary missing)
-    ///      but emits `att_required=false` in the event for audit.
+    ///   3. If no attestation exists, the configured `GateMode` decides:
+    ///      `Hard` panics, `Soft` passes but emits an `att_miss` warning event,
+    ///      `Off` passes silently (local testing only), `DryRun` passes but
+    ///      emits a `gate_would_reject` event for every missing or
+    ///      unverified attestation.
     ///
     /// # Arguments
     /// * `hole_cards`   — [card1, card2] as u8 values (deck index 0-51)
@@ -85,6 +231,8 @@ impl NoirVerifier {
     /// * `claimed_rank` — Hand rank: 0=High, 1=Pair … 9=Royal Flush
     /// * `proof_bytes`  — 128-byte value: [SHA-256(raw_proof) 32B][zeros 96B]
     /// * `player`       — 32-byte player address (for event log)
+    /// * `game_id`      — game the proof is presented for; a recorded
+    ///   attestation must have been made for the same game
     ///
     /// # Returns
     /// `true` if all checks pass, panics otherwise.
@@ -96,64 +244,161 @@ impl NoirVerifier {
         claimed_rank: u32,
         proof_bytes:  BytesN<128>,
         player:       BytesN<32>,
+        game_id:      BytesN<32>,
     ) -> bool {
-        // ── 1. Proof bytes exist ───────────────────────────────────────────
-        let proof_slice = proof_bytes.to_array();
-        let proof_non_zero = proof_slice.iter().any(|b| *b != 0);
-        assert!(proof_non_zero, "Proof bytes are all zero — not generated");
+        let proof_hash = Self::prefix_proof_hash(&env, &proof_bytes);
+        let mode = Self::get_gate_mode(env.clone());
+        Self::check_hand_and_attestation(&env, &hole_cards, claimed_rank, proof_hash, player, game_id, mode, false)
+    }
 
-        // ── 2. Card validity ──────────────────────────────────────────────
-        let card_arr = hole_cards.to_array();
-        let c1 = card_arr[0];
-        let c2 = card_arr[1];
-        assert!(c1 <= 51, "Card 1 out of range [0,51]");
-        assert!(c2 <= 51, "Card 2 out of range [0,51]");
-        assert!(c1 != c2, "Duplicate hole cards detected");
+    /// Run every `verify_proof` check and return the verified rank.
+    ///
+    /// Lets callers such as `poker_game.resolve_showdown` take the rank from
+    /// the verifier instead of trusting their own copy of `claimed_rank`.
+    /// The caller can tighten the attestation gate for this call:
+    /// `require_attestation` enforces it as `Hard`, otherwise the admin's
+    /// `GateMode` applies. The caller can never relax the admin's mode.
+    ///
+    /// Once a game contract is registered (`set_game_contract`) only it may
+    /// call this, and the proof is consumed; see `set_proof_reuse`.
+    pub fn verify_and_get_rank(
+        env:                 Env,
+        hole_cards:          BytesN<2>,
+        _salt:               BytesN<32>,
+        _commitment:         BytesN<32>,
+        claimed_rank:        u32,
+        proof_bytes:         BytesN<128>,
+        player:              BytesN<32>,
+        game_id:             BytesN<32>,
+        require_attestation: bool,
+    ) -> u32 {
+        let proof_hash = Self::prefix_proof_hash(&env, &proof_bytes);
+        let mode = Self::caller_gate_mode(&env, require_attestation);
+        let consume = Self::require_game_contract(&env);
+        let verified = Self::check_hand_and_attestation(&env, &hole_cards, claimed_rank, proof_hash, player, game_id, mode, consume);
+        Self::ensure(&env, verified, VerifierError::VerificationFailed);
+        claimed_rank
+    }
 
-        // ── 3. Rank range ─────────────────────────────────────────────────
-        assert!(claimed_rank <= 9, "Claimed rank out of range [0,9]");
+    /// Verify a proof for a variant dealing `expected_count` hole cards
+    /// (e.g. 4 for Omaha).
+    ///
+    /// `hole_cards` holds one byte per card (deck index 0-51). Asserts the
+    /// length equals `expected_count`, every card is in range and no card
+    /// repeats, then runs the same rank and attestation checks as
+    /// `verify_proof`. Restricted and consuming like `verify_and_get_rank`.
+    pub fn verify_proof_variant(
+        env:            Env,
+        hole_cards:     Bytes,
+        expected_count: u32,
+        _salt:          BytesN<32>,
+        _commitment:    BytesN<32>,
+        claimed_rank:   u32,
+        proof_bytes:    BytesN<128>,
+        player:         BytesN<32>,
+        game_id:        BytesN<32>,
+        require_attestation: bool,
+    ) -> bool {
+        Self::ensure(&env, (2..=MAX_HOLE_CARDS).contains(&expected_count), VerifierError::UnsupportedHoleCards);
+        Self::ensure(&env, hole_cards.len() == expected_count, VerifierError::WrongHoleCardCount);
+        for i in 0..hole_cards.len() {
+            let card = hole_cards.get(i).unwrap();
+            Self::ensure(&env, Self::is_valid_card(card as u32), VerifierError::CardOutOfRange);
+            for j in 0..i {
+                Self::ensure(&env, hole_cards.get(j).unwrap() != card, VerifierError::DuplicateCards);
+            }
+        }
 
-        // ── 4. Attestation gate — check for real ZK verification ─────────
-        //  Extract proof_hash (first 32 bytes = SHA-256 of the full UltraHonk proof)
-        //  and check if a VERIFIED attestation was recorded by the frontend
-        //  (after passing bb verify / zkVerify chain).
-        let mut proof_hash_arr = [0u8; 32];
-        proof_hash_arr.copy_from_slice(&proof_slice[..32]);
-        let proof_hash = BytesN::from_array(&env, &proof_hash_arr);
-        let hash_key = (symbol_short!("ATT_H"), proof_hash.clone());
-        let has_att = env.storage().instance().has(&hash_key);
+        let proof_hash = Self::prefix_proof_hash(&env, &proof_bytes);
+        Self::ensure(&env, claimed_rank <= 9, VerifierError::RankOutOfRange);
+        let mode = Self::caller_gate_mode(&env, require_attestation);
+        let consume = Self::require_game_contract(&env);
+        Self::check_attestation(&env, claimed_rank, proof_hash, player, game_id, mode, consume)
+    }
 
-        // If attestation exists, verify it was marked as `verified=true`
-        let att_verified = if has_att {
-            // Look up the full attestation by iterating (proof_hash → attestation_id stored,
-            // but we need verified flag → check via ATT records)
-            let att_key = (symbol_short!("ATT_H"), proof_hash.clone());
-            let _att_id: String = env.storage().instance()
-                .get::<(Symbol, BytesN<32>), String>(&att_key)
-                .unwrap_or(String::from_str(&env, ""));
-            // Attestation recorded = bb verify passed on server.
-            // The record_zkverify_attestation fn only stores when verified=true.
-            true
-        } else {
-            false
-        };
+    /// Verify a Noir poker hand proof against the full board.
+    ///
+    /// Recomputes the best 5-of-N hand rank from the hole cards plus the
+    /// community cards (3 on the flop, 4 on the turn, 5 on the river) and
+    /// asserts it equals `claimed_rank`, then runs the `verify_proof` checks.
+    ///
+    /// # Arguments
+    /// * `community` — revealed board cards as deck indices 0-51
+    ///
+    /// All other arguments are as in `verify_proof`.
+    pub fn verify_proof_with_board(
+        env:          Env,
+        hole_cards:   BytesN<2>,
+        salt:         BytesN<32>,
+        commitment:   BytesN<32>,
+        claimed_rank: u32,
+        proof_bytes:  BytesN<128>,
+        player:       BytesN<32>,
+        game_id:      BytesN<32>,
+        community:    Vec<u32>,
+    ) -> bool {
+        Self::ensure(&env, (MIN_BOARD_CARDS..=MAX_BOARD_CARDS).contains(&community.len()), VerifierError::InvalidBoard);
 
-        // ── 5. Attestation HARD GATE — no attestation = no verify ────────
-        assert!(att_verified, "No zkVerify attestation found — proof not cryptographically verified");
+        let (cards, n) = Self::collect_cards(&hole_cards, &community);
+        let actual_rank = Self::evaluate_rank(&cards[..n]);
+        log_at!(&env, LOG_DEBUG, "🔍 board eval: claimed={} actual={}", claimed_rank, actual_rank);
+        Self::ensure(&env, actual_rank == claimed_rank, VerifierError::RankMismatch);
 
-        // ── 6. Emit verification event ────────────────────────────────────
-        env.events().publish(
-            (symbol_short!("zkverify"), symbol_short!("noir")),
-            VerificationResult {
-                valid:        true,
-                claimed_rank,
-                player: player.clone(),
-            },
-        );
+        Self::verify_proof(env, hole_cards, salt, commitment, claimed_rank, proof_bytes, player, game_id)
+    }
+
+    /// Verify a Noir poker hand proof supplied as the full, variable-length blob.
+    ///
+    /// Same checks as `verify_proof`, but instead of reading a precomputed hash
+    /// from a fixed 128-byte prefix, the attestation lookup hash is derived as
+    /// SHA-256 over the entire `proof` (e.g. the ~16 KB UltraHonk proof).
+    ///
+    /// # Arguments
+    /// * `proof` — raw proof bytes of arbitrary length (must be non-empty, non-zero)
+    ///
+    /// All other arguments are as in `verify_proof`.
+    pub fn verify_proof_full(
+        env:          Env,
+        hole_cards:   BytesN<2>,
+        _salt:        BytesN<32>,
+        _commitment:  BytesN<32>,
+        claimed_rank: u32,
+        proof:        Bytes,
+        player:       BytesN<32>,
+        game_id:      BytesN<32>,
+    ) -> bool {
+        // ── 1. Proof bytes exist ───────────────────────────────────────────
+        let proof_non_zero = proof.iter().any(|b| b != 0);
+        Self::ensure(&env, proof_non_zero, VerifierError::ProofAllZero);
 
-        log!(&env, "✅ Noir proof verified — REAL zkVerify attestation confirmed — player={:?} rank={}", player, claimed_rank);
+        // ── Proof hash = SHA-256 over the whole blob ──────────────────────
+        let proof_hash: BytesN<32> = env.crypto().sha256(&proof).into();
 
-        true
+        let mode = Self::get_gate_mode(env.clone());
+        Self::check_hand_and_attestation(&env, &hole_cards, claimed_rank, proof_hash, player, game_id, mode, false)
+    }
+
+    /// Verify both showdown proofs in one call.
+    ///
+    /// Runs the `verify_proof` checks for each player under the configured
+    /// gate mode, after checking what only a joint view can: both proofs
+    /// are for the same game (`WrongGame`) and no card appears in both
+    /// hands (`DuplicateCards`). Any failure panics with its error code.
+    pub fn verify_proofs_batch(env: Env, p1: ProofArgs, p2: ProofArgs) -> (bool, bool) {
+        Self::ensure(&env, p1.game_id == p2.game_id, VerifierError::WrongGame);
+        for a in p1.hole_cards.to_array() {
+            Self::ensure(&env, !p2.hole_cards.to_array().contains(&a), VerifierError::DuplicateCards);
+        }
+
+        let mode = Self::get_gate_mode(env.clone());
+        let mut results = [false; 2];
+        for (i, args) in [p1, p2].into_iter().enumerate() {
+            let proof_hash = Self::prefix_proof_hash(&env, &args.proof_bytes);
+            results[i] = Self::check_hand_and_attestation(
+                &env, &args.hole_cards, args.claimed_rank, proof_hash, args.player, args.game_id, mode, false,
+            );
+        }
+        (results[0], results[1])
     }
 
     /// Batch verify both players in a showdown.
@@ -162,20 +407,16 @@ impl NoirVerifier {
     /// with the verified ranks to get the winner.
     ///
     /// Returns the winner: 0 = player1, 1 = player2, 2 = tie.
+    /// Prefer `resolve_winner_typed` — this numeric form is kept for compatibility.
     pub fn resolve_winner(
         env:     Env,
         p1_rank: u32,
         p2_rank: u32,
     ) -> u32 {
-        assert!(p1_rank <= 9, "p1_rank out of range");
-        assert!(p2_rank <= 9, "p2_rank out of range");
-
-        let winner: u32 = if p1_rank > p2_rank {
-            0 // player 1 wins
-        } else if p2_rank > p1_rank {
-            1 // player 2 wins
-        } else {
-            2 // tie
+        let winner: u32 = match Self::compare_ranks(&env, p1_rank, p2_rank) {
+            Winner::Player1 => 0,
+            Winner::Player2 => 1,
+            Winner::Tie     => 2,
         };
 
         env.events().publish(
@@ -183,10 +424,312 @@ impl NoirVerifier {
             winner,
         );
 
-        log!(&env, "🏆 Showdown: winner={} (p1={}, p2={})", winner, p1_rank, p2_rank);
+        log_at!(&env, LOG_INFO, "🏆 Showdown: winner={} (p1={}, p2={})", winner, p1_rank, p2_rank);
+        winner
+    }
+
+    /// Same as `resolve_winner`, but returns an explicit `Winner` variant.
+    ///
+    /// Emits the `Winner` under `("showdown", "winner")`.
+    pub fn resolve_winner_typed(
+        env:     Env,
+        p1_rank: u32,
+        p2_rank: u32,
+    ) -> Winner {
+        let winner = Self::compare_ranks(&env, p1_rank, p2_rank);
+
+        env.events().publish(
+            (symbol_short!("showdown"), symbol_short!("winner")),
+            winner,
+        );
+
+        log_at!(&env, LOG_INFO, "🏆 Showdown: winner={:?} (p1={}, p2={})", winner, p1_rank, p2_rank);
         winner
     }
 
+    /// Check that `claimed_rank` is plausible for the revealed cards.
+    ///
+    /// This is a necessary-condition check, not a full evaluation: it only
+    /// rejects claims the hole + community cards cannot support at all —
+    /// e.g. a Pair when no two cards share a rank, or a Flush with fewer than
+    /// five cards of one suit.  Card encoding matches the circuit:
+    /// `rank = card % 13` (0=Two … 12=Ace), `suit = card / 13`.
+    ///
+    /// # Arguments
+    /// * `hole_cards`      — [card1, card2] as u8 values (deck index 0-51)
+    /// * `community_cards` — revealed board cards (0-5 cards, deck index 0-51)
+    /// * `claimed_rank`    — Hand rank: 0=High, 1=Pair … 9=Royal Flush
+    ///
+    /// # Returns
+    /// `true` if the claim is consistent, panics otherwise.
+    pub fn verify_rank_consistency(
+        env:             Env,
+        hole_cards:      BytesN<2>,
+        community_cards: Vec<u32>,
+        claimed_rank:    u32,
+    ) -> bool {
+        Self::ensure(&env, claimed_rank <= 9, VerifierError::RankOutOfRange);
+
+        let (cards, n) = Self::collect_cards(&hole_cards, &community_cards);
+        Self::ensure(&env, Self::rank_supported(&cards[..n], claimed_rank), VerifierError::RankInconsistent);
+        true
+    }
+
+    /// Compare two Hold'em hands over the same board, kickers included.
+    ///
+    /// Returns `1` if the first hand is stronger, `-1` if the second is, and
+    /// `0` only when both best five-card hands are identical (e.g. both
+    /// players play the board), which is the one case a pot should chop.
+    pub fn compare_hands(
+        env:       Env,
+        hole1:     BytesN<2>,
+        hole2:     BytesN<2>,
+        community: Vec<u32>,
+    ) -> i32 {
+        Self::ensure(&env, (MIN_BOARD_CARDS..=MAX_BOARD_CARDS).contains(&community.len()), VerifierError::InvalidBoard);
+        let (first, second) = (hole1.to_array(), hole2.to_array());
+        Self::ensure(&env, !second.iter().any(|c| first.contains(c)), VerifierError::DuplicateCards);
+
+        let (cards1, n1) = Self::collect_cards(&hole1, &community);
+        let (cards2, n2) = Self::collect_cards(&hole2, &community);
+        let score1 = Self::hand_score(&cards1[..n1]);
+        let score2 = Self::hand_score(&cards2[..n2]);
+        log_at!(&env, LOG_DEBUG, "🔍 compare_hands: score1={} score2={}", score1, score2);
+        match score1.cmp(&score2) {
+            core::cmp::Ordering::Greater => 1,
+            core::cmp::Ordering::Less => -1,
+            core::cmp::Ordering::Equal => 0,
+        }
+    }
+
+    /// Interface version of this verifier, checked by `poker_game::set_verifier`.
+    pub fn verifier_version(_env: Env) -> u32 {
+        INTERFACE_VERSION
+    }
+
+    /// Health check: answers with the interface version so operators (and
+    /// `poker_game::set_verifier`) can confirm the address is a live verifier.
+    pub fn ping(_env: Env) -> u32 {
+        INTERFACE_VERSION
+    }
+
+    // ════════════════════════════════════════════════════════════════════
+    //  Admin configuration
+    // ════════════════════════════════════════════════════════════════════
+
+    /// Set the verifier admin.
+    /// The first call claims the role; afterwards only the current admin can hand it over.
+    /// Once a multisig is configured the admin can only change via `transfer_admin`.
+    pub fn set_admin(env: Env, admin: Address) {
+        Self::ensure(&env, !Self::has_multisig(&env), VerifierError::MultisigRequired);
+        if let Some(current) = env.storage().instance().get::<_, Address>(&symbol_short!("ADMIN")) {
+            current.require_auth();
+        }
+        admin.require_auth();
+        env.storage().instance().set(&symbol_short!("ADMIN"), &admin);
+    }
+
+    /// Choose how a missing attestation is handled by verify_proof.
+    pub fn set_gate_mode(env: Env, admin: Address, mode: GateMode) {
+        Self::require_admin(&env, &admin);
+        env.storage().instance().set(&symbol_short!("GATE"), &mode);
+    }
+
+    /// Current attestation gate mode (defaults to `Hard`).
+    pub fn get_gate_mode(env: Env) -> GateMode {
+        env.storage().instance().get(&symbol_short!("GATE")).unwrap_or(GateMode::Hard)
+    }
+
+    /// Decode a deck index into `(rank, suit)`: rank 0-12 (0=Two … 12=Ace),
+    /// suit 0-3. Matches the circuit's `card = suit*13 + rank` encoding.
+    /// Panics for indices outside [0,51].
+    pub fn decode_card(env: Env, index: u32) -> (u32, u32) {
+        Self::ensure(&env, Self::is_valid_card(index), VerifierError::CardOutOfRange);
+        Self::split_card(index)
+    }
+
+    /// Whether `index` is a deck index in [0,51].
+    pub fn is_valid_card(index: u32) -> bool {
+        index <= 51
+    }
+
+    /// Set whether higher or lower ranks win in `resolve_winner` (admin only).
+    pub fn set_hand_ordering(env: Env, admin: Address, ordering: HandOrdering) {
+        Self::require_admin(&env, &admin);
+        env.storage().instance().set(&symbol_short!("ORDERING"), &ordering);
+    }
+
+    /// Current hand ordering (defaults to `HighWins`).
+    pub fn get_hand_ordering(env: Env) -> HandOrdering {
+        env.storage().instance().get(&symbol_short!("ORDERING")).unwrap_or(HandOrdering::HighWins)
+    }
+
+    /// Canonical name for a hand rank (0 = "High Card" … 9 = "Royal Flush").
+    /// Panics for ranks above 9.
+    pub fn rank_name(env: Env, rank: u32) -> String {
+        let name = match rank {
+            0 => "High Card",
+            1 => "One Pair",
+            2 => "Two Pair",
+            3 => "Three of a Kind",
+            4 => "Straight",
+            5 => "Flush",
+            6 => "Full House",
+            7 => "Four of a Kind",
+            8 => "Straight Flush",
+            9 => "Royal Flush",
+            _ => panic_with_error!(&env, VerifierError::RankOutOfRange),
+        };
+        String::from_str(&env, name)
+    }
+
+    /// Set how much the contract logs: 0 silences `log!` output,
+    /// `LOG_INFO` (1) keeps verification summaries and `LOG_DEBUG`
+    /// (2, the default) adds evaluator detail. Published events are
+    /// unaffected.
+    pub fn set_log_level(env: Env, admin: Address, level: u32) {
+        Self::require_admin(&env, &admin);
+        env.storage().instance().set(&symbol_short!("LOG_LVL"), &level);
+    }
+
+    /// Current log level (see `set_log_level`).
+    pub fn get_log_level(env: Env) -> u32 {
+        Self::log_level(&env)
+    }
+
+    /// Enable or disable block_hash format checks on verified attestations
+    /// (admin only). Strict by default; relaxing is meant for local testing.
+    pub fn set_strict_block_hash(env: Env, admin: Address, strict: bool) {
+        Self::require_admin(&env, &admin);
+        env.storage().instance().set(&symbol_short!("STRICT_BH"), &strict);
+    }
+
+    /// Whether verified attestations must carry a well-formed block_hash.
+    pub fn is_strict_block_hash(env: Env) -> bool {
+        env.storage().instance().get(&symbol_short!("STRICT_BH")).unwrap_or(true)
+    }
+
+    /// Enable or disable proof shape checks (admin only). When strict, a
+    /// 128-byte proof must follow the documented `[hash 32B][zeros 96B]`
+    /// layout. Off by default.
+    pub fn set_strict_proof_shape(env: Env, admin: Address, strict: bool) {
+        Self::require_admin(&env, &admin);
+        env.storage().instance().set(&symbol_short!("STRICT_PF"), &strict);
+    }
+
+    /// Whether 128-byte proofs must have an all-zero tail after the hash.
+    pub fn is_strict_proof_shape(env: Env) -> bool {
+        env.storage().instance().get(&symbol_short!("STRICT_PF")).unwrap_or(false)
+    }
+
+    /// Set where the 32-byte attestation lookup hash sits inside a 128-byte
+    /// proof (admin only), for proof formats that do not lead with it.
+    /// `offset + 32` must not exceed 128. Defaults to 0.
+    pub fn set_proof_hash_offset(env: Env, admin: Address, offset: u32) {
+        Self::require_admin(&env, &admin);
+        let fits = offset as usize + PROOF_HASH_LEN <= PROOF_LEN;
+        Self::ensure(&env, fits, VerifierError::InvalidProofHashOffset);
+        env.storage().instance().set(&symbol_short!("PH_OFF"), &offset);
+    }
+
+    /// Byte offset of the lookup hash inside a 128-byte proof.
+    pub fn get_proof_hash_offset(env: Env) -> u32 {
+        env.storage().instance().get(&symbol_short!("PH_OFF")).unwrap_or(0)
+    }
+
+    /// Allow or forbid verifying the same proof more than once (admin only).
+    /// When forbidden, a proof_hash the game contract already had verified
+    /// is rejected with `ProofConsumed`. Reuse is allowed by default.
+    pub fn set_proof_reuse(env: Env, admin: Address, allowed: bool) {
+        Self::require_admin(&env, &admin);
+        env.storage().instance().set(&symbol_short!("PF_REUSE"), &allowed);
+    }
+
+    /// Whether a proof may pass verification more than once.
+    pub fn is_proof_reuse_allowed(env: Env) -> bool {
+        env.storage().instance().get(&symbol_short!("PF_REUSE")).unwrap_or(true)
+    }
+
+    /// Whether `proof_hash` has already been consumed by the game contract.
+    pub fn is_proof_consumed(env: Env, proof_hash: BytesN<32>) -> bool {
+        env.storage().persistent().has(&(symbol_short!("USED"), proof_hash))
+    }
+
+    /// Register the game contract whose showdown calls consume proofs
+    /// (admin only). Other `verify_proof*` calls are read-only checks, so
+    /// nobody else can burn a player's proof ahead of the showdown.
+    pub fn set_game_contract(env: Env, admin: Address, game: Address) {
+        Self::require_admin(&env, &admin);
+        env.storage().instance().set(&symbol_short!("GAME_CT"), &game);
+    }
+
+    /// Game contract registered with `set_game_contract`, if any.
+    pub fn get_game_contract(env: Env) -> Option<Address> {
+        env.storage().instance().get(&symbol_short!("GAME_CT"))
+    }
+
+    // ════════════════════════════════════════════════════════════════════
+    //  Multi-signature admin — M-of-N approval for sensitive operations
+    // ════════════════════════════════════════════════════════════════════
+
+    /// Configure the signer set and approval threshold for `revoke_attestation`
+    /// and `transfer_admin`. Can only be set once; if an admin is configured
+    /// it must authorize the call.
+    pub fn initialize_multisig(env: Env, signers: Vec<Address>, threshold: u32) {
+        Self::ensure(&env, !Self::has_multisig(&env), VerifierError::MultisigAlreadySet);
+        if let Some(admin) = env.storage().instance().get::<_, Address>(&symbol_short!("ADMIN")) {
+            admin.require_auth();
+        }
+        Self::ensure(&env, threshold >= 1 && threshold <= signers.len(), VerifierError::InvalidThreshold);
+        for i in 0..signers.len() {
+            let signer = signers.get(i).unwrap();
+            Self::ensure(&env, signers.first_index_of(&signer) == Some(i), VerifierError::DuplicateSigner);
+        }
+
+        env.storage().instance().set(&symbol_short!("SIGNERS"), &signers);
+        env.storage().instance().set(&symbol_short!("THRESHOLD"), &threshold);
+    }
+
+    /// Configured multisig signers and threshold.
+    pub fn get_multisig(env: Env) -> (Vec<Address>, u32) {
+        let signers: Vec<Address> = env.storage().instance()
+            .get(&symbol_short!("SIGNERS"))
+            .unwrap_or_else(|| panic_with_error!(&env, VerifierError::MultisigNotConfigured));
+        let threshold: u32 = env.storage().instance().get(&symbol_short!("THRESHOLD")).unwrap();
+        (signers, threshold)
+    }
+
+    /// Mark the attestation for `proof_hash` as unverified, so the Hard gate
+    /// rejects proofs relying on it. Requires `threshold` distinct signers
+    /// in `approvers`, each authorizing the call.
+    pub fn revoke_attestation(env: Env, proof_hash: BytesN<32>, approvers: Vec<Address>) {
+        Self::require_multisig(&env, &approvers);
+
+        let ix_key = (symbol_short!("ATT_IX"), proof_hash.clone());
+        let index: u32 = env.storage().instance()
+            .get::<(Symbol, BytesN<32>), u32>(&ix_key)
+            .unwrap_or_else(|| panic_with_error!(&env, VerifierError::AttestationNotFound));
+        let mut attestation = Self::get_attestation(env.clone(), index);
+        Self::count_verified(&env, &attestation, false);
+        attestation.verified = false;
+        env.storage().instance().set(&(symbol_short!("ATT"), index), &attestation);
+        // Lets the hash chain tell a revocation apart from tampering
+        env.storage().instance().set(&(symbol_short!("REVOKED"), index), &true);
+
+        env.events().publish(
+            (symbol_short!("zkverify"), symbol_short!("revoke")),
+            (index, proof_hash),
+        );
+    }
+
+    /// Hand the admin role to `new_admin`. Requires `threshold` distinct
+    /// signers in `approvers`, each authorizing the call.
+    pub fn transfer_admin(env: Env, new_admin: Address, approvers: Vec<Address>) {
+        Self::require_multisig(&env, &approvers);
+        env.storage().instance().set(&symbol_short!("ADMIN"), &new_admin);
+        env.events().publish((symbol_short!("admin"), symbol_short!("transfer")), new_admin);
+    }
+
     // ════════════════════════════════════════════════════════════════════
     //  zkVerify Integration — Record attestations from zkVerify blockchain
     // ════════════════════════════════════════════════════════════════════
@@ -198,63 +741,218 @@ impl NoirVerifier {
     /// immutable on-chain record linking the zkVerify attestation to the
     /// poker game's proof data.
     ///
+    /// Each proof_hash maps to exactly one index.  Re-recording a known
+    /// proof_hash panics unless `allow_overwrite` is set, in which case the
+    /// existing record is replaced in place and its index returned — the
+    /// attestation count is not incremented.
+    ///
+    /// The `recorder` must authorize the call and be either the admin or the
+    /// player being attested (`player == player_id(recorder)`), so nobody
+    /// can attribute a proof to someone else.
+    ///
     /// # Arguments
+    /// * `recorder`       — admin, or the attested player's own address
     /// * `attestation_id` — zkVerify chain attestation ID (string)
     /// * `proof_hash`     — SHA-256 of the full proof (links to proof_bytes in verify_proof)
     /// * `player`         — 32-byte player identity
+    /// * `game_id`        — game the proof belongs to; verification for any
+    ///   other game is rejected
     /// * `claimed_rank`   — hand rank that was verified (0-9)
     /// * `verified`       — whether zkVerify confirmed the proof
-    /// * `block_hash`     — zkVerify block hash containing the attestation
+    /// * `block_hash`     — zkVerify block hash containing the attestation;
+    ///   when `verified` and strict mode is on, must be "0x" + 64 hex digits
+    /// * `allow_overwrite` — replace an existing record for the same proof_hash
+    /// * `full_proof`     — optional raw proof; when given, `proof_hash` must equal SHA-256(full_proof)
+    ///
+    /// The record's `proof_system` is `ultrahonk`; proofs from other schemes
+    /// go through `record_system_attestation`.
     pub fn record_zkverify_attestation(
         env:            Env,
+        recorder:       Address,
+        attestation_id: String,
+        proof_hash:     BytesN<32>,
+        player:         BytesN<32>,
+        game_id:        BytesN<32>,
+        claimed_rank:   u32,
+        verified:       bool,
+        block_hash:     String,
+        allow_overwrite: bool,
+        full_proof:     Option<Bytes>,
+    ) -> u32 {
+        Self::record_attestation(
+            &env, recorder, ULTRAHONK, attestation_id, proof_hash, player, game_id,
+            claimed_rank, verified, block_hash, allow_overwrite, full_proof,
+        )
+    }
+
+    /// Record a zkVerify attestation for a proof made with `proof_system`
+    /// (e.g. `groth16`, `risc0`). Same checks and storage as
+    /// `record_zkverify_attestation`, minus the optional `full_proof`
+    /// check, which would exceed the contract argument limit.
+    pub fn record_system_attestation(
+        env:            Env,
+        recorder:       Address,
+        proof_system:   Symbol,
         attestation_id: String,
         proof_hash:     BytesN<32>,
         player:         BytesN<32>,
+        game_id:        BytesN<32>,
         claimed_rank:   u32,
         verified:       bool,
         block_hash:     String,
+        allow_overwrite: bool,
     ) -> u32 {
-        assert!(claimed_rank <= 9, "Invalid claimed_rank");
+        Self::record_attestation(
+            &env, recorder, proof_system, attestation_id, proof_hash, player, game_id,
+            claimed_rank, verified, block_hash, allow_overwrite, None,
+        )
+    }
+
+    /// Restrict `verify_proof` and friends to attestations recorded for
+    /// `proof_system` (`WrongProofSystem` otherwise); `None` accepts any.
+    pub fn set_expected_proof_system(env: Env, admin: Address, proof_system: Option<Symbol>) {
+        Self::require_admin(&env, &admin);
+        match proof_system {
+            Some(system) => env.storage().instance().set(&symbol_short!("PROOF_SYS"), &system),
+            None => env.storage().instance().remove(&symbol_short!("PROOF_SYS")),
+        }
+    }
+
+    /// Proof system required by `set_expected_proof_system`, if any.
+    pub fn get_expected_proof_system(env: Env) -> Option<Symbol> {
+        env.storage().instance().get(&symbol_short!("PROOF_SYS"))
+    }
+
+    /// Helper: shared body of the `record_zkverify_attestation*` entry points.
+    fn record_attestation(
+        env:            &Env,
+        recorder:       Address,
+        proof_system:   Symbol,
+        attestation_id: String,
+        proof_hash:     BytesN<32>,
+        player:         BytesN<32>,
+        game_id:        BytesN<32>,
+        claimed_rank:   u32,
+        verified:       bool,
+        block_hash:     String,
+        allow_overwrite: bool,
+        full_proof:     Option<Bytes>,
+    ) -> u32 {
+        let env = env.clone();
+        recorder.require_auth();
+        let admin: Option<Address> = env.storage().instance().get(&symbol_short!("ADMIN"));
+        let is_admin = admin == Some(recorder.clone());
+        let recorder_id = Self::player_id(env.clone(), recorder);
+        Self::ensure(&env, is_admin || recorder_id == player, VerifierError::NotAttestationOwner);
+        Self::ensure(&env, claimed_rank <= 9, VerifierError::RankOutOfRange);
+        Self::ensure(&env, attestation_id.len() <= MAX_ATTESTATION_ID_LEN, VerifierError::AttestationIdTooLong);
+        if verified && Self::is_strict_block_hash(env.clone()) {
+            Self::ensure(&env, Self::is_block_hash_format(&block_hash), VerifierError::MalformedBlockHash);
+        }
+
+        // Self-consistency — the recorded hash must be the hash of the real proof
+        if let Some(proof) = full_proof {
+            let computed: BytesN<32> = env.crypto().sha256(&proof).into();
+            Self::ensure(&env, computed == proof_hash, VerifierError::ProofHashMismatch);
+        }
 
-        // Get current attestation count
+        // Replay protection — one record per proof_hash
         let cnt_key = symbol_short!("ATT_CNT");
-        let index: u32 = env.storage().instance()
-            .get::<Symbol, u32>(&cnt_key)
-            .unwrap_or(0);
+        let ix_key = (symbol_short!("ATT_IX"), proof_hash.clone());
+        let existing: Option<u32> = env.storage().instance()
+            .get::<(Symbol, BytesN<32>), u32>(&ix_key);
+        if existing.is_some() {
+            Self::ensure(&env, allow_overwrite, VerifierError::AlreadyRecorded);
+        }
+
+        // Only the admin or the original record's player may replace it;
+        // an overwritten record no longer counts towards its player
+        if let Some(old) = existing {
+            let old = Self::get_attestation(env.clone(), old);
+            Self::ensure(&env, is_admin || recorder_id == old.player, VerifierError::NotAttestationOwner);
+            Self::count_verified(&env, &old, false);
+        }
+
+        // Reuse the existing slot on overwrite, otherwise append
+        let index: u32 = existing.unwrap_or_else(|| {
+            env.storage().instance()
+                .get::<Symbol, u32>(&cnt_key)
+                .unwrap_or(0)
+        });
+
+        // Link to the previous record so later edits to it are detectable
+        let prev_hash = if index == 0 {
+            BytesN::from_array(&env, &[0u8; 32])
+        } else {
+            Self::attestation_digest(&env, index - 1, &Self::get_attestation(env.clone(), index - 1))
+        };
 
         // Store the attestation record
         let attestation = ZkVerifyAttestation {
             attestation_id: attestation_id.clone(),
             proof_hash:     proof_hash.clone(),
             player:         player.clone(),
+            game_id,
             claimed_rank,
             verified,
             block_hash:     block_hash.clone(),
+            proof_system,
+            prev_hash,
         };
 
         // Store by sequential index: (ATT, index) → attestation
         let att_key = (symbol_short!("ATT"), index);
         env.storage().instance().set(&att_key, &attestation);
+        env.storage().instance().remove(&(symbol_short!("REVOKED"), index));
+        Self::count_verified(&env, &attestation, true);
 
         // Store by proof_hash: (ATT_H, proof_hash) → attestation_id
         let hash_key = (symbol_short!("ATT_H"), proof_hash.clone());
         env.storage().instance().set(&hash_key, &attestation_id);
+        env.storage().instance().set(&ix_key, &index);
+
+        // Store by zkVerify id: (ATT_I, attestation_id) → index (latest record wins)
+        env.storage().instance().set(&(symbol_short!("ATT_I"), attestation_id.clone()), &index);
 
-        // Increment counter
-        env.storage().instance().set(&cnt_key, &(index + 1));
+        // Increment counter (new records only)
+        if existing.is_none() {
+            env.storage().instance().set(&cnt_key, &(index + 1));
+        }
 
-        // Emit event for indexers / Stellar explorers
+        // Emit event for indexers / Stellar explorers:
+        //   ("zkverify", "attest") → (index, count, attestation)
+        //   ("att", "idx")         → (index, proof_hash) — compact backfill of (ATT, index)
+        let count = Self::get_attestation_count(env.clone());
         env.events().publish(
             (symbol_short!("zkverify"), symbol_short!("attest")),
-            attestation,
+            (index, count, attestation),
+        );
+        env.events().publish(
+            (symbol_short!("att"), symbol_short!("idx")),
+            (index, proof_hash),
         );
 
-        log!(&env, "📋 zkVerify attestation #{} recorded — verified={} rank={}",
+        log_at!(&env, LOG_INFO, "📋 zkVerify attestation #{} recorded — verified={} rank={}",
             index, verified, claimed_rank);
 
         index  // return the attestation index
     }
 
+    /// 32-byte player identity of `address`, as used in attestations: the
+    /// trailing 32 bytes of its XDR, i.e. an account's raw ed25519 key or a
+    /// contract's hash.
+    pub fn player_id(env: Env, address: Address) -> BytesN<32> {
+        let xdr = address.to_xdr(&env);
+        xdr.slice(xdr.len() - 32..).try_into().unwrap()
+    }
+
+    /// Number of verified, unrevoked attestations recorded for `player`
+    /// (matched by `player_id`), e.g. to vet players before a table starts.
+    pub fn get_player_attestation_count(env: Env, player: Address) -> u32 {
+        let id = Self::player_id(env.clone(), player);
+        env.storage().instance().get(&(symbol_short!("ATT_P"), id)).unwrap_or(0)
+    }
+
     /// Get the total number of recorded zkVerify attestations.
     pub fn get_attestation_count(env: Env) -> u32 {
         let cnt_key = symbol_short!("ATT_CNT");
@@ -268,51 +966,680 @@ impl NoirVerifier {
         let att_key = (symbol_short!("ATT"), index);
         env.storage().instance()
             .get::<(Symbol, u32), ZkVerifyAttestation>(&att_key)
-            .expect("Attestation not found")
+            .unwrap_or_else(|| panic_with_error!(&env, VerifierError::AttestationNotFound))
     }
 
-    /// Check if a proof_hash has a recorded zkVerify attestation.
-    pub fn has_attestation(env: Env, proof_hash: BytesN<32>) -> bool {
-        let hash_key = (symbol_short!("ATT_H"), proof_hash);
-        env.storage().instance().has(&hash_key)
+    /// Get attestations `[start, start + limit)`, clamped to the recorded count.
+    ///
+    /// `limit` is capped at MAX_PAGE_SIZE (50). Fewer than `limit` records
+    /// (possibly none) means the end has been reached.
+    pub fn get_attestations_range(env: Env, start: u32, limit: u32) -> Vec<ZkVerifyAttestation> {
+        let count = Self::get_attestation_count(env.clone());
+        let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+
+        let mut page = Vec::new(&env);
+        for index in start..end {
+            page.push_back(Self::get_attestation(env.clone(), index));
+        }
+        page
     }
-}
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use soroban_sdk::{testutils::Logs, Env, String};
+    /// Get attestations from `from_index` onwards, for indexers resuming
+    /// from the last index they saw.
+    ///
+    /// At most MAX_PAGE_SIZE (50) records are returned; a full page means
+    /// the caller should call again from `from_index + 50`.
+    pub fn get_attestations_since(env: Env, from_index: u32) -> Vec<ZkVerifyAttestation> {
+        Self::get_attestations_range(env, from_index, MAX_PAGE_SIZE)
+    }
 
-    fn make_commitment(_env: &Env, _cards: [u8; 2], _salt: [u8; 32]) -> BytesN<32> {
-        // Commitment is now opaque — noir_verifier does not re-hash.
-        // For tests, use a deterministic non-zero 32-byte value.
-        BytesN::from_array(_env, &[0xABu8; 32])
+    /// Count recorded attestations whose `claimed_rank` equals `rank`.
+    ///
+    /// Scans every indexed record — intended for off-chain analytics reads.
+    pub fn count_attestations_by_rank(env: Env, rank: u32) -> u32 {
+        Self::ensure(&env, rank <= 9, VerifierError::RankOutOfRange);
+        let count = Self::get_attestation_count(env.clone());
+        let mut matches = 0;
+        for index in 0..count {
+            if Self::get_attestation(env.clone(), index).claimed_rank == rank {
+                matches += 1;
+            }
+        }
+        matches
     }
 
-    #[test]
-    fn test_valid_proof() {
-        let env = Env::default();
-        let contract_id = env.register_contract(None, NoirVerifier);
-        let client = NoirVerifierClient::new(&env, &contract_id);
+    /// Get attestations with `claimed_rank == rank`, paging over the matches:
+    /// skips the first `start` matches and returns up to `limit` (capped at
+    /// MAX_PAGE_SIZE) in recording order.
+    pub fn get_attestations_by_rank(env: Env, rank: u32, start: u32, limit: u32) -> Vec<ZkVerifyAttestation> {
+        Self::ensure(&env, rank <= 9, VerifierError::RankOutOfRange);
+        let count = Self::get_attestation_count(env.clone());
+        let limit = limit.min(MAX_PAGE_SIZE);
 
-        let cards: [u8; 2] = [51, 38]; // Ace of Spades (51), Ace of Hearts (38)
-        let salt = [42u8; 32];
-        let commitment = make_commitment(&env, cards, salt);
+        let mut page = Vec::new(&env);
+        let mut skipped = 0;
+        for index in 0..count {
+            if page.len() >= limit {
+                break;
+            }
+            let att = Self::get_attestation(env.clone(), index);
+            if att.claimed_rank != rank {
+                continue;
+            }
+            if skipped < start {
+                skipped += 1;
+                continue;
+            }
+            page.push_back(att);
+        }
+        page
+    }
 
-        // Mock 128-byte proof (non-zero) — first 32 bytes are the "proof hash"
-        let mut proof_arr = [1u8; 128];
-        proof_arr[0] = 0xa3;
-        proof_arr[1] = 0x5f;
+    /// Totals across every recorded attestation, computed in one pass.
+    ///
+    /// Reads each record once, so cost grows linearly with the attestation
+    /// count — intended for off-chain dashboard reads, not other contracts.
+    pub fn get_attestation_stats(env: Env) -> AttestationStats {
+        let total = Self::get_attestation_count(env.clone());
+        let mut per_rank = [0u32; 10];
+        let mut verified_count = 0;
+        for index in 0..total {
+            let att = Self::get_attestation(env.clone(), index);
+            per_rank[att.claimed_rank as usize] += 1;
+            if att.verified {
+                verified_count += 1;
+            }
+        }
 
-        // ── Record attestation FIRST (simulates frontend recording after bb verify) ──
-        let proof_hash = BytesN::from_array(&env, &proof_arr[..32].try_into().unwrap());
-        client.record_zkverify_attestation(
-            &String::from_str(&env, "bb-verify-test-p1"),
-            &proof_hash,
+        AttestationStats {
+            total,
+            verified_count,
+            per_rank: Vec::from_array(&env, per_rank),
+        }
+    }
+
+    /// Get the full zkVerify attestation recorded for a proof_hash.
+    pub fn get_attestation_by_proof_hash(env: Env, proof_hash: BytesN<32>) -> ZkVerifyAttestation {
+        let index = Self::get_attestation_index(env.clone(), proof_hash)
+            .unwrap_or_else(|| panic_with_error!(&env, VerifierError::AttestationNotFound));
+        Self::get_attestation(env, index)
+    }
+
+    /// Index of the attestation recorded for a proof_hash (for use with
+    /// `get_attestation`), or `None` if there is none.
+    pub fn get_attestation_index(env: Env, proof_hash: BytesN<32>) -> Option<u32> {
+        env.storage().instance().get(&(symbol_short!("ATT_IX"), proof_hash))
+    }
+
+    /// Check the hash chain over attestations `[start, end)`: every record
+    /// after `start` must carry the digest of the one before it, and record
+    /// 0 must link to zero. Returns false if any link is broken.
+    ///
+    /// `revoke_attestation` does not break the chain; overwriting a record
+    /// does break its successor's link.
+    pub fn verify_attestation_chain(env: Env, start: u32, end: u32) -> bool {
+        Self::ensure(&env, end <= Self::get_attestation_count(env.clone()), VerifierError::AttestationNotFound);
+        if start >= end {
+            return true;
+        }
+
+        let mut prev = Self::get_attestation(env.clone(), start);
+        if start == 0 && prev.prev_hash != BytesN::from_array(&env, &[0u8; 32]) {
+            return false;
+        }
+        for index in start..end {
+            if index > start {
+                let current = Self::get_attestation(env.clone(), index);
+                if current.prev_hash != Self::attestation_digest(&env, index - 1, &prev) {
+                    return false;
+                }
+                prev = current;
+            }
+            // A revoked record must stay unverified
+            if prev.verified && Self::is_revoked(&env, index) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Helper: SHA-256 over the XDR of every stored field of the record at
+    /// `index`, including its own `prev_hash` link. `verified` is taken as
+    /// recorded, i.e. before any `revoke_attestation`.
+    fn attestation_digest(env: &Env, index: u32, att: &ZkVerifyAttestation) -> BytesN<32> {
+        let fields = (
+            att.attestation_id.clone(),
+            att.proof_hash.clone(),
+            att.player.clone(),
+            att.game_id.clone(),
+            att.claimed_rank,
+            att.verified || Self::is_revoked(env, index),
+            att.block_hash.clone(),
+            att.proof_system.clone(),
+            att.prev_hash.clone(),
+        );
+        env.crypto().sha256(&fields.to_xdr(env)).into()
+    }
+
+    /// Helper: whether the record at `index` was revoked.
+    fn is_revoked(env: &Env, index: u32) -> bool {
+        env.storage().instance().has(&(symbol_short!("REVOKED"), index))
+    }
+
+    /// Get the latest attestation recorded under a zkVerify `attestation_id`,
+    /// for reconciling with the zkVerify chain.
+    pub fn get_attestation_by_id(env: Env, attestation_id: String) -> ZkVerifyAttestation {
+        let index: u32 = env.storage().instance()
+            .get(&(symbol_short!("ATT_I"), attestation_id.clone()))
+            .unwrap_or_else(|| panic_with_error!(&env, VerifierError::AttestationNotFound));
+        // The slot may since have been overwritten under a different id
+        let att = Self::get_attestation(env.clone(), index);
+        Self::ensure(&env, att.attestation_id == attestation_id, VerifierError::AttestationNotFound);
+        att
+    }
+
+    /// Check if a proof_hash has a recorded zkVerify attestation.
+    pub fn has_attestation(env: Env, proof_hash: BytesN<32>) -> bool {
+        let hash_key = (symbol_short!("ATT_H"), proof_hash);
+        env.storage().instance().has(&hash_key)
+    }
+
+    /// Helper: `(rank, suit)` of a deck index, without range checking.
+    fn split_card(index: u32) -> (u32, u32) {
+        (index % 13, index / 13)
+    }
+
+    /// Gather hole + community cards into a fixed buffer, validating ranges
+    /// and rejecting board cards that collide with cards already gathered.
+    /// Returns the buffer and the number of cards filled.
+    fn collect_cards(hole_cards: &BytesN<2>, community: &Vec<u32>) -> ([u8; MAX_HAND_CARDS], usize) {
+        Self::ensure(hole_cards.env(), community.len() <= MAX_BOARD_CARDS, VerifierError::InvalidBoard);
+
+        let mut cards = [0u8; MAX_HAND_CARDS];
+        let hole = hole_cards.to_array();
+        let hole_valid = Self::is_valid_card(hole[0] as u32) && Self::is_valid_card(hole[1] as u32);
+        Self::ensure(hole_cards.env(), hole_valid, VerifierError::CardOutOfRange);
+        cards[0] = hole[0];
+        cards[1] = hole[1];
+        let mut n = 2;
+        for c in community.iter() {
+            Self::ensure(hole_cards.env(), Self::is_valid_card(c), VerifierError::CardOutOfRange);
+            // A real deck holds each card once: no board card may repeat a
+            // hole card or an earlier board card.
+            Self::ensure(hole_cards.env(), !cards[..n].contains(&(c as u8)), VerifierError::DuplicateCards);
+            cards[n] = c as u8;
+            n += 1;
+        }
+        (cards, n)
+    }
+
+    /// Rank (0-12) and suit (0-3) frequency tables for a set of cards.
+    fn rank_suit_counts(cards: &[u8]) -> ([u8; 13], [u8; 4]) {
+        let mut ranks = [0u8; 13];
+        let mut suits = [0u8; 4];
+        for c in cards.iter() {
+            let (rank, suit) = Self::split_card(*c as u32);
+            ranks[rank as usize] += 1;
+            suits[suit as usize] += 1;
+        }
+        (ranks, suits)
+    }
+
+    /// Rank frequency restricted to the suit holding five or more cards, if any.
+    fn flush_rank_counts(cards: &[u8], suits: &[u8; 4]) -> Option<[u8; 13]> {
+        let suit = suits.iter().position(|count| *count >= 5)? as u32;
+        let mut ranks = [0u8; 13];
+        for c in cards.iter() {
+            let (rank, card_suit) = Self::split_card(*c as u32);
+            if card_suit == suit {
+                ranks[rank as usize] += 1;
+            }
+        }
+        Some(ranks)
+    }
+
+    /// Five consecutive ranks present, including the wheel (A-2-3-4-5).
+    fn has_straight(ranks: &[u8; 13]) -> bool {
+        let wheel = ranks[12] > 0 && ranks[..4].iter().all(|r| *r > 0);
+        wheel || ranks.windows(5).any(|w| w.iter().all(|r| *r > 0))
+    }
+
+    /// T-J-Q-K-A all present.
+    fn has_royal(ranks: &[u8; 13]) -> bool {
+        ranks[8..].iter().all(|r| *r > 0)
+    }
+
+    /// Best hand rank (0-9) over all of `cards`, mirroring `calculate_hand_rank`
+    /// in the Noir circuit.  Straight/royal flushes only count cards of the
+    /// flush suit.
+    fn evaluate_rank(cards: &[u8]) -> u32 {
+        let (ranks, suits) = Self::rank_suit_counts(cards);
+        let flush = Self::flush_rank_counts(cards, &suits);
+        let straight_flush = flush.map(|f| Self::has_straight(&f)).unwrap_or(false);
+        let royal = straight_flush && flush.map(|f| Self::has_royal(&f)).unwrap_or(false);
+
+        let mut max = 0u8;
+        let mut second = 0u8;
+        for r in ranks.iter().copied() {
+            if r > max {
+                second = max;
+                max = r;
+            } else if r > second {
+                second = r;
+            }
+        }
+
+        if royal {
+            9
+        } else if straight_flush {
+            8
+        } else if max == 4 {
+            7
+        } else if max == 3 && second >= 2 {
+            6
+        } else if flush.is_some() {
+            5
+        } else if Self::has_straight(&ranks) {
+            4
+        } else if max == 3 {
+            3
+        } else if max == 2 && second == 2 {
+            2
+        } else if max == 2 {
+            1
+        } else {
+            0
+        }
+    }
+
+    /// Comparable strength of the best five of `cards`: the rank (0-9) in
+    /// the top bits, followed by up to five tiebreak ranks (4 bits each),
+    /// most significant first.  Equal scores mean identical best hands.
+    fn hand_score(cards: &[u8]) -> u32 {
+        let category = Self::evaluate_rank(cards);
+        let (ranks, suits) = Self::rank_suit_counts(cards);
+        let mut tiebreak = [0u32; 5];
+        match category {
+            9 => {}
+            8 => {
+                let flush = Self::flush_rank_counts(cards, &suits).unwrap_or([0; 13]);
+                tiebreak[0] = Self::straight_high(&flush).unwrap_or(0);
+            }
+            7 => {
+                let quads = Self::top_rank(&ranks, 4, &[]);
+                tiebreak[0] = quads;
+                tiebreak[1] = Self::top_rank(&ranks, 1, &[quads]);
+            }
+            6 => {
+                let trips = Self::top_rank(&ranks, 3, &[]);
+                tiebreak[0] = trips;
+                tiebreak[1] = Self::top_rank(&ranks, 2, &[trips]);
+            }
+            5 => {
+                let flush = Self::flush_rank_counts(cards, &suits).unwrap_or([0; 13]);
+                Self::fill_high(&flush, &[], &mut tiebreak);
+            }
+            4 => tiebreak[0] = Self::straight_high(&ranks).unwrap_or(0),
+            3 => {
+                let trips = Self::top_rank(&ranks, 3, &[]);
+                tiebreak[0] = trips;
+                Self::fill_high(&ranks, &[trips], &mut tiebreak[1..3]);
+            }
+            2 => {
+                let high = Self::top_rank(&ranks, 2, &[]);
+                let low = Self::top_rank(&ranks, 2, &[high]);
+                tiebreak[0] = high;
+                tiebreak[1] = low;
+                Self::fill_high(&ranks, &[high, low], &mut tiebreak[2..3]);
+            }
+            1 => {
+                let pair = Self::top_rank(&ranks, 2, &[]);
+                tiebreak[0] = pair;
+                Self::fill_high(&ranks, &[pair], &mut tiebreak[1..4]);
+            }
+            _ => Self::fill_high(&ranks, &[], &mut tiebreak),
+        }
+        tiebreak.iter().fold(category, |score, rank| (score << 4) | rank)
+    }
+
+    /// Top rank of the highest straight, counting the wheel as five-high.
+    fn straight_high(ranks: &[u8; 13]) -> Option<u32> {
+        let top = (4..13).rev().find(|top| ranks[top - 4..=*top].iter().all(|r| *r > 0));
+        match top {
+            Some(top) => Some(top as u32),
+            None if ranks[12] > 0 && ranks[..4].iter().all(|r| *r > 0) => Some(3),
+            None => None,
+        }
+    }
+
+    /// Highest rank held at least `min_count` times, other than `skip`.
+    fn top_rank(ranks: &[u8; 13], min_count: u8, skip: &[u32]) -> u32 {
+        (0..13u32).rev()
+            .find(|r| ranks[*r as usize] >= min_count && !skip.contains(r))
+            .unwrap_or(0)
+    }
+
+    /// Fill `out` with the highest ranks present, other than `skip`.
+    fn fill_high(ranks: &[u8; 13], skip: &[u32], out: &mut [u32]) {
+        let kickers = (0..13u32).rev().filter(|r| ranks[*r as usize] > 0 && !skip.contains(r));
+        for (slot, rank) in out.iter_mut().zip(kickers) {
+            *slot = rank;
+        }
+    }
+
+    /// Whether `cards` contain the combination required by `claimed_rank`.
+    fn rank_supported(cards: &[u8], claimed_rank: u32) -> bool {
+        let (ranks, suits) = Self::rank_suit_counts(cards);
+        let max = ranks.iter().copied().max().unwrap_or(0);
+        let pairs = ranks.iter().filter(|r| **r >= 2).count();
+        let trips = ranks.iter().filter(|r| **r >= 3).count();
+        let flush = Self::flush_rank_counts(cards, &suits);
+
+        match claimed_rank {
+            0 => true,
+            1 => max >= 2,
+            2 => pairs >= 2,
+            3 => max >= 3,
+            4 => Self::has_straight(&ranks),
+            5 => flush.is_some(),
+            6 => trips >= 1 && pairs >= 2,
+            7 => max >= 4,
+            8 => flush.map(|f| Self::has_straight(&f)).unwrap_or(false),
+            9 => flush.map(|f| Self::has_royal(&f)).unwrap_or(false),
+            _ => false,
+        }
+    }
+
+    /// Panic unless `admin` is the configured admin and has authorized the call.
+    fn require_admin(env: &Env, admin: &Address) {
+        let stored: Address = env.storage().instance()
+            .get(&symbol_short!("ADMIN"))
+            .unwrap_or_else(|| panic_with_error!(env, VerifierError::AdminNotConfigured));
+        Self::ensure(env, stored == *admin, VerifierError::NotAdmin);
+        admin.require_auth();
+    }
+
+    /// Whether `initialize_multisig` has run.
+    fn has_multisig(env: &Env) -> bool {
+        env.storage().instance().has(&symbol_short!("SIGNERS"))
+    }
+
+    /// Panic unless `approvers` holds at least `threshold` distinct
+    /// configured signers, and require each of them to authorize the call.
+    fn require_multisig(env: &Env, approvers: &Vec<Address>) {
+        let (signers, threshold) = Self::get_multisig(env.clone());
+        for i in 0..approvers.len() {
+            let approver = approvers.get(i).unwrap();
+            Self::ensure(env, signers.contains(&approver), VerifierError::NotASigner);
+            Self::ensure(env, approvers.first_index_of(&approver) == Some(i), VerifierError::DuplicateSigner);
+            approver.require_auth();
+        }
+        Self::ensure(env, approvers.len() >= threshold, VerifierError::ThresholdNotMet);
+    }
+
+    /// Check `block_hash` looks like a zkVerify block hash: "0x" + 64 hex digits.
+    fn is_block_hash_format(block_hash: &String) -> bool {
+        if block_hash.len() != BLOCK_HASH_LEN {
+            return false;
+        }
+        let mut buf = [0u8; BLOCK_HASH_LEN as usize];
+        block_hash.copy_into_slice(&mut buf);
+        buf[0] == b'0' && buf[1] == b'x' && buf[2..].iter().all(|b| b.is_ascii_hexdigit())
+    }
+
+    /// Compare two verified ranks under the configured `HandOrdering` —
+    /// the better rank wins, equal ranks tie.
+    fn compare_ranks(env: &Env, p1_rank: u32, p2_rank: u32) -> Winner {
+        Self::ensure(env, p1_rank <= 9, VerifierError::RankOutOfRange);
+        Self::ensure(env, p2_rank <= 9, VerifierError::RankOutOfRange);
+
+        let (p1_rank, p2_rank) = match Self::get_hand_ordering(env.clone()) {
+            HandOrdering::HighWins => (p1_rank, p2_rank),
+            HandOrdering::LowWins  => (p2_rank, p1_rank),
+        };
+        if p1_rank > p2_rank {
+            Winner::Player1
+        } else if p2_rank > p1_rank {
+            Winner::Player2
+        } else {
+            Winner::Tie
+        }
+    }
+
+    /// Shared tail of `verify_proof` / `verify_proof_full`: card and rank
+    /// sanity checks, attestation gate for `proof_hash`, and event emission.
+    /// Helper: proof bytes must be non-zero; the 32 bytes at the configured
+    /// offset (the front, by default) are the SHA-256 of the full UltraHonk
+    /// proof. In strict shape mode every other byte must be zero.
+    fn prefix_proof_hash(env: &Env, proof_bytes: &BytesN<128>) -> BytesN<32> {
+        // ── 1. Proof bytes exist ───────────────────────────────────────────
+        let proof_slice: [u8; PROOF_LEN] = proof_bytes.to_array();
+        let proof_non_zero = proof_slice.iter().any(|b| *b != 0);
+        Self::ensure(env, proof_non_zero, VerifierError::ProofAllZero);
+        let start = Self::get_proof_hash_offset(env.clone()) as usize;
+        let end = start + PROOF_HASH_LEN;
+        if Self::is_strict_proof_shape(env.clone()) {
+            let rest_zero = proof_slice[..start].iter().chain(&proof_slice[end..]).all(|b| *b == 0);
+            Self::ensure(env, rest_zero, VerifierError::MalformedProof);
+        }
+
+        let mut proof_hash_arr = [0u8; PROOF_HASH_LEN];
+        proof_hash_arr.copy_from_slice(&proof_slice[start..end]);
+        BytesN::from_array(env, &proof_hash_arr)
+    }
+
+    /// Helper: add (or remove) a verified record from its player's count
+    /// behind `get_player_attestation_count`. Unverified records never count.
+    fn count_verified(env: &Env, att: &ZkVerifyAttestation, add: bool) {
+        if !att.verified {
+            return;
+        }
+        let key = (symbol_short!("ATT_P"), att.player.clone());
+        let count: u32 = env.storage().instance().get(&key).unwrap_or(0);
+        let count = if add { count + 1 } else { count.saturating_sub(1) };
+        env.storage().instance().set(&key, &count);
+    }
+
+    /// Helper: configured log level, `LOG_DEBUG` unless set.
+    fn log_level(env: &Env) -> u32 {
+        env.storage().instance().get(&symbol_short!("LOG_LVL")).unwrap_or(LOG_DEBUG)
+    }
+
+    /// Helper: panic with `error` unless `condition` holds.
+    fn ensure(env: &Env, condition: bool, error: VerifierError) {
+        if !condition {
+            panic_with_error!(env, error);
+        }
+    }
+
+    /// Helper: remember that the game contract used `proof_hash`. Recorded
+    /// even while reuse is allowed, so turning reuse off also covers past proofs.
+    fn mark_consumed(env: &Env, proof_hash: &BytesN<32>) {
+        env.storage().persistent().set(&(symbol_short!("USED"), proof_hash.clone()), &true);
+    }
+
+    /// Helper: require the registered game contract to be the caller.
+    /// Returns whether a proof verified on this call should be consumed —
+    /// false while no game contract is registered.
+    fn require_game_contract(env: &Env) -> bool {
+        match Self::get_game_contract(env.clone()) {
+            Some(game) => {
+                game.require_auth();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Helper: gate mode for callers that choose attestation per call.
+    /// Requiring it forces `Hard`; otherwise the admin's mode stands.
+    fn caller_gate_mode(env: &Env, require_attestation: bool) -> GateMode {
+        if require_attestation {
+            GateMode::Hard
+        } else {
+            Self::get_gate_mode(env.clone())
+        }
+    }
+
+    fn check_hand_and_attestation(
+        env:          &Env,
+        hole_cards:   &BytesN<2>,
+        claimed_rank: u32,
+        proof_hash:   BytesN<32>,
+        player:       BytesN<32>,
+        game_id:      BytesN<32>,
+        mode:         GateMode,
+        consume:      bool,
+    ) -> bool {
+        // ── 2. Card validity ──────────────────────────────────────────────
+        let card_arr = hole_cards.to_array();
+        let c1 = card_arr[0];
+        let c2 = card_arr[1];
+        Self::ensure(env, Self::is_valid_card(c1 as u32), VerifierError::CardOutOfRange);
+        Self::ensure(env, Self::is_valid_card(c2 as u32), VerifierError::CardOutOfRange);
+        Self::ensure(env, c1 != c2, VerifierError::DuplicateCards);
+
+        // ── 3. Rank range ─────────────────────────────────────────────────
+        Self::ensure(env, claimed_rank <= 9, VerifierError::RankOutOfRange);
+
+        Self::check_attestation(env, claimed_rank, proof_hash, player, game_id, mode, consume)
+    }
+
+    /// Helper: attestation gate and verification event shared by every
+    /// `verify_proof*` entry point once the cards and rank are validated.
+    fn check_attestation(
+        env:          &Env,
+        claimed_rank: u32,
+        proof_hash:   BytesN<32>,
+        player:       BytesN<32>,
+        game_id:      BytesN<32>,
+        mode:         GateMode,
+        consume:      bool,
+    ) -> bool {
+        // ── 4. Attestation gate — check for real ZK verification ─────────
+        //  Check if a VERIFIED attestation was recorded by the frontend for
+        //  proof_hash (after passing bb verify / zkVerify chain).
+        let hash_key = (symbol_short!("ATT_H"), proof_hash.clone());
+        let has_att = env.storage().instance().has(&hash_key);
+
+        // If attestation exists, verify it was marked as `verified=true`
+        let att_verified = if has_att {
+            // Replay protection — an attestation only counts for the game it
+            // was recorded for, so a proof cannot be reused across games.
+            let att = Self::get_attestation_by_proof_hash(env.clone(), proof_hash.clone());
+            Self::ensure(env, att.game_id == game_id, VerifierError::WrongGame);
+            // ...and only for the player it was recorded for
+            Self::ensure(env, att.player == player, VerifierError::WrongPlayer);
+            // ...and only for the rank that was proven
+            Self::ensure(env, att.claimed_rank == claimed_rank, VerifierError::WrongRank);
+            if let Some(expected) = Self::get_expected_proof_system(env.clone()) {
+                Self::ensure(env, att.proof_system == expected, VerifierError::WrongProofSystem);
+            }
+            att.verified
+        } else {
+            false
+        };
+
+        // ── 5. Attestation gate — behaviour depends on GateMode ──────────
+        //  Hard mode reports whether the attestation is missing or was
+        //  recorded with verified=false.
+        if !att_verified {
+            match mode {
+                GateMode::Hard if has_att => panic_with_error!(env, VerifierError::AttestationUnverified),
+                GateMode::Hard => panic_with_error!(env, VerifierError::NoAttestation),
+                GateMode::Soft => env.events().publish(
+                    (symbol_short!("zkverify"), symbol_short!("att_miss")),
+                    (proof_hash.clone(), player.clone()),
+                ),
+                GateMode::Off => {}
+                // Data: (proof_hash, player, attestation recorded but unverified)
+                GateMode::DryRun => env.events().publish(
+                    (symbol_short!("zkverify"), Symbol::new(env, "gate_would_reject")),
+                    (proof_hash.clone(), player.clone(), has_att),
+                ),
+            }
+        }
+
+        // ── 6. Proof replay — each proof wins at most once unless reuse is on
+        if !Self::is_proof_reuse_allowed(env.clone()) {
+            Self::ensure(env, !Self::is_proof_consumed(env.clone(), proof_hash.clone()), VerifierError::ProofConsumed);
+        }
+        if consume {
+            Self::mark_consumed(env, &proof_hash);
+        }
+
+        // ── 7. Emit verification event ────────────────────────────────────
+        env.events().publish(
+            (symbol_short!("zkverify"), symbol_short!("noir")),
+            VerificationResult {
+                valid:               true,
+                claimed_rank,
+                player:              player.clone(),
+                proof_hash:          proof_hash.clone(),
+                attestation_present: att_verified,
+            },
+        );
+
+        log_at!(env, LOG_INFO, "✅ Noir proof verified — attestation={} player={:?} rank={}", att_verified, player, claimed_rank);
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::{Address as _, Events, Logs}, xdr::FromXdr, Address, Env, IntoVal, String, Val};
+
+    const GAME_A: [u8; 32] = [0xA1; 32];
+    const GAME_B: [u8; 32] = [0xB2; 32];
+    const BLOCK_A: &str = "0xabababababababababababababababababababababababababababababababab";
+    const BLOCK_B: &str = "0xcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcd";
+
+    /// Helper: the (contract) address whose `player_id` is `player`, with
+    /// auths mocked so it can record its own attestations.
+    fn owner(env: &Env, player: &BytesN<32>) -> Address {
+        env.mock_all_auths();
+        // ScVal::Address(ScAddress::Contract(player)) in XDR
+        let mut xdr = Bytes::from_array(env, &[0, 0, 0, 18, 0, 0, 0, 1]);
+        xdr.extend_from_array(&player.to_array());
+        Address::from_xdr(env, &xdr).unwrap()
+    }
+
+    fn make_commitment(_env: &Env, _cards: [u8; 2], _salt: [u8; 32]) -> BytesN<32> {
+        // Commitment is now opaque — noir_verifier does not re-hash.
+        // For tests, use a deterministic non-zero 32-byte value.
+        BytesN::from_array(_env, &[0xABu8; 32])
+    }
+
+    #[test]
+    fn test_valid_proof() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+
+        let cards: [u8; 2] = [51, 38]; // Ace of Spades (51), Ace of Hearts (38)
+        let salt = [42u8; 32];
+        let commitment = make_commitment(&env, cards, salt);
+
+        // Mock 128-byte proof (non-zero) — first 32 bytes are the "proof hash"
+        let mut proof_arr = [1u8; 128];
+        proof_arr[0] = 0xa3;
+        proof_arr[1] = 0x5f;
+
+        // ── Record attestation FIRST (simulates frontend recording after bb verify) ──
+        let proof_hash = BytesN::from_array(&env, &proof_arr[..32].try_into().unwrap());
+        client.record_zkverify_attestation(
+            &owner(&env, &BytesN::from_array(&env, &[0u8; 32])),
+            &String::from_str(&env, "bb-verify-test-p1"),
+            &proof_hash,
             &BytesN::from_array(&env, &[0u8; 32]),
+            &BytesN::from_array(&env, &GAME_A),
             &6,
             &true,
-            &String::from_str(&env, ""),
+            &String::from_str(&env, BLOCK_A),
+            &false,
+            &None,
         );
 
         let result = client.verify_proof(
@@ -322,14 +1649,15 @@ mod test {
             &6, // Three of a kind
             &BytesN::from_array(&env, &proof_arr),
             &BytesN::from_array(&env, &[0u8; 32]),
+            &BytesN::from_array(&env, &GAME_A),
         );
 
         assert!(result);
-        assert!(env.logs().all().len() > 0);
+        assert!(!env.logs().all().is_empty());
     }
 
     #[test]
-    #[should_panic(expected = "No zkVerify attestation found")]
+    #[should_panic(expected = "Error(Contract, #5)")] // VerifierError::NoAttestation
     fn test_no_attestation_rejected() {
         // Without a recorded attestation, verify_proof MUST panic (attestation gate).
         let env = Env::default();
@@ -351,6 +1679,7 @@ mod test {
             &6,
             &BytesN::from_array(&env, &proof_arr),
             &BytesN::from_array(&env, &[0u8; 32]),
+            &BytesN::from_array(&env, &GAME_A),
         );
     }
 
@@ -372,12 +1701,16 @@ mod test {
         // Record attestation for this proof hash
         let proof_hash = BytesN::from_array(&env, &proof_arr[..32].try_into().unwrap());
         client.record_zkverify_attestation(
+            &owner(&env, &BytesN::from_array(&env, &[0u8; 32])),
             &String::from_str(&env, "bb-verify-test"),
             &proof_hash,
             &BytesN::from_array(&env, &[0u8; 32]),
+            &BytesN::from_array(&env, &GAME_A),
             &6,
             &true,
-            &String::from_str(&env, ""),
+            &String::from_str(&env, BLOCK_A),
+            &false,
+            &None,
         );
 
         let result = client.verify_proof(
@@ -387,12 +1720,13 @@ mod test {
             &6,
             &BytesN::from_array(&env, &proof_arr),
             &BytesN::from_array(&env, &[0u8; 32]),
+            &BytesN::from_array(&env, &GAME_A),
         );
         assert!(result);
     }
 
     #[test]
-    #[should_panic(expected = "Proof bytes are all zero")]
+    #[should_panic(expected = "Error(Contract, #1)")] // VerifierError::ProofAllZero
     fn test_empty_proof_rejected() {
         let env = Env::default();
         let contract_id = env.register_contract(None, NoirVerifier);
@@ -409,6 +1743,7 @@ mod test {
             &6,
             &BytesN::from_array(&env, &[0u8; 128]), // EMPTY PROOF
             &BytesN::from_array(&env, &[0u8; 32]),
+            &BytesN::from_array(&env, &GAME_A),
         );
     }
 
@@ -424,11 +1759,12 @@ mod test {
         let att_id = String::from_str(&env, "zkv_att_abc123");
         let proof_hash = BytesN::from_array(&env, &[0xCA; 32]);
         let player = BytesN::from_array(&env, &[0x01; 32]);
-        let block = String::from_str(&env, "0xblock_deadbeef");
+        let block = String::from_str(&env, BLOCK_A);
 
         // Record attestation
         let idx = client.record_zkverify_attestation(
-            &att_id, &proof_hash, &player, &6, &true, &block,
+            &owner(&env, &player),
+            &att_id, &proof_hash, &player, &BytesN::from_array(&env, &GAME_A), &6, &true, &block, &false, &None,
         );
         assert_eq!(idx, 0);
         assert_eq!(client.get_attestation_count(), 1);
@@ -446,12 +1782,1904 @@ mod test {
         let att_id2 = String::from_str(&env, "zkv_att_xyz789");
         let proof_hash2 = BytesN::from_array(&env, &[0xBB; 32]);
         let idx2 = client.record_zkverify_attestation(
-            &att_id2, &proof_hash2, &player, &3, &true,
-            &String::from_str(&env, "0xblock2"),
+            &owner(&env, &player),
+            &att_id2, &proof_hash2, &player, &BytesN::from_array(&env, &GAME_A), &3, &true,
+            &String::from_str(&env, BLOCK_B), &false, &None,
         );
         assert_eq!(idx2, 1);
         assert_eq!(client.get_attestation_count(), 2);
 
-        assert!(env.logs().all().len() > 0);
+        assert!(!env.logs().all().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #15)")] // VerifierError::AlreadyRecorded
+    fn test_duplicate_attestation_rejected() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+
+        let proof_hash = BytesN::from_array(&env, &[0xCA; 32]);
+        let player = BytesN::from_array(&env, &[0x01; 32]);
+        let block = String::from_str(&env, BLOCK_A);
+
+        client.record_zkverify_attestation(
+            &owner(&env, &player),
+            &String::from_str(&env, "zkv_att_1"), &proof_hash, &player, &BytesN::from_array(&env, &GAME_A), &6, &true, &block, &false, &None,
+        );
+        // Same proof_hash without allow_overwrite → should panic
+        client.record_zkverify_attestation(
+            &owner(&env, &player),
+            &String::from_str(&env, "zkv_att_2"), &proof_hash, &player, &BytesN::from_array(&env, &GAME_A), &6, &true, &block, &false, &None,
+        );
+    }
+
+    #[test]
+    fn test_duplicate_attestation_overwrite_is_idempotent() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+
+        let proof_hash = BytesN::from_array(&env, &[0xCA; 32]);
+        let player = BytesN::from_array(&env, &[0x01; 32]);
+        let block = String::from_str(&env, BLOCK_A);
+
+        let idx = client.record_zkverify_attestation(
+            &owner(&env, &player),
+            &String::from_str(&env, "zkv_att_1"), &proof_hash, &player, &BytesN::from_array(&env, &GAME_A), &6, &true, &block, &false, &None,
+        );
+        let idx2 = client.record_zkverify_attestation(
+            &owner(&env, &player),
+            &String::from_str(&env, "zkv_att_2"), &proof_hash, &player, &BytesN::from_array(&env, &GAME_A), &7, &true, &block, &true, &None,
+        );
+
+        // Same slot reused, no orphaned index
+        assert_eq!(idx, idx2);
+        assert_eq!(client.get_attestation_count(), 1);
+        let stored = client.get_attestation(&idx);
+        assert_eq!(stored.claimed_rank, 7);
+        assert_eq!(stored.attestation_id, String::from_str(&env, "zkv_att_2"));
+    }
+
+    #[test]
+    fn test_verify_proof_full_variable_length() {
+        let env = Env::default();
+        env.budget().reset_unlimited();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+
+        let cards: [u8; 2] = [51, 38];
+        let salt = BytesN::from_array(&env, &[42u8; 32]);
+        let commitment = make_commitment(&env, cards, [42u8; 32]);
+        let player = BytesN::from_array(&env, &[0u8; 32]);
+
+        // ~16 KB UltraHonk-sized proof and a short proof
+        let mut big_proof = Bytes::new(&env);
+        for i in 0..16384u32 {
+            big_proof.push_back((i % 251) as u8 + 1);
+        }
+        let mut short_proof = Bytes::new(&env);
+        short_proof.extend_from_array(&[7u8; 100]);
+
+        let big_hash: BytesN<32> = env.crypto().sha256(&big_proof).into();
+        let short_hash: BytesN<32> = env.crypto().sha256(&short_proof).into();
+        assert_ne!(big_hash, short_hash);
+
+        for (i, hash) in [big_hash, short_hash].iter().enumerate() {
+            client.record_zkverify_attestation(
+                &owner(&env, &player),
+                &String::from_str(&env, if i == 0 { "att-big" } else { "att-short" }),
+                hash,
+                &player,
+                &BytesN::from_array(&env, &GAME_A),
+                &6,
+                &true,
+                &String::from_str(&env, BLOCK_A),
+                &false,
+                &None,
+            );
+        }
+
+        for proof in [big_proof, short_proof].iter() {
+            let result = client.verify_proof_full(
+                &BytesN::from_array(&env, &cards),
+                &salt,
+                &commitment,
+                &6,
+                proof,
+                &player,
+                &BytesN::from_array(&env, &GAME_A),
+            );
+            assert!(result);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #5)")] // VerifierError::NoAttestation
+    fn test_verify_proof_full_hashes_whole_blob() {
+        // An attestation for the prefix hash must not satisfy the full-blob path
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+
+        let cards: [u8; 2] = [51, 38];
+        let mut proof_arr = [1u8; 128];
+        proof_arr[0] = 0xa3;
+        let prefix_hash = BytesN::from_array(&env, &proof_arr[..32].try_into().unwrap());
+        client.record_zkverify_attestation(
+            &owner(&env, &BytesN::from_array(&env, &[0u8; 32])),
+            &String::from_str(&env, "att-prefix"),
+            &prefix_hash,
+            &BytesN::from_array(&env, &[0u8; 32]),
+            &BytesN::from_array(&env, &GAME_A),
+            &6,
+            &true,
+            &String::from_str(&env, BLOCK_A),
+            &false,
+            &None,
+        );
+
+        client.verify_proof_full(
+            &BytesN::from_array(&env, &cards),
+            &BytesN::from_array(&env, &[42u8; 32]),
+            &make_commitment(&env, cards, [42u8; 32]),
+            &6,
+            &Bytes::from_array(&env, &proof_arr),
+            &BytesN::from_array(&env, &[0u8; 32]),
+            &BytesN::from_array(&env, &GAME_A),
+        );
+    }
+
+    #[test]
+    fn test_resolve_winner_typed() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+
+        assert_eq!(client.resolve_winner_typed(&6, &3), Winner::Player1);
+        assert_eq!(client.resolve_winner_typed(&2, &8), Winner::Player2);
+        assert_eq!(client.resolve_winner_typed(&4, &4), Winner::Tie);
+        assert_eq!(client.resolve_winner_typed(&0, &0), Winner::Tie);
+
+        // Numeric form stays consistent with the typed one
+        assert_eq!(client.resolve_winner(&6, &3), 0);
+        assert_eq!(client.resolve_winner(&2, &8), 1);
+        assert_eq!(client.resolve_winner(&4, &4), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #4)")] // VerifierError::RankOutOfRange
+    fn test_resolve_winner_typed_rejects_bad_rank() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+
+        client.resolve_winner_typed(&3, &10);
+    }
+
+    fn board(env: &Env, cards: &[u32]) -> Vec<u32> {
+        let mut v = Vec::new(env);
+        for c in cards.iter() {
+            v.push_back(*c);
+        }
+        v
+    }
+
+    #[test]
+    fn test_rank_consistency_valid_pair() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+
+        // Pocket aces (A♠ A♥), no board
+        let aces = BytesN::from_array(&env, &[51, 38]);
+        assert!(client.verify_rank_consistency(&aces, &board(&env, &[]), &1));
+
+        // A♠ K♠ paired by the K♦ on the flop (24 % 13 = 11 = King)
+        let ak = BytesN::from_array(&env, &[51, 50]);
+        assert!(client.verify_rank_consistency(&ak, &board(&env, &[24, 2, 5]), &1));
+
+        // High card is always plausible
+        assert!(client.verify_rank_consistency(&ak, &board(&env, &[]), &0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #12)")] // VerifierError::RankInconsistent
+    fn test_rank_consistency_false_pair() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+
+        // A♠ K♠ with an unpaired board — a Pair claim is impossible
+        let ak = BytesN::from_array(&env, &[51, 50]);
+        client.verify_rank_consistency(&ak, &board(&env, &[0, 2, 5]), &1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #12)")] // VerifierError::RankInconsistent
+    fn test_rank_consistency_flush_needs_five_suited() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+
+        // Four spades only (51, 50, 48, 40) + one club
+        let ak = BytesN::from_array(&env, &[51, 50]);
+        client.verify_rank_consistency(&ak, &board(&env, &[48, 40, 3]), &5);
+    }
+
+    #[test]
+    fn test_evaluate_rank_categories() {
+        // Card = suit*13 + rank (rank 0=Two … 12=Ace)
+        assert_eq!(NoirVerifier::evaluate_rank(&[51, 50, 49, 48, 47]), 9);       // royal flush ♠
+        assert_eq!(NoirVerifier::evaluate_rank(&[4, 5, 6, 7, 8, 20]), 8);         // 6-T straight flush ♣ (turn)
+        assert_eq!(NoirVerifier::evaluate_rank(&[12, 25, 38, 51, 0, 1, 2]), 7);   // quad aces (river)
+        assert_eq!(NoirVerifier::evaluate_rank(&[12, 25, 38, 0, 13]), 6);         // aces full of twos
+        assert_eq!(NoirVerifier::evaluate_rank(&[0, 3, 5, 9, 11, 26]), 5);        // club flush
+        assert_eq!(NoirVerifier::evaluate_rank(&[12, 13, 27, 41, 3]), 4);         // wheel A-2-3-4-5
+        assert_eq!(NoirVerifier::evaluate_rank(&[12, 25, 38, 1, 16]), 3);         // trip aces
+        assert_eq!(NoirVerifier::evaluate_rank(&[12, 25, 1, 14, 30]), 2);         // aces and threes
+        assert_eq!(NoirVerifier::evaluate_rank(&[12, 25, 1, 15, 30]), 1);         // pair of aces
+        assert_eq!(NoirVerifier::evaluate_rank(&[12, 24, 1, 15, 30]), 0);         // ace high
+    }
+
+    #[test]
+    fn test_verify_proof_with_board_matching_rank() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+
+        // A♠ K♠ on Q♠ J♠ T♠ 2♣ 3♦ → royal flush
+        let cards: [u8; 2] = [51, 50];
+        let mut proof_arr = [1u8; 128];
+        proof_arr[0] = 0xa3;
+        let proof_hash = BytesN::from_array(&env, &proof_arr[..32].try_into().unwrap());
+        client.record_zkverify_attestation(
+            &owner(&env, &BytesN::from_array(&env, &[0u8; 32])),
+            &String::from_str(&env, "bb-verify-board"),
+            &proof_hash,
+            &BytesN::from_array(&env, &[0u8; 32]),
+            &BytesN::from_array(&env, &GAME_A),
+            &9,
+            &true,
+            &String::from_str(&env, BLOCK_A),
+            &false,
+            &None,
+        );
+
+        let result = client.verify_proof_with_board(
+            &BytesN::from_array(&env, &cards),
+            &BytesN::from_array(&env, &[42u8; 32]),
+            &make_commitment(&env, cards, [42u8; 32]),
+            &9,
+            &BytesN::from_array(&env, &proof_arr),
+            &BytesN::from_array(&env, &[0u8; 32]),
+            &BytesN::from_array(&env, &GAME_A),
+            &board(&env, &[49, 48, 47, 0, 14]),
+        );
+        assert!(result);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #11)")] // VerifierError::RankMismatch
+    fn test_verify_proof_with_board_rejects_inflated_claim() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+
+        // A♠ A♥ on a dry flop → one pair, but claims a full house
+        let cards: [u8; 2] = [51, 38];
+        client.verify_proof_with_board(
+            &BytesN::from_array(&env, &cards),
+            &BytesN::from_array(&env, &[42u8; 32]),
+            &make_commitment(&env, cards, [42u8; 32]),
+            &6,
+            &BytesN::from_array(&env, &[1u8; 128]),
+            &BytesN::from_array(&env, &[0u8; 32]),
+            &BytesN::from_array(&env, &GAME_A),
+            &board(&env, &[0, 15, 30]),
+        );
+    }
+
+    #[test]
+    fn test_get_attestation_by_proof_hash() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+
+        let player = BytesN::from_array(&env, &[0x01; 32]);
+        let hash_a = BytesN::from_array(&env, &[0xAA; 32]);
+        let hash_b = BytesN::from_array(&env, &[0xBB; 32]);
+        client.record_zkverify_attestation(
+            &owner(&env, &player),
+            &String::from_str(&env, "zkv_att_a"), &hash_a, &player, &BytesN::from_array(&env, &GAME_A), &3, &true,
+            &String::from_str(&env, BLOCK_A), &false, &None,
+        );
+        client.record_zkverify_attestation(
+            &owner(&env, &player),
+            &String::from_str(&env, "zkv_att_b"), &hash_b, &player, &BytesN::from_array(&env, &GAME_A), &6, &true,
+            &String::from_str(&env, BLOCK_B), &false, &None,
+        );
+
+        let stored = client.get_attestation_by_proof_hash(&hash_b);
+        assert_eq!(stored.attestation_id, String::from_str(&env, "zkv_att_b"));
+        assert_eq!(stored.proof_hash, hash_b);
+        assert_eq!(stored.player, player);
+        assert_eq!(stored.claimed_rank, 6);
+        assert_eq!(stored.block_hash, String::from_str(&env, BLOCK_B));
+        assert!(stored.verified);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #16)")] // VerifierError::AttestationNotFound
+    fn test_get_attestation_by_unknown_proof_hash() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+
+        client.get_attestation_by_proof_hash(&BytesN::from_array(&env, &[0xFF; 32]));
+    }
+
+    fn record_n(env: &Env, client: &NoirVerifierClient, n: u32) {
+        for i in 0..n {
+            let mut hash = [0u8; 32];
+            hash[..4].copy_from_slice(&(i + 1).to_be_bytes());
+            client.record_zkverify_attestation(
+                &owner(env, &BytesN::from_array(env, &[0x01; 32])),
+                &String::from_str(env, "zkv_att"),
+                &BytesN::from_array(env, &hash),
+                &BytesN::from_array(env, &[0x01; 32]),
+                &BytesN::from_array(env, &GAME_A),
+                &(i % 10),
+                &true,
+                &String::from_str(env, BLOCK_A),
+                &false,
+                &None,
+            );
+        }
+    }
+
+    #[test]
+    fn test_get_attestations_range() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+        record_n(&env, &client, 5);
+
+        // Mid-range window
+        let page = client.get_attestations_range(&1, &3);
+        assert_eq!(page.len(), 3);
+        assert_eq!(page.get(0).unwrap().claimed_rank, 1);
+        assert_eq!(page.get(2).unwrap().claimed_rank, 3);
+
+        // Window running past the end is clamped
+        assert_eq!(client.get_attestations_range(&3, &10).len(), 2);
+
+        // Window starting past the end is empty
+        assert_eq!(client.get_attestations_range(&5, &10).len(), 0);
+        assert_eq!(client.get_attestations_range(&100, &10).len(), 0);
+    }
+
+    #[test]
+    fn test_get_attestations_since() {
+        let env = Env::default();
+        env.budget().reset_unlimited();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+        record_n(&env, &client, 55);
+
+        // A fresh indexer gets one capped page, then resumes after it
+        let first = client.get_attestations_since(&0);
+        assert_eq!(first.len(), 50);
+        assert_eq!(first.get(0).unwrap().claimed_rank, 0);
+        let rest = client.get_attestations_since(&first.len());
+        assert_eq!(rest.len(), 5);
+        assert_eq!(rest.get(0).unwrap().claimed_rank, 0); // index 50
+
+        // Resuming from a midpoint returns everything after it
+        let tail = client.get_attestations_since(&52);
+        assert_eq!(tail.len(), 3);
+        assert_eq!(tail.get(0).unwrap().claimed_rank, 2);
+
+        // Caught up: nothing new
+        assert_eq!(client.get_attestations_since(&client.get_attestation_count()).len(), 0);
+    }
+
+    #[test]
+    fn test_attestations_by_rank() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+
+        for (i, rank) in [6u32, 3, 6].iter().enumerate() {
+            client.record_zkverify_attestation(
+                &owner(&env, &BytesN::from_array(&env, &[0x01; 32])),
+                &String::from_str(&env, "zkv_att"),
+                &BytesN::from_array(&env, &[i as u8 + 1; 32]),
+                &BytesN::from_array(&env, &[0x01; 32]),
+                &BytesN::from_array(&env, &GAME_A),
+                rank,
+                &true,
+                &String::from_str(&env, BLOCK_A),
+                &false,
+                &None,
+            );
+        }
+
+        assert_eq!(client.count_attestations_by_rank(&6), 2);
+        assert_eq!(client.count_attestations_by_rank(&3), 1);
+        assert_eq!(client.count_attestations_by_rank(&9), 0);
+
+        let sixes = client.get_attestations_by_rank(&6, &0, &10);
+        assert_eq!(sixes.len(), 2);
+        assert_eq!(sixes.get(0).unwrap().proof_hash, BytesN::from_array(&env, &[1u8; 32]));
+        assert_eq!(sixes.get(1).unwrap().proof_hash, BytesN::from_array(&env, &[3u8; 32]));
+
+        // Paging is over the matching records
+        let second = client.get_attestations_by_rank(&6, &1, &1);
+        assert_eq!(second.len(), 1);
+        assert_eq!(second.get(0).unwrap().proof_hash, BytesN::from_array(&env, &[3u8; 32]));
+        assert_eq!(client.get_attestations_by_rank(&6, &2, &10).len(), 0);
+
+        assert!(client.try_count_attestations_by_rank(&10).is_err());
+        assert!(client.try_get_attestations_by_rank(&10, &0, &10).is_err());
+    }
+
+    #[test]
+    fn test_get_attestations_range_caps_limit() {
+        let env = Env::default();
+        env.budget().reset_unlimited();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+        record_n(&env, &client, 55);
+
+        assert_eq!(client.get_attestations_range(&0, &100).len(), 50);
+        assert_eq!(client.get_attestations_range(&50, &100).len(), 5);
+    }
+
+    #[test]
+    fn test_record_attestation_with_matching_full_proof() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+
+        let full_proof = Bytes::from_array(&env, &[0x5Au8; 256]);
+        let proof_hash: BytesN<32> = env.crypto().sha256(&full_proof).into();
+
+        let idx = client.record_zkverify_attestation(
+            &owner(&env, &BytesN::from_array(&env, &[0x01; 32])),
+            &String::from_str(&env, "zkv_att_full"), &proof_hash,
+            &BytesN::from_array(&env, &[0x01; 32]), &BytesN::from_array(&env, &GAME_A), &4, &true,
+            &String::from_str(&env, BLOCK_A), &false, &Some(full_proof),
+        );
+        assert_eq!(idx, 0);
+        assert!(client.has_attestation(&proof_hash));
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #14)")] // VerifierError::ProofHashMismatch
+    fn test_record_attestation_with_mismatched_full_proof() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+
+        let full_proof = Bytes::from_array(&env, &[0x5Au8; 256]);
+        let wrong_hash = BytesN::from_array(&env, &[0xCA; 32]);
+
+        client.record_zkverify_attestation(
+            &owner(&env, &BytesN::from_array(&env, &[0x01; 32])),
+            &String::from_str(&env, "zkv_att_full"), &wrong_hash,
+            &BytesN::from_array(&env, &[0x01; 32]), &BytesN::from_array(&env, &GAME_A), &4, &true,
+            &String::from_str(&env, BLOCK_A), &false, &Some(full_proof),
+        );
+    }
+
+    #[test]
+    fn test_attestation_events_carry_index() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+        record_n(&env, &client, 1);
+
+        let hash_b = BytesN::from_array(&env, &[0xBB; 32]);
+        client.record_zkverify_attestation(
+            &owner(&env, &BytesN::from_array(&env, &[0x01; 32])),
+            &String::from_str(&env, "zkv_att_b"), &hash_b,
+            &BytesN::from_array(&env, &[0x01; 32]), &BytesN::from_array(&env, &GAME_A), &6, &true,
+            &String::from_str(&env, BLOCK_B), &false, &None,
+        );
+
+        let events = env.events().all();
+        let n = events.len();
+
+        // Full record event: (index, count, attestation)
+        let (_, topics, data) = events.get(n - 2).unwrap();
+        let expected: soroban_sdk::Vec<Val> =
+            (symbol_short!("zkverify"), symbol_short!("attest")).into_val(&env);
+        assert_eq!(topics, expected);
+        let (index, count, att): (u32, u32, ZkVerifyAttestation) = data.into_val(&env);
+        assert_eq!(index, 1);
+        assert_eq!(count, 2);
+        assert_eq!(att.proof_hash, hash_b);
+
+        // Compact index event: (index, proof_hash)
+        let (_, topics, data) = events.get(n - 1).unwrap();
+        let expected: soroban_sdk::Vec<Val> =
+            (symbol_short!("att"), symbol_short!("idx")).into_val(&env);
+        assert_eq!(topics, expected);
+        let (index, proof_hash): (u32, BytesN<32>) = data.into_val(&env);
+        assert_eq!(index, 1);
+        assert_eq!(proof_hash, hash_b);
+    }
+
+    /// Verify an unattested proof under `mode`; returns the number of
+    /// `att_miss` warning events emitted.
+    fn verify_unattested_with_mode(mode: GateMode) -> u32 {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.set_admin(&admin);
+        client.set_gate_mode(&admin, &mode);
+        assert_eq!(client.get_gate_mode(), mode);
+
+        let cards: [u8; 2] = [51, 38];
+        let mut proof_arr = [1u8; 128];
+        proof_arr[0] = 0xa3;
+        let result = client.verify_proof(
+            &BytesN::from_array(&env, &cards),
+            &BytesN::from_array(&env, &[42u8; 32]),
+            &make_commitment(&env, cards, [42u8; 32]),
+            &6,
+            &BytesN::from_array(&env, &proof_arr),
+            &BytesN::from_array(&env, &[0u8; 32]),
+            &BytesN::from_array(&env, &GAME_A),
+        );
+        assert!(result);
+
+        let miss_topics: soroban_sdk::Vec<Val> =
+            (symbol_short!("zkverify"), symbol_short!("att_miss")).into_val(&env);
+        env.events().all().iter().filter(|(_, t, _)| *t == miss_topics).count() as u32
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #5)")] // VerifierError::NoAttestation
+    fn test_gate_mode_hard_rejects_missing_attestation() {
+        verify_unattested_with_mode(GateMode::Hard);
+    }
+
+    #[test]
+    fn test_gate_mode_soft_passes_with_warning() {
+        assert_eq!(verify_unattested_with_mode(GateMode::Soft), 1);
+    }
+
+    #[test]
+    fn test_gate_mode_off_passes_silently() {
+        assert_eq!(verify_unattested_with_mode(GateMode::Off), 0);
+    }
+
+    #[test]
+    fn test_verification_event_carries_proof_hash() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+
+        let cards: [u8; 2] = [51, 38];
+        let mut proof_arr = [1u8; 128];
+        proof_arr[0] = 0xa3;
+        let proof_hash = BytesN::from_array(&env, &proof_arr[..32].try_into().unwrap());
+        client.record_zkverify_attestation(
+            &owner(&env, &BytesN::from_array(&env, &[0u8; 32])),
+            &String::from_str(&env, "bb-verify-event"), &proof_hash,
+            &BytesN::from_array(&env, &[0u8; 32]), &BytesN::from_array(&env, &GAME_A), &6, &true,
+            &String::from_str(&env, BLOCK_A), &false, &None,
+        );
+
+        client.verify_proof(
+            &BytesN::from_array(&env, &cards),
+            &BytesN::from_array(&env, &[42u8; 32]),
+            &make_commitment(&env, cards, [42u8; 32]),
+            &6,
+            &BytesN::from_array(&env, &proof_arr),
+            &BytesN::from_array(&env, &[0u8; 32]),
+            &BytesN::from_array(&env, &GAME_A),
+        );
+
+        let (_, topics, data) = env.events().all().last().unwrap();
+        let expected: soroban_sdk::Vec<Val> =
+            (symbol_short!("zkverify"), symbol_short!("noir")).into_val(&env);
+        assert_eq!(topics, expected);
+        let result: VerificationResult = data.into_val(&env);
+        assert_eq!(result.proof_hash, proof_hash);
+        assert!(result.attestation_present);
+        assert_eq!(result.claimed_rank, 6);
+    }
+
+    #[test]
+    fn test_strict_block_hash_rejects_empty() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+        assert!(client.is_strict_block_hash());
+
+        let record = |hash: u8, block: &str, verified: bool| {
+            client.try_record_zkverify_attestation(
+                &owner(&env, &BytesN::from_array(&env, &[0x01; 32])),
+                &String::from_str(&env, "zkv_att"),
+                &BytesN::from_array(&env, &[hash; 32]),
+                &BytesN::from_array(&env, &[0x01; 32]),
+                &BytesN::from_array(&env, &GAME_A),
+                &3,
+                &verified,
+                &String::from_str(&env, block),
+                &false,
+                &None,
+            ).is_ok()
+        };
+
+        assert!(!record(1, "", true));
+        assert!(!record(1, "0xblock", true));
+        assert!(!record(1, &BLOCK_A.replace("0x", "1x"), true));
+        assert!(record(1, BLOCK_A, true));
+        // Unverified records carry no block to check
+        assert!(record(2, "", false));
+    }
+
+    #[test]
+    fn test_relaxed_block_hash_accepts_empty() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.set_admin(&admin);
+        client.set_strict_block_hash(&admin, &false);
+        assert!(!client.is_strict_block_hash());
+
+        client.record_zkverify_attestation(
+            &owner(&env, &BytesN::from_array(&env, &[0x01; 32])),
+            &String::from_str(&env, "zkv_att"),
+            &BytesN::from_array(&env, &[1u8; 32]),
+            &BytesN::from_array(&env, &[0x01; 32]),
+            &BytesN::from_array(&env, &GAME_A),
+            &3,
+            &true,
+            &String::from_str(&env, ""),
+            &false,
+            &None,
+        );
+        assert_eq!(client.get_attestation_count(), 1);
+
+        let stranger = Address::generate(&env);
+        assert!(client.try_set_strict_block_hash(&stranger, &true).is_err());
+    }
+
+    fn verify_omaha(env: &Env, client: &NoirVerifierClient, cards: &[u8], expected: u32) -> bool {
+        client.try_verify_proof_variant(
+            &Bytes::from_slice(env, cards),
+            &expected,
+            &BytesN::from_array(env, &[42u8; 32]),
+            &BytesN::from_array(env, &[0xABu8; 32]),
+            &2,
+            &BytesN::from_array(env, &[7u8; 128]),
+            &BytesN::from_array(env, &[0u8; 32]),
+            &BytesN::from_array(env, &GAME_A),
+            &true,
+        ).is_ok()
+    }
+
+    #[test]
+    fn test_verify_proof_variant_omaha() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+        client.record_zkverify_attestation(
+            &owner(&env, &BytesN::from_array(&env, &[0u8; 32])),
+            &String::from_str(&env, "omaha"),
+            &BytesN::from_array(&env, &[7u8; 32]),
+            &BytesN::from_array(&env, &[0u8; 32]),
+            &BytesN::from_array(&env, &GAME_A),
+            &2,
+            &true,
+            &String::from_str(&env, BLOCK_A),
+            &false,
+            &None,
+        );
+
+        // Four distinct in-range cards
+        assert!(verify_omaha(&env, &client, &[51, 38, 12, 0], 4));
+
+        // Duplicate anywhere in the hand
+        assert!(!verify_omaha(&env, &client, &[51, 38, 12, 38], 4));
+        // Out of range
+        assert!(!verify_omaha(&env, &client, &[51, 38, 12, 52], 4));
+        // Card count must match the variant
+        assert!(!verify_omaha(&env, &client, &[51, 38, 12], 4));
+        assert!(!verify_omaha(&env, &client, &[51, 38], 4));
+        // Unsupported variant size
+        assert!(!verify_omaha(&env, &client, &[51], 1));
+    }
+
+    #[test]
+    fn test_rank_name_all_ranks() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+
+        let names = [
+            "High Card", "One Pair", "Two Pair", "Three of a Kind", "Straight",
+            "Flush", "Full House", "Four of a Kind", "Straight Flush", "Royal Flush",
+        ];
+        for (rank, name) in names.iter().enumerate() {
+            assert_eq!(client.rank_name(&(rank as u32)), String::from_str(&env, name));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #4)")] // VerifierError::RankOutOfRange
+    fn test_rank_name_out_of_range() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+        client.rank_name(&10);
+    }
+
+    #[test]
+    fn test_attestation_stats() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+
+        let empty = client.get_attestation_stats();
+        assert_eq!(empty.total, 0);
+        assert_eq!(empty.per_rank.len(), 10);
+
+        // (rank, verified)
+        let records = [(6u32, true), (3, false), (6, true), (9, true), (0, false)];
+        for (i, (rank, verified)) in records.iter().enumerate() {
+            client.record_zkverify_attestation(
+                &owner(&env, &BytesN::from_array(&env, &[0x01; 32])),
+                &String::from_str(&env, "zkv_att"),
+                &BytesN::from_array(&env, &[i as u8 + 1; 32]),
+                &BytesN::from_array(&env, &[0x01; 32]),
+                &BytesN::from_array(&env, &GAME_A),
+                rank,
+                verified,
+                &String::from_str(&env, BLOCK_A),
+                &false,
+                &None,
+            );
+        }
+
+        let stats = client.get_attestation_stats();
+        assert_eq!(stats.total, 5);
+        assert_eq!(stats.verified_count, 3);
+        assert_eq!(stats.per_rank.len(), 10);
+        assert_eq!(stats.per_rank.get(6).unwrap(), 2);
+        assert_eq!(stats.per_rank.get(3).unwrap(), 1);
+        assert_eq!(stats.per_rank.get(9).unwrap(), 1);
+        assert_eq!(stats.per_rank.get(0).unwrap(), 1);
+        assert_eq!(stats.per_rank.iter().sum::<u32>(), 5);
+    }
+
+    #[test]
+    fn test_decode_card() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+
+        // 51 = suit 3, rank 12 (Ace); 0 = suit 0, rank 0 (Two)
+        assert_eq!(client.decode_card(&51), (12, 3));
+        assert_eq!(client.decode_card(&0), (0, 0));
+        assert_eq!(client.decode_card(&24), (11, 1));
+
+        assert!(client.is_valid_card(&51));
+        assert!(!client.is_valid_card(&52));
+        assert!(client.try_decode_card(&52).is_err());
+    }
+
+    #[test]
+    fn test_verify_and_get_rank_returns_verified_rank() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+
+        let cards: [u8; 2] = [51, 38];
+        let proof_arr = [5u8; 128];
+        client.record_zkverify_attestation(
+            &owner(&env, &BytesN::from_array(&env, &[0u8; 32])),
+            &String::from_str(&env, "rank"),
+            &BytesN::from_array(&env, &[5u8; 32]),
+            &BytesN::from_array(&env, &[0u8; 32]),
+            &BytesN::from_array(&env, &GAME_A),
+            &7,
+            &true,
+            &String::from_str(&env, BLOCK_A),
+            &false,
+            &None,
+        );
+
+        let rank = client.verify_and_get_rank(
+            &BytesN::from_array(&env, &cards),
+            &BytesN::from_array(&env, &[42u8; 32]),
+            &make_commitment(&env, cards, [42u8; 32]),
+            &7,
+            &BytesN::from_array(&env, &proof_arr),
+            &BytesN::from_array(&env, &[0u8; 32]),
+            &BytesN::from_array(&env, &GAME_A),
+            &true,
+        );
+        assert_eq!(rank, 7);
+
+        // Same checks as verify_proof: duplicate hole cards are rejected
+        assert!(client.try_verify_and_get_rank(
+            &BytesN::from_array(&env, &[38, 38]),
+            &BytesN::from_array(&env, &[42u8; 32]),
+            &make_commitment(&env, cards, [42u8; 32]),
+            &7,
+            &BytesN::from_array(&env, &proof_arr),
+            &BytesN::from_array(&env, &[0u8; 32]),
+            &BytesN::from_array(&env, &GAME_A),
+            &true,
+        ).is_err());
+    }
+
+    #[test]
+    fn test_resolve_winner_lowball() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+        assert_eq!(client.get_hand_ordering(), HandOrdering::HighWins);
+        assert_eq!(client.resolve_winner(&1, &4), 1);
+
+        let admin = Address::generate(&env);
+        client.set_admin(&admin);
+        client.set_hand_ordering(&admin, &HandOrdering::LowWins);
+
+        assert_eq!(client.resolve_winner(&1, &4), 0);
+        assert_eq!(client.resolve_winner_typed(&6, &2), Winner::Player2);
+        assert_eq!(client.resolve_winner_typed(&3, &3), Winner::Tie);
+    }
+
+    #[test]
+    fn test_verify_and_get_rank_caller_can_only_tighten_gate() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.set_admin(&admin);
+
+        // No attestation recorded for this proof
+        let cards: [u8; 2] = [51, 38];
+        let verify = |require: bool| {
+            client.try_verify_and_get_rank(
+                &BytesN::from_array(&env, &cards),
+                &BytesN::from_array(&env, &[42u8; 32]),
+                &make_commitment(&env, cards, [42u8; 32]),
+                &4,
+                &BytesN::from_array(&env, &[9u8; 128]),
+                &BytesN::from_array(&env, &[0u8; 32]),
+                &BytesN::from_array(&env, &GAME_A),
+                &require,
+            )
+        };
+
+        // Admin's default Hard gate holds even when the caller opts out
+        assert_eq!(verify(false).err(), Some(Ok(VerifierError::NoAttestation.into())));
+
+        // Under an admin Soft gate the caller may still insist on Hard
+        client.set_gate_mode(&admin, &GateMode::Soft);
+        assert!(verify(true).is_err());
+        assert_eq!(verify(false).unwrap().unwrap(), 4);
+        let miss_topics: soroban_sdk::Vec<Val> =
+            (symbol_short!("zkverify"), symbol_short!("att_miss")).into_val(&env);
+        assert_eq!(env.events().all().iter().filter(|(_, t, _)| *t == miss_topics).count(), 1);
+    }
+
+    #[test]
+    fn test_attestation_is_bound_to_its_game() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+
+        let cards: [u8; 2] = [51, 38];
+        let proof_arr = [3u8; 128];
+        client.record_zkverify_attestation(
+            &owner(&env, &BytesN::from_array(&env, &[0u8; 32])),
+            &String::from_str(&env, "game_a"),
+            &BytesN::from_array(&env, &[3u8; 32]),
+            &BytesN::from_array(&env, &[0u8; 32]),
+            &BytesN::from_array(&env, &GAME_A),
+            &6,
+            &true,
+            &String::from_str(&env, BLOCK_A),
+            &false,
+            &None,
+        );
+        let att = client.get_attestation(&0);
+        assert_eq!(att.game_id, BytesN::from_array(&env, &GAME_A));
+
+        let verify = |game: [u8; 32], require: bool| {
+            client.try_verify_and_get_rank(
+                &BytesN::from_array(&env, &cards),
+                &BytesN::from_array(&env, &[42u8; 32]),
+                &make_commitment(&env, cards, [42u8; 32]),
+                &6,
+                &BytesN::from_array(&env, &proof_arr),
+                &BytesN::from_array(&env, &[0u8; 32]),
+                &BytesN::from_array(&env, &game),
+                &require,
+            )
+        };
+        assert_eq!(verify(GAME_A, true).unwrap().unwrap(), 6);
+
+        // Replaying game A's proof in game B fails, even at a relaxed table
+        assert!(verify(GAME_B, true).is_err());
+        assert!(verify(GAME_B, false).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #19)")] // VerifierError::WrongGame
+    fn test_verify_proof_rejects_attestation_from_other_game() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+
+        let cards: [u8; 2] = [51, 38];
+        let proof_arr = [4u8; 128];
+        client.record_zkverify_attestation(
+            &owner(&env, &BytesN::from_array(&env, &[0u8; 32])),
+            &String::from_str(&env, "game_a"),
+            &BytesN::from_array(&env, &[4u8; 32]),
+            &BytesN::from_array(&env, &[0u8; 32]),
+            &BytesN::from_array(&env, &GAME_A),
+            &6,
+            &true,
+            &String::from_str(&env, BLOCK_A),
+            &false,
+            &None,
+        );
+
+        client.verify_proof(
+            &BytesN::from_array(&env, &cards),
+            &BytesN::from_array(&env, &[42u8; 32]),
+            &make_commitment(&env, cards, [42u8; 32]),
+            &6,
+            &BytesN::from_array(&env, &proof_arr),
+            &BytesN::from_array(&env, &[0u8; 32]),
+            &BytesN::from_array(&env, &GAME_B),
+        );
+    }
+
+    #[test]
+    fn test_verify_proof_error_codes() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+
+        // [6; 32] is attested as verified, [8; 32] is recorded but unverified
+        for (hash, verified) in [(6u8, true), (8u8, false)] {
+            client.record_zkverify_attestation(
+                &owner(&env, &BytesN::from_array(&env, &[0u8; 32])),
+                &String::from_str(&env, "codes"),
+                &BytesN::from_array(&env, &[hash; 32]),
+                &BytesN::from_array(&env, &[0u8; 32]),
+                &BytesN::from_array(&env, &GAME_A),
+                &2,
+                &verified,
+                &String::from_str(&env, BLOCK_A),
+                &false,
+                &None,
+            );
+        }
+
+        let verify = |cards: [u8; 2], rank: u32, proof: u8| {
+            client.try_verify_proof(
+                &BytesN::from_array(&env, &cards),
+                &BytesN::from_array(&env, &[42u8; 32]),
+                &make_commitment(&env, cards, [42u8; 32]),
+                &rank,
+                &BytesN::from_array(&env, &[proof; 128]),
+                &BytesN::from_array(&env, &[0u8; 32]),
+                &BytesN::from_array(&env, &GAME_A),
+            )
+        };
+        let code = |e: VerifierError| Err(Ok(e.into()));
+
+        assert_eq!(verify([51, 38], 2, 6), Ok(Ok(true)));
+        assert_eq!(verify([51, 38], 2, 0), code(VerifierError::ProofAllZero));
+        assert_eq!(verify([52, 38], 2, 6), code(VerifierError::CardOutOfRange));
+        assert_eq!(verify([38, 38], 2, 6), code(VerifierError::DuplicateCards));
+        assert_eq!(verify([51, 38], 10, 6), code(VerifierError::RankOutOfRange));
+        assert_eq!(verify([51, 38], 2, 7), code(VerifierError::NoAttestation));
+        assert_eq!(verify([51, 38], 2, 8), code(VerifierError::AttestationUnverified));
+    }
+
+    #[test]
+    fn test_multisig_revoke_two_of_three() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+
+        let signers = [Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+        client.initialize_multisig(&Vec::from_array(&env, signers.clone()), &2);
+        record_n(&env, &client, 1);
+        let mut hash = [0u8; 32];
+        hash[3] = 1;
+        let proof_hash = BytesN::from_array(&env, &hash);
+
+        // One signature, a repeated signature or an outsider are not enough
+        let one = Vec::from_array(&env, [signers[0].clone()]);
+        let twice = Vec::from_array(&env, [signers[0].clone(), signers[0].clone()]);
+        let outsider = Vec::from_array(&env, [signers[0].clone(), Address::generate(&env)]);
+        assert_eq!(client.try_revoke_attestation(&proof_hash, &one), Err(Ok(VerifierError::ThresholdNotMet.into())));
+        assert_eq!(client.try_revoke_attestation(&proof_hash, &twice), Err(Ok(VerifierError::DuplicateSigner.into())));
+        assert_eq!(client.try_revoke_attestation(&proof_hash, &outsider), Err(Ok(VerifierError::NotASigner.into())));
+        assert!(client.get_attestation(&0).verified);
+
+        client.revoke_attestation(&proof_hash, &Vec::from_array(&env, [signers[0].clone(), signers[2].clone()]));
+        let auths = env.auths();
+        assert_eq!(auths.len(), 2);
+        let approvers: Vec<Address> = Vec::from_array(&env, [auths[0].0.clone(), auths[1].0.clone()]);
+        assert!(approvers.contains(&signers[0]) && approvers.contains(&signers[2]));
+        assert!(!client.get_attestation(&0).verified);
+    }
+
+    #[test]
+    fn test_multisig_transfer_admin() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.set_admin(&admin);
+        let signers = [Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+        let signer_vec = Vec::from_array(&env, signers.clone());
+        assert_eq!(client.try_initialize_multisig(&signer_vec, &4), Err(Ok(VerifierError::InvalidThreshold.into())));
+        client.initialize_multisig(&signer_vec, &2);
+        assert_eq!(client.get_multisig(), (signer_vec.clone(), 2));
+        assert_eq!(client.try_initialize_multisig(&signer_vec, &1), Err(Ok(VerifierError::MultisigAlreadySet.into())));
+
+        // The single admin can no longer hand the role over alone
+        let new_admin = Address::generate(&env);
+        assert_eq!(client.try_set_admin(&new_admin), Err(Ok(VerifierError::MultisigRequired.into())));
+        client.transfer_admin(&new_admin, &Vec::from_array(&env, [signers[1].clone(), signers[2].clone()]));
+        client.set_gate_mode(&new_admin, &GateMode::Soft);
+        assert!(client.try_set_gate_mode(&admin, &GateMode::Hard).is_err());
+    }
+
+    #[test]
+    fn test_gate_mode_dry_run_records_discrepancies() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.set_admin(&admin);
+        client.set_gate_mode(&admin, &GateMode::DryRun);
+
+        // [8; 32] is recorded but unverified; [7; 32] has no attestation
+        client.record_zkverify_attestation(
+            &owner(&env, &BytesN::from_array(&env, &[9u8; 32])),
+            &String::from_str(&env, "unverified"),
+            &BytesN::from_array(&env, &[8u8; 32]),
+            &BytesN::from_array(&env, &[9u8; 32]),
+            &BytesN::from_array(&env, &GAME_A),
+            &6,
+            &false,
+            &String::from_str(&env, ""),
+            &false,
+            &None,
+        );
+
+        let cards: [u8; 2] = [51, 38];
+        let player = BytesN::from_array(&env, &[9u8; 32]);
+        let rejections = |proof: u8| {
+            assert!(client.verify_proof(
+                &BytesN::from_array(&env, &cards),
+                &BytesN::from_array(&env, &[42u8; 32]),
+                &make_commitment(&env, cards, [42u8; 32]),
+                &6,
+                &BytesN::from_array(&env, &[proof; 128]),
+                &player,
+                &BytesN::from_array(&env, &GAME_A),
+            ));
+            let topics: soroban_sdk::Vec<Val> =
+                (symbol_short!("zkverify"), Symbol::new(&env, "gate_would_reject")).into_val(&env);
+            let mut found = soroban_sdk::Vec::<(BytesN<32>, BytesN<32>, bool)>::new(&env);
+            for (_, t, data) in env.events().all().iter() {
+                if t == topics {
+                    found.push_back(data.into_val(&env));
+                }
+            }
+            found
+        };
+
+        let missing = rejections(7);
+        assert_eq!(missing.len(), 1);
+        assert_eq!(missing.get(0).unwrap(), (BytesN::from_array(&env, &[7u8; 32]), player.clone(), false));
+        let unverified = rejections(8);
+        assert_eq!(unverified.last().unwrap(), (BytesN::from_array(&env, &[8u8; 32]), player.clone(), true));
+    }
+
+    #[test]
+    fn test_hole_card_on_board_rejected() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+
+        // A♠ K♠ with a clean flop
+        let ak = BytesN::from_array(&env, &[51, 50]);
+        assert!(client.verify_rank_consistency(&ak, &board(&env, &[24, 2, 5]), &1));
+
+        // The A♠ also dealt to the board
+        assert_eq!(
+            client.try_verify_rank_consistency(&ak, &board(&env, &[24, 51, 5]), &1),
+            Err(Ok(VerifierError::DuplicateCards.into()))
+        );
+        assert_eq!(
+            client.try_verify_rank_consistency(&ak, &board(&env, &[24, 2, 24]), &1),
+            Err(Ok(VerifierError::DuplicateCards.into()))
+        );
+    }
+
+    #[test]
+    fn test_strict_proof_shape_rejects_non_zero_tail() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.set_admin(&admin);
+        client.set_gate_mode(&admin, &GateMode::Off);
+        assert!(!client.is_strict_proof_shape());
+
+        let cards: [u8; 2] = [51, 38];
+        let mut shaped = [0u8; PROOF_LEN];
+        shaped[..PROOF_HASH_LEN].copy_from_slice(&[5u8; PROOF_HASH_LEN]);
+        let mut malformed = shaped;
+        malformed[PROOF_LEN - 1] = 1;
+        let verify = |proof: [u8; PROOF_LEN]| {
+            client.try_verify_proof(
+                &BytesN::from_array(&env, &cards),
+                &BytesN::from_array(&env, &[42u8; 32]),
+                &make_commitment(&env, cards, [42u8; 32]),
+                &6,
+                &BytesN::from_array(&env, &proof),
+                &BytesN::from_array(&env, &[0u8; 32]),
+                &BytesN::from_array(&env, &GAME_A),
+            )
+        };
+
+        // Tail bytes are ignored until strict mode is on
+        assert_eq!(verify(malformed), Ok(Ok(true)));
+        client.set_strict_proof_shape(&admin, &true);
+        assert_eq!(verify(shaped), Ok(Ok(true)));
+        assert_eq!(verify(malformed), Err(Ok(VerifierError::MalformedProof.into())));
+
+        let stranger = Address::generate(&env);
+        assert!(client.try_set_strict_proof_shape(&stranger, &false).is_err());
+    }
+
+    #[test]
+    fn test_attestation_chain_intact() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+        record_n(&env, &client, 4);
+
+        assert_eq!(client.get_attestation(&0).prev_hash, BytesN::from_array(&env, &[0u8; 32]));
+        assert_ne!(client.get_attestation(&1).prev_hash, BytesN::from_array(&env, &[0u8; 32]));
+        assert!(client.verify_attestation_chain(&0, &4));
+        assert!(client.verify_attestation_chain(&1, &3));
+    }
+
+    #[test]
+    fn test_attestation_chain_detects_tampered_record() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+        record_n(&env, &client, 4);
+
+        // Rewrite the middle record's rank directly in storage
+        let mut tampered = client.get_attestation(&1);
+        tampered.claimed_rank = 9;
+        env.as_contract(&contract_id, || {
+            env.storage().instance().set(&(symbol_short!("ATT"), 1u32), &tampered);
+        });
+
+        assert!(!client.verify_attestation_chain(&0, &4));
+        assert!(!client.verify_attestation_chain(&1, &3));
+        // Links before the edit are still intact
+        assert!(client.verify_attestation_chain(&0, &2));
+    }
+
+    #[test]
+    fn test_attestation_chain_detects_flipped_verified() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+        record_n(&env, &client, 4);
+
+        // Flip the middle record's verified flag directly in storage
+        let mut tampered = client.get_attestation(&1);
+        tampered.verified = false;
+        env.as_contract(&contract_id, || {
+            env.storage().instance().set(&(symbol_short!("ATT"), 1u32), &tampered);
+        });
+        assert!(!client.verify_attestation_chain(&0, &4));
+
+        // Re-validating a revoked record is caught as well
+        let signer = Address::generate(&env);
+        client.initialize_multisig(&Vec::from_array(&env, [signer.clone()]), &1);
+        let hash = client.get_attestation(&2).proof_hash;
+        client.revoke_attestation(&hash, &Vec::from_array(&env, [signer]));
+        assert!(client.verify_attestation_chain(&2, &4));
+
+        let mut restored = client.get_attestation(&2);
+        restored.verified = true;
+        env.as_contract(&contract_id, || {
+            env.storage().instance().set(&(symbol_short!("ATT"), 2u32), &restored);
+        });
+        assert!(!client.verify_attestation_chain(&2, &4));
+    }
+
+    #[test]
+    fn test_revoke_keeps_attestation_chain_intact() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+        record_n(&env, &client, 3);
+
+        let signer = Address::generate(&env);
+        client.initialize_multisig(&Vec::from_array(&env, [signer.clone()]), &1);
+        let hash = client.get_attestation(&1).proof_hash;
+        client.revoke_attestation(&hash, &Vec::from_array(&env, [signer]));
+
+        assert!(client.verify_attestation_chain(&0, &3));
+    }
+
+    #[test]
+    fn test_consumed_proof_rejected_when_reuse_disabled() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.set_admin(&admin);
+        client.set_gate_mode(&admin, &GateMode::Off);
+
+        let cards: [u8; 2] = [51, 38];
+        let proof_hash = BytesN::from_array(&env, &[5u8; PROOF_HASH_LEN]);
+        let mut proof = [0u8; PROOF_LEN];
+        proof[..PROOF_HASH_LEN].copy_from_slice(&[5u8; PROOF_HASH_LEN]);
+        client.set_game_contract(&admin, &Address::generate(&env));
+        let verify = || {
+            client.try_verify_and_get_rank(
+                &BytesN::from_array(&env, &cards),
+                &BytesN::from_array(&env, &[42u8; 32]),
+                &make_commitment(&env, cards, [42u8; 32]),
+                &6,
+                &BytesN::from_array(&env, &proof),
+                &BytesN::from_array(&env, &[0u8; 32]),
+                &BytesN::from_array(&env, &GAME_A),
+                &false,
+            )
+        };
+
+        // Reuse is allowed by default, but the first use is still recorded
+        assert!(client.is_proof_reuse_allowed());
+        assert!(!client.is_proof_consumed(&proof_hash));
+        assert_eq!(verify(), Ok(Ok(6)));
+        assert!(client.is_proof_consumed(&proof_hash));
+        assert_eq!(verify(), Ok(Ok(6)));
+
+        client.set_proof_reuse(&admin, &false);
+        assert_eq!(verify(), Err(Ok(VerifierError::ProofConsumed.into())));
+    }
+
+    #[test]
+    fn test_public_verify_proof_does_not_consume() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.set_admin(&admin);
+        client.set_gate_mode(&admin, &GateMode::Off);
+        client.set_proof_reuse(&admin, &false);
+        client.set_game_contract(&admin, &Address::generate(&env));
+
+        let cards: [u8; 2] = [51, 38];
+        let proof = BytesN::from_array(&env, &[5u8; PROOF_LEN]);
+        let args = (
+            BytesN::from_array(&env, &cards),
+            BytesN::from_array(&env, &[42u8; 32]),
+            make_commitment(&env, cards, [42u8; 32]),
+        );
+
+        // Anyone checking the proof first cannot burn it
+        for _ in 0..2 {
+            assert!(client.verify_proof(&args.0, &args.1, &args.2, &6, &proof, &BytesN::from_array(&env, &[0u8; 32]), &BytesN::from_array(&env, &GAME_A)));
+        }
+        assert!(!client.is_proof_consumed(&BytesN::from_array(&env, &[5u8; PROOF_HASH_LEN])));
+        assert_eq!(
+            client.verify_and_get_rank(&args.0, &args.1, &args.2, &6, &proof, &BytesN::from_array(&env, &[0u8; 32]), &BytesN::from_array(&env, &GAME_A), &false),
+            6
+        );
+        assert!(client.is_proof_consumed(&BytesN::from_array(&env, &[5u8; PROOF_HASH_LEN])));
+
+        // The consuming entry point is reserved for the game contract
+        env.set_auths(&[]);
+        let fresh = BytesN::from_array(&env, &[8u8; PROOF_LEN]);
+        assert!(client.try_verify_and_get_rank(&args.0, &args.1, &args.2, &6, &fresh, &BytesN::from_array(&env, &[0u8; 32]), &BytesN::from_array(&env, &GAME_A), &false).is_err());
+        assert!(!client.is_proof_consumed(&BytesN::from_array(&env, &[8u8; PROOF_HASH_LEN])));
+    }
+
+    #[test]
+    fn test_proof_hash_read_from_configured_offset() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.set_admin(&admin);
+        client.set_gate_mode(&admin, &GateMode::Off);
+
+        // Front hash of 5s, and a second hash of 7s placed at byte 64
+        let cards: [u8; 2] = [51, 38];
+        let front = BytesN::from_array(&env, &[5u8; PROOF_HASH_LEN]);
+        let later = BytesN::from_array(&env, &[7u8; PROOF_HASH_LEN]);
+        let mut proof = [0u8; PROOF_LEN];
+        proof[..PROOF_HASH_LEN].copy_from_slice(&[5u8; PROOF_HASH_LEN]);
+        proof[64..64 + PROOF_HASH_LEN].copy_from_slice(&[7u8; PROOF_HASH_LEN]);
+        client.set_game_contract(&admin, &Address::generate(&env));
+        let verify = || {
+            client.verify_and_get_rank(
+                &BytesN::from_array(&env, &cards),
+                &BytesN::from_array(&env, &[42u8; 32]),
+                &make_commitment(&env, cards, [42u8; 32]),
+                &6,
+                &BytesN::from_array(&env, &proof),
+                &BytesN::from_array(&env, &[0u8; 32]),
+                &BytesN::from_array(&env, &GAME_A),
+                &false,
+            )
+        };
+
+        // Default: the hash is the first 32 bytes
+        assert_eq!(client.get_proof_hash_offset(), 0);
+        assert_eq!(verify(), 6);
+        assert!(client.is_proof_consumed(&front));
+        assert!(!client.is_proof_consumed(&later));
+
+        client.set_proof_hash_offset(&admin, &64);
+        assert_eq!(verify(), 6);
+        assert!(client.is_proof_consumed(&later));
+
+        // The hash must fit inside the 128-byte proof
+        client.set_proof_hash_offset(&admin, &96);
+        assert_eq!(
+            client.try_set_proof_hash_offset(&admin, &97),
+            Err(Ok(VerifierError::InvalidProofHashOffset.into()))
+        );
+    }
+
+    #[test]
+    fn test_fresh_proof_passes_once_with_reuse_disabled() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.set_admin(&admin);
+        client.set_gate_mode(&admin, &GateMode::Off);
+        client.set_proof_reuse(&admin, &false);
+        client.set_game_contract(&admin, &Address::generate(&env));
+
+        let cards: [u8; 2] = [51, 38];
+        let verify = || {
+            client.try_verify_and_get_rank(
+                &BytesN::from_array(&env, &cards),
+                &BytesN::from_array(&env, &[42u8; 32]),
+                &make_commitment(&env, cards, [42u8; 32]),
+                &6,
+                &BytesN::from_array(&env, &[9u8; PROOF_LEN]),
+                &BytesN::from_array(&env, &[0u8; 32]),
+                &BytesN::from_array(&env, &GAME_A),
+                &false,
+            )
+        };
+
+        assert_eq!(verify(), Ok(Ok(6)));
+        assert_eq!(verify(), Err(Ok(VerifierError::ProofConsumed.into())));
+    }
+
+    #[test]
+    fn test_get_attestation_by_id() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+
+        let player = BytesN::from_array(&env, &[0x01; 32]);
+        let hash_a = BytesN::from_array(&env, &[0xAA; 32]);
+        let hash_b = BytesN::from_array(&env, &[0xBB; 32]);
+        for (id, hash, rank) in [("zkv_att_a", &hash_a, 3u32), ("zkv_att_b", &hash_b, 6)] {
+            client.record_zkverify_attestation(
+                &owner(&env, &player),
+                &String::from_str(&env, id), hash, &player, &BytesN::from_array(&env, &GAME_A), &rank, &true,
+                &String::from_str(&env, BLOCK_A), &false, &None,
+            );
+        }
+
+        let stored = client.get_attestation_by_id(&String::from_str(&env, "zkv_att_a"));
+        assert_eq!(stored.proof_hash, hash_a);
+        assert_eq!(stored.claimed_rank, 3);
+        assert_eq!(client.get_attestation_by_id(&String::from_str(&env, "zkv_att_b")).proof_hash, hash_b);
+        assert_eq!(
+            client.try_get_attestation_by_id(&String::from_str(&env, "zkv_att_c")).err(),
+            Some(Ok(VerifierError::AttestationNotFound.into()))
+        );
+    }
+
+    #[test]
+    fn test_attestation_id_over_max_length_rejected() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+
+        let record = |id: &[u8]| {
+            client.try_record_zkverify_attestation(
+                &owner(&env, &BytesN::from_array(&env, &[0x01; 32])),
+                &String::from_bytes(&env, id), &BytesN::from_array(&env, &[0xAA; 32]),
+                &BytesN::from_array(&env, &[0x01; 32]), &BytesN::from_array(&env, &GAME_A), &3, &true,
+                &String::from_str(&env, BLOCK_A), &true, &None,
+            )
+        };
+        assert_eq!(record(&[b'a'; MAX_ATTESTATION_ID_LEN as usize + 1]), Err(Ok(VerifierError::AttestationIdTooLong.into())));
+        assert_eq!(record(&[b'a'; MAX_ATTESTATION_ID_LEN as usize]), Ok(Ok(0)));
+    }
+
+    fn proof_args(env: &Env, cards: [u8; 2], rank: u32, proof_byte: u8, game: [u8; 32]) -> ProofArgs {
+        ProofArgs {
+            hole_cards:   BytesN::from_array(env, &cards),
+            salt:         BytesN::from_array(env, &[42u8; 32]),
+            commitment:   make_commitment(env, cards, [42u8; 32]),
+            claimed_rank: rank,
+            proof_bytes:  BytesN::from_array(env, &[proof_byte; PROOF_LEN]),
+            player:       BytesN::from_array(env, &[proof_byte; 32]),
+            game_id:      BytesN::from_array(env, &game),
+        }
+    }
+
+    #[test]
+    fn test_verify_proofs_batch_both_pass() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.set_admin(&admin);
+        client.set_gate_mode(&admin, &GateMode::Off);
+
+        let p1 = proof_args(&env, [51, 38], 1, 1, GAME_A);
+        let p2 = proof_args(&env, [12, 25], 2, 2, GAME_A);
+        assert_eq!(client.verify_proofs_batch(&p1, &p2), (true, true));
+    }
+
+    #[test]
+    fn test_verify_proofs_batch_one_fails() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.set_admin(&admin);
+        client.set_gate_mode(&admin, &GateMode::Off);
+
+        // Player 2 claims an impossible rank; the whole batch is rejected
+        let p1 = proof_args(&env, [51, 38], 1, 1, GAME_A);
+        let p2 = proof_args(&env, [12, 25], 10, 2, GAME_A);
+        assert_eq!(client.try_verify_proofs_batch(&p1, &p2), Err(Ok(VerifierError::RankOutOfRange.into())));
+
+        // So is a pair of proofs presented for different games
+        let p2 = proof_args(&env, [12, 25], 2, 2, GAME_B);
+        assert_eq!(client.try_verify_proofs_batch(&p1, &p2), Err(Ok(VerifierError::WrongGame.into())));
+    }
+
+    #[test]
+    fn test_verify_proofs_batch_rejects_shared_card() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+
+        // The ace of spades (51) cannot be in both hands
+        let p1 = proof_args(&env, [51, 38], 1, 1, GAME_A);
+        let p2 = proof_args(&env, [12, 51], 2, 2, GAME_A);
+        assert_eq!(client.try_verify_proofs_batch(&p1, &p2), Err(Ok(VerifierError::DuplicateCards.into())));
+    }
+
+    #[test]
+    fn test_get_attestation_index() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+        record_n(&env, &client, 3);
+
+        let hash = client.get_attestation(&2).proof_hash;
+        assert_eq!(client.get_attestation_index(&hash), Some(2));
+        assert_eq!(client.get_attestation_index(&BytesN::from_array(&env, &[0xFF; 32])), None);
+    }
+
+    #[test]
+    fn test_board_size_limits() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+        let ak = BytesN::from_array(&env, &[51, 50]);
+
+        // A sixth board card is rejected before any evaluation
+        assert_eq!(
+            client.try_verify_rank_consistency(&ak, &board(&env, &[0, 1, 2, 3, 4, 5]), &0),
+            Err(Ok(VerifierError::InvalidBoard.into()))
+        );
+        let verify = |community: &[u32], rank: u32| {
+            client.try_verify_proof_with_board(
+                &ak,
+                &BytesN::from_array(&env, &[42u8; 32]),
+                &make_commitment(&env, [51, 50], [42u8; 32]),
+                &rank,
+                &BytesN::from_array(&env, &[1u8; 128]),
+                &BytesN::from_array(&env, &[0u8; 32]),
+                &BytesN::from_array(&env, &GAME_A),
+                &board(&env, community),
+            )
+        };
+        assert_eq!(verify(&[0, 1, 2, 3, 4, 5], 0), Err(Ok(VerifierError::InvalidBoard.into())));
+        assert_eq!(verify(&[0, 1], 0), Err(Ok(VerifierError::InvalidBoard.into())));
+    }
+
+    #[test]
+    fn test_full_seven_card_hand_evaluates_best_five() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+
+        // A♠ K♠ + Q♥ J♦ T♣ 2♣ 2♦: the best five are the broadway straight (4),
+        // beating the board pair
+        let ak = BytesN::from_array(&env, &[51, 50]);
+        let seven = board(&env, &[36, 22, 8, 0, 13]);
+        assert!(client.verify_rank_consistency(&ak, &seven, &4));
+        assert_eq!(
+            client.try_verify_proof_with_board(
+                &ak,
+                &BytesN::from_array(&env, &[42u8; 32]),
+                &make_commitment(&env, [51, 50], [42u8; 32]),
+                &1,
+                &BytesN::from_array(&env, &[1u8; 128]),
+                &BytesN::from_array(&env, &[0u8; 32]),
+                &BytesN::from_array(&env, &GAME_A),
+                &seven,
+            ),
+            Err(Ok(VerifierError::RankMismatch.into()))
+        );
+    }
+
+    #[test]
+    fn test_verifier_version_reports_interface() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+        assert_eq!(client.verifier_version(), INTERFACE_VERSION);
+        assert_eq!(client.ping(), INTERFACE_VERSION);
+    }
+
+    #[test]
+    fn test_compare_hands_chops_when_both_play_the_board() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+
+        // Broadway straight on the board; neither 2-3 nor 3-4 offsuit improves it
+        let broadway = board(&env, &[12, 24, 36, 48, 8]);
+        let low1 = BytesN::from_array(&env, &[0, 14]);
+        let low2 = BytesN::from_array(&env, &[1, 15]);
+        assert_eq!(client.compare_hands(&low1, &low2, &broadway), 0);
+        assert_eq!(client.compare_hands(&low2, &low1, &broadway), 0);
+    }
+
+    #[test]
+    fn test_compare_hands_separates_equal_ranks_on_kickers() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+
+        // K 9 7 4 2 rainbow: both make high card, the ace outkicks the queen
+        let dry = board(&env, &[11, 20, 31, 41, 0]);
+        let ace = BytesN::from_array(&env, &[12, 14]);
+        let queen = BytesN::from_array(&env, &[10, 16]);
+        assert!(client.verify_rank_consistency(&ace, &dry, &0));
+        assert!(client.verify_rank_consistency(&queen, &dry, &0));
+        assert_eq!(client.compare_hands(&ace, &queen, &dry), 1);
+        assert_eq!(client.compare_hands(&queen, &ace, &dry), -1);
+
+        // Both pair kings; the second pair decides two pair
+        let kings = board(&env, &[11, 24, 31, 41, 0]);
+        let nines = BytesN::from_array(&env, &[7, 33]);
+        let fives = BytesN::from_array(&env, &[3, 29]);
+        assert_eq!(client.compare_hands(&nines, &fives, &kings), 1);
+
+        assert_eq!(
+            client.try_compare_hands(&ace, &BytesN::from_array(&env, &[12, 16]), &dry),
+            Err(Ok(VerifierError::DuplicateCards.into()))
+        );
+    }
+
+    #[test]
+    fn test_player_records_own_attestation_only() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+        let alice = Address::generate(&env);
+        let victim = BytesN::from_array(&env, &[0x0Bu8; 32]);
+        let record = |recorder: &Address, player: &BytesN<32>, hash: u8| {
+            client.try_record_zkverify_attestation(
+                recorder,
+                &String::from_str(&env, "zkv_att"),
+                &BytesN::from_array(&env, &[hash; 32]),
+                player,
+                &BytesN::from_array(&env, &GAME_A),
+                &6,
+                &true,
+                &String::from_str(&env, BLOCK_A),
+                &false,
+                &None,
+            )
+        };
+
+        // Recording under someone else's identity is rejected
+        assert_eq!(record(&alice, &victim, 1), Err(Ok(VerifierError::NotAttestationOwner.into())));
+
+        // Alice may record for her own id
+        assert_eq!(record(&alice, &client.player_id(&alice), 2), Ok(Ok(0)));
+        assert_eq!(client.get_attestation(&0).player, client.player_id(&alice));
+    }
+
+    #[test]
+    fn test_admin_records_attestation_for_any_player() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.set_admin(&admin);
+
+        let player = BytesN::from_array(&env, &[0x0Bu8; 32]);
+        let idx = client.record_zkverify_attestation(
+            &admin,
+            &String::from_str(&env, "zkv_att"),
+            &BytesN::from_array(&env, &[1u8; 32]),
+            &player,
+            &BytesN::from_array(&env, &GAME_A),
+            &6,
+            &true,
+            &String::from_str(&env, BLOCK_A),
+            &false,
+            &None,
+        );
+        assert_eq!(client.get_attestation(&idx).player, player);
+        assert_eq!(client.player_id(&owner(&env, &player)), player);
+    }
+
+    #[test]
+    fn test_player_attestation_count_tracks_verified_records() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+        let alice = Address::generate(&env);
+        let id = client.player_id(&alice);
+        let record = |hash: u8, verified: bool, overwrite: bool| {
+            client.record_zkverify_attestation(
+                &alice,
+                &String::from_str(&env, "zkv_att"),
+                &BytesN::from_array(&env, &[hash; 32]),
+                &id,
+                &BytesN::from_array(&env, &GAME_A),
+                &6,
+                &verified,
+                &String::from_str(&env, BLOCK_A),
+                &overwrite,
+                &None,
+            )
+        };
+
+        assert_eq!(client.get_player_attestation_count(&alice), 0);
+        record(1, true, false);
+        record(2, false, false);
+        assert_eq!(client.get_player_attestation_count(&alice), 1);
+        record(2, true, true);
+        assert_eq!(client.get_player_attestation_count(&alice), 2);
+        record(1, false, true);
+        assert_eq!(client.get_player_attestation_count(&alice), 1);
+        assert_eq!(client.get_player_attestation_count(&Address::generate(&env)), 0);
+    }
+
+    #[test]
+    fn test_attestations_filtered_by_proof_system() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.set_admin(&admin);
+
+        let cards: [u8; 2] = [51, 38];
+        let player = BytesN::from_array(&env, &[0u8; 32]);
+        client.record_zkverify_attestation(
+            &admin,
+            &String::from_str(&env, "noir"),
+            &BytesN::from_array(&env, &[1u8; 32]),
+            &player,
+            &BytesN::from_array(&env, &GAME_A),
+            &6,
+            &true,
+            &String::from_str(&env, BLOCK_A),
+            &false,
+            &None,
+        );
+        client.record_system_attestation(
+            &admin,
+            &symbol_short!("groth16"),
+            &String::from_str(&env, "g16"),
+            &BytesN::from_array(&env, &[2u8; 32]),
+            &player,
+            &BytesN::from_array(&env, &GAME_A),
+            &6,
+            &true,
+            &String::from_str(&env, BLOCK_A),
+            &false,
+        );
+        assert_eq!(client.get_attestation(&0).proof_system, symbol_short!("ultrahonk"));
+        assert_eq!(client.get_attestation(&1).proof_system, symbol_short!("groth16"));
+
+        let verify = |hash: u8| {
+            client.try_verify_proof(
+                &BytesN::from_array(&env, &cards),
+                &BytesN::from_array(&env, &[42u8; 32]),
+                &make_commitment(&env, cards, [42u8; 32]),
+                &6,
+                &BytesN::from_array(&env, &[hash; 128]),
+                &player,
+                &BytesN::from_array(&env, &GAME_A),
+            )
+        };
+
+        client.set_expected_proof_system(&admin, &Some(symbol_short!("groth16")));
+        assert_eq!(client.get_expected_proof_system(), Some(symbol_short!("groth16")));
+        assert_eq!(verify(1), Err(Ok(VerifierError::WrongProofSystem.into())));
+        assert_eq!(verify(2), Ok(Ok(true)));
+
+        // Without an expected system any scheme is accepted
+        client.set_expected_proof_system(&admin, &None);
+        assert_eq!(verify(1), Ok(Ok(true)));
+    }
+
+    #[test]
+    fn test_overwrite_limited_to_admin_or_original_player() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.set_admin(&admin);
+        let alice = Address::generate(&env);
+        let mallory = Address::generate(&env);
+        let record = |recorder: &Address, player: &BytesN<32>, overwrite: bool| {
+            client.try_record_zkverify_attestation(
+                recorder,
+                &String::from_str(&env, "att"),
+                &BytesN::from_array(&env, &[5u8; 32]),
+                player,
+                &BytesN::from_array(&env, &GAME_A),
+                &6,
+                &true,
+                &String::from_str(&env, BLOCK_A),
+                &overwrite,
+                &None,
+            )
+        };
+
+        let alice_id = client.player_id(&alice);
+        let mallory_id = client.player_id(&mallory);
+        assert_eq!(record(&alice, &alice_id, false), Ok(Ok(0)));
+        // Mallory may record for herself, but not over Alice's proof hash
+        assert_eq!(record(&mallory, &mallory_id, true), Err(Ok(VerifierError::NotAttestationOwner.into())));
+        assert_eq!(client.get_attestation(&0).player, alice_id);
+
+        assert_eq!(record(&alice, &alice_id, true), Ok(Ok(0)));
+        assert_eq!(record(&admin, &mallory_id, true), Ok(Ok(0)));
+        assert_eq!(client.get_attestation(&0).player, mallory_id);
+    }
+
+    #[test]
+    fn test_verify_proof_rejects_attestation_of_other_player() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+        let alice = BytesN::from_array(&env, &[1u8; 32]);
+        client.record_zkverify_attestation(
+            &owner(&env, &alice),
+            &String::from_str(&env, "alice"),
+            &BytesN::from_array(&env, &[6u8; 32]),
+            &alice,
+            &BytesN::from_array(&env, &GAME_A),
+            &6,
+            &true,
+            &String::from_str(&env, BLOCK_A),
+            &false,
+            &None,
+        );
+
+        let cards: [u8; 2] = [51, 38];
+        let verify = |player: [u8; 32]| {
+            client.try_verify_proof(
+                &BytesN::from_array(&env, &cards),
+                &BytesN::from_array(&env, &[42u8; 32]),
+                &make_commitment(&env, cards, [42u8; 32]),
+                &6,
+                &BytesN::from_array(&env, &[6u8; 128]),
+                &BytesN::from_array(&env, &player),
+                &BytesN::from_array(&env, &GAME_A),
+            )
+        };
+        assert_eq!(verify([2u8; 32]), Err(Ok(VerifierError::WrongPlayer.into())));
+        assert_eq!(verify([1u8; 32]), Ok(Ok(true)));
+    }
+
+    #[test]
+    fn test_verify_and_get_rank_rejects_rank_other_than_attested() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, NoirVerifier);
+        let client = NoirVerifierClient::new(&env, &contract_id);
+        let alice = BytesN::from_array(&env, &[1u8; 32]);
+        client.record_zkverify_attestation(
+            &owner(&env, &alice),
+            &String::from_str(&env, "alice"),
+            &BytesN::from_array(&env, &[6u8; 32]),
+            &alice,
+            &BytesN::from_array(&env, &GAME_A),
+            &2,
+            &true,
+            &String::from_str(&env, BLOCK_A),
+            &false,
+            &None,
+        );
+
+        let cards: [u8; 2] = [51, 38];
+        let verify = |rank: u32| {
+            client.try_verify_and_get_rank(
+                &BytesN::from_array(&env, &cards),
+                &BytesN::from_array(&env, &[42u8; 32]),
+                &make_commitment(&env, cards, [42u8; 32]),
+                &rank,
+                &BytesN::from_array(&env, &[6u8; 128]),
+                &alice,
+                &BytesN::from_array(&env, &GAME_A),
+                &true,
+            )
+        };
+        // A pair was attested; claiming a royal flush must not pass
+        assert_eq!(verify(9), Err(Ok(VerifierError::WrongRank.into())));
+        assert_eq!(verify(2), Ok(Ok(2)));
     }
 }
@@ -1,7 +1,17 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Vec, Bytes, BytesN, Symbol, IntoVal, log, symbol_short};
+#![allow(clippy::too_many_arguments)]
+use soroban_sdk::{contract, contracterror, contractimpl, contracttype, panic_with_error, token, Address, Env, Map, Vec, Bytes, BytesN, Symbol, IntoVal, log, symbol_short, xdr::ToXdr};
 
-#[derive(Clone)]
+/// `log!` that stays quiet below the table's `set_log_level`.
+macro_rules! log_at {
+    ($env:expr, $level:expr, $($arg:tt)*) => {
+        if cfg!(debug_assertions) && Self::log_level($env) >= $level {
+            log!($env, $($arg)*);
+        }
+    };
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
 #[contracttype]
 pub enum BettingRound {
     Preflop,
@@ -11,6 +21,85 @@ pub enum BettingRound {
     Showdown,
 }
 
+/// Which end of the rank scale wins a showdown
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum HandOrdering {
+    HighWins, // standard poker (default)
+    LowWins,  // lowball: the lower rank takes the pot
+}
+
+/// Failure codes returned by the contract. Clients match these through the
+/// generated `try_` methods instead of parsing panic strings.
+#[contracterror]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PokerError {
+    NotActive             = 1,  // game already ended
+    MissingCommitment     = 2,  // player never submitted a card commitment
+    EmptyProof            = 3,  // proof bytes are all zero
+    InvalidRank           = 4,  // rank outside [0, 9]
+    VerifierFalse         = 5,  // noir_verifier rejected the proof
+    VerifierNotConfigured = 6,
+    GameNotFound          = 7,
+    PlayerNotFound        = 8,
+    PlayerFolded          = 9,
+    InsufficientChips     = 10,
+    BetAboveLimit         = 11,
+    BetBelowMinimum       = 12,
+    InvalidPlayerCount    = 13,
+    InvalidStartingChips  = 14, // non-positive starting stack or maximum
+    StartingChipsTooHigh  = 15,
+    AdminNotConfigured    = 16,
+    NotAdmin              = 17,
+    RakeTooHigh           = 18,
+    InvalidBetLimits      = 19,
+    InvalidAnte           = 20,
+    UnsupportedHoleCards  = 21,
+    InvalidTimeout        = 22,
+    ZeroCommitment        = 23,
+    DeckAlreadyCommitted  = 24,
+    DeckNotCommitted      = 25,
+    HandInProgress        = 26,
+    DeckSeedMismatch      = 27, // seed does not hash to the commitment or deal the board
+    CardsMismatch         = 28, // revealed cards do not hash to the commitment
+    NoAnte                = 29,
+    BetsAlreadyPlaced     = 30, // antes collected after betting started
+    NoChips               = 31,
+    TimeoutNotConfigured  = 32,
+    NotPlayersTurn        = 33,
+    TimeoutNotReached     = 34,
+    InvalidAmount         = 35,
+    TooManyCommunityCards = 36,
+    Reentrant             = 37, // resolve_showdown re-entered during the verifier call
+    NoActivePlayers       = 38,
+    NotRevealed           = 39,
+    ChipConservation      = 40,
+    TokenNotConfigured    = 41,
+    BetsNotSettled        = 42,
+    WrongHoleCardCount    = 43,
+    SaltReused            = 44,
+    Paused                = 45,
+    HandNotFound          = 46,
+    NoHandResolved        = 47,
+    RebuyNotAllowed       = 48, // stack above the rebuy threshold
+    WrongRound            = 49, // board street revealed out of order
+    InvalidCard           = 50, // card outside [0, 51] or already on the board
+}
+
+/// Failure codes continuing `PokerError`'s numbering; a `contracterror`
+/// enum holds at most 50 variants.
+#[contracterror]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TableError {
+    ArithmeticOverflow       = 51, // chip, pot or rake arithmetic would overflow
+    InsufficientAttestations = 52, // player below `set_min_player_attestations`
+    UnbackedChips            = 53, // withdrawal exceeds the tokens deposited at the table
+    VerifierFault            = 54, // verifier failed for a reason other than the proof
+    BatchTooLarge            = 55, // more entries than a batch call accepts
+}
+
 #[derive(Clone)]
 #[contracttype]
 pub struct Player {
@@ -19,6 +108,9 @@ pub struct Player {
     pub commitment: BytesN<32>,     // SHA-256(hole_cards || salt) — verified on-chain at showdown
     pub current_bet: i128,
     pub has_folded: bool,
+    pub last_action_ledger: u32,    // ledger sequence of this player's last action
+    pub revealed: bool,             // cards verified against commitment via verify_reveal
+    pub all_in: bool,               // whole stack committed; no further actions this hand
 }
 
 #[derive(Clone)]
@@ -34,11 +126,172 @@ pub struct GameState {
     pub is_active: bool,
     pub player1_proof_hash: BytesN<32>,
     pub player2_proof_hash: BytesN<32>,
-    pub verifier_contract: Address,   // noir_verifier contract address
+    pub verifier_contract: Option<Address>, // noir_verifier contract address, captured at init
+    pub min_bet: i128,                // smallest bet allowed (except an all-in for less)
+    pub max_bet: i128,                // table limit
+    pub deck_commitment: BytesN<32>,  // SHA-256(shuffle seed), zero until committed
+    pub deck_seed: BytesN<32>,        // revealed shuffle seed, zero until revealed
+    pub chip_supply: i128,            // chips that should exist at the table (stacks + pot)
+    pub hole_cards: u32,              // hole cards per player: 2 = Hold'em, 4 = Omaha
+    pub hand_ordering: HandOrdering,  // whether the higher or lower rank wins
+    pub require_attestation: bool,    // showdown proofs need a zkVerify attestation
+    pub ante: i128,                   // forced bet from every player per hand, 0 = none
+}
+
+/// A seat as stored by the schema-1 layout, read only by `migrate`
+#[derive(Clone)]
+#[contracttype]
+pub struct LegacyPlayer {
+    pub address: Address,
+    pub chips: i128,
+    pub commitment: BytesN<32>,
+    pub current_bet: i128,
+    pub has_folded: bool,
+}
+
+/// The single table kept by the schema-1 layout under the instance key
+/// `LEGACY_GAME_STATE`, read only by `migrate`
+#[derive(Clone)]
+#[contracttype]
+pub struct LegacyGameState {
+    pub game_id: BytesN<32>,
+    pub players: Vec<LegacyPlayer>,
+    pub pot: i128,
+    pub community_cards: Vec<u32>,
+    pub current_round: BettingRound,
+    pub dealer_button: u32,
+    pub current_player: u32,
+    pub is_active: bool,
+    pub player1_proof_hash: BytesN<32>,
+    pub player2_proof_hash: BytesN<32>,
+    pub verifier_contract: Address,
+}
+
+/// Fixed-limit bet sizes: every bet or raise is exactly one increment
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct LimitStructure {
+    pub small_bet: i128, // increment preflop and on the flop
+    pub big_bet: i128,   // increment on the turn and river
+}
+
+/// Rule set new games are initialized with, as returned by `get_table_rules`
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct TableRules {
+    pub min_bet: i128,                  // set_bet_limits
+    pub max_bet: i128,
+    pub small_bet: i128,                // set_limit_structure; 0 = no-limit
+    pub big_bet: i128,
+    pub ante: i128,                     // set_ante
+    pub rake_bps: u32,                  // set_rake
+    pub hand_ordering: HandOrdering,    // set_hand_ordering
+    pub hole_cards: u32,                // set_hole_cards
+    pub require_attestation: bool,      // set_require_attestation
+    pub require_reveal: bool,           // set_require_reveal
+}
+
+/// Hole cards stored encrypted by `submit_encrypted_cards`, bound to the
+/// commitment they were submitted against
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct EncryptedCards {
+    pub commitment: BytesN<32>, // player's commitment at submission time
+    pub ciphertext: Bytes,      // (cards || salt) XOR keystream(reveal key)
+}
+
+/// One table's `resolve_showdown` arguments, for `resolve_showdowns_batch`
+#[derive(Clone)]
+#[contracttype]
+pub struct ShowdownInput {
+    pub game_id: BytesN<32>,
+    pub player1_proof: BytesN<128>,
+    pub player1_rank: u32,
+    pub player1_cards: Bytes,
+    pub player1_salt: BytesN<32>,
+    pub player2_proof: BytesN<128>,
+    pub player2_rank: u32,
+    pub player2_cards: Bytes,
+    pub player2_salt: BytesN<32>,
+}
+
+/// Showdown outcome predicted by `simulate_showdown`
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Winner {
+    Player1,
+    Player2,
+    Tie,
+}
+
+/// Cumulative results for one address across every hand it played
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[contracttype]
+pub struct PlayerStats {
+    pub hands_played: u32,
+    pub hands_won: u32,      // hands where the player took all or part of the pot
+    pub chips_won: i128,     // sum of net gains over bets placed, per winning hand
+    pub chips_lost: i128,    // sum of net losses over bets placed, per losing hand
+}
+
+/// Persistent record of a resolved showdown
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct HandResult {
+    pub game_id: BytesN<32>,
+    pub winner: Address,
+    pub pot: i128,                    // pot before rake
+    pub player1_rank: u32,
+    pub player2_rank: u32,
+    pub player1_proof_hash: BytesN<32>,
+    pub player2_proof_hash: BytesN<32>,
+    pub timestamp: u64,               // ledger timestamp at resolution
 }
 
-const GAME_STATE: &str = "GAME_STATE";
 const ZERO_COMMITMENT: [u8; 32] = [0u8; 32];
+/// `set_log_level` levels: per-call summaries, then the showdown trace.
+const LOG_INFO: u32 = 1;
+const LOG_DEBUG: u32 = 2;
+/// Storage layout version written by this build; bump with each migration step.
+const VERSION: u32 = 2;
+/// Instance key of the one table in the schema-1 layout, before games were
+/// stored per game_id.
+const LEGACY_GAME_STATE: &str = "GAME_STATE";
+/// noir_verifier interface version this build calls into.
+const VERIFIER_INTERFACE: u32 = 1;
+/// Maximum seats at a table.
+const MAX_PLAYERS: u32 = 9;
+/// Default minimum bet when no limits are configured.
+const DEFAULT_MIN_BET: i128 = 1;
+/// Default cap on `starting_chips` when no maximum is configured.
+const DEFAULT_MAX_STARTING_CHIPS: i128 = 1_000_000_000;
+/// Hole cards dealt in Texas Hold'em (the default variant).
+const HOLDEM_HOLE_CARDS: u32 = 2;
+/// Most hole cards a variant may deal; matches noir_verifier.
+const MAX_HOLE_CARDS: u32 = 6;
+/// Size of a showdown proof: `[SHA-256(raw proof) 32B][zeros 96B]`.
+/// Entry points spell it as `BytesN<128>` since the contract spec needs a literal.
+const PROOF_LEN: usize = 128;
+/// Leading bytes of a proof holding the attestation lookup hash.
+const PROOF_HASH_LEN: usize = 32;
+/// Most hands returned by a single `get_recent_hands` call.
+const MAX_RECENT_HANDS: u32 = 50;
+/// Largest `chip_decimals`; 10^18 token units per chip still fits in i128.
+const MAX_CHIP_DECIMALS: u32 = 18;
+/// Most showdowns settled by one `resolve_showdowns_batch` call; each makes
+/// two verifier calls, so this keeps a batch within one transaction.
+const MAX_SHOWDOWN_BATCH: u32 = 4;
+/// Most tables returned by a single `get_game_states` call.
+const MAX_GAME_BATCH: u32 = 20;
+/// Upper bound on the configurable rake: 10% of the pot.
+const MAX_RAKE_BPS: u32 = 1_000;
+/// noir_verifier error codes meaning the proof itself failed (cards, rank,
+/// attestation); only these count for `set_disqualify_on_fail`.
+const PROOF_FAILURE_CODES: [u32; 15] = [1, 2, 3, 4, 5, 6, 7, 9, 11, 12, 19, 27, 32, 33, 34];
+/// Cards in a standard deck; card indices run from 0 to 51.
+const DECK_SIZE: u32 = 52;
+/// Board size after the river.
+const MAX_BOARD_CARDS: u32 = 5;
 
 #[contract]
 pub struct PokerGameContract;
@@ -52,35 +305,70 @@ impl PokerGameContract {
         player1: Address,
         player2: Address,
         starting_chips: i128,
+    ) -> GameState {
+        let mut players = Vec::new(&env);
+        players.push_back(player1);
+        players.push_back(player2);
+        Self::init_game_multi(env, game_id, players, starting_chips)
+    }
+
+    /// Initialize a new poker game with 2..=MAX_PLAYERS seats.
+    /// Seat order follows `players`; heads-up showdown uses seats 0 and 1.
+    /// A game_id whose hand is still active is rejected with
+    /// `HandInProgress` — resolve it first or use `force_reset`.
+    pub fn init_game_multi(
+        env: Env,
+        game_id: BytesN<32>,
+        addresses: Vec<Address>,
+        starting_chips: i128,
     ) -> GameState {
         // No require_auth — deployer initializes games on behalf of players
+        Self::require_not_paused(&env);
+        Self::ensure(&env, addresses.len() >= 2 && addresses.len() <= MAX_PLAYERS, PokerError::InvalidPlayerCount);
+        Self::ensure(&env, starting_chips > 0, PokerError::InvalidStartingChips);
+        Self::ensure(&env, starting_chips <= Self::get_max_starting_chips(env.clone()), PokerError::StartingChipsTooHigh);
+        let existing: Option<GameState> = env.storage().persistent().get(&Self::game_key(&game_id));
+        Self::ensure(&env, existing.is_none_or(|g| !g.is_active), PokerError::HandInProgress);
+
         let mut players = Vec::new(&env);
-        
-        // Add player 1
-        players.push_back(Player {
-            address: player1.clone(),
-            chips: starting_chips,
-            commitment: BytesN::from_array(&env, &[0u8; 32]),
-            current_bet: 0,
-            has_folded: false,
-        });
-        
-        // Add player 2
-        players.push_back(Player {
-            address: player2.clone(),
-            chips: starting_chips,
-            commitment: BytesN::from_array(&env, &[0u8; 32]),
-            current_bet: 0,
-            has_folded: false,
-        });
+        for address in addresses.iter() {
+            players.push_back(Player {
+                address,
+                chips: starting_chips,
+                commitment: BytesN::from_array(&env, &ZERO_COMMITMENT),
+                current_bet: 0,
+                has_folded: false,
+                last_action_ledger: env.ledger().sequence(),
+                revealed: false,
+                all_in: false,
+            });
+        }
 
-        // Read verifier contract address from instance storage (set by set_verifier),
-        // or fall back to a zero address.
-        let verifier: Address = env.storage().instance()
-            .get::<_, Address>(&symbol_short!("VERIFIER"))
-            .unwrap_or(player1.clone()); // placeholder — set_verifier should be called once after deploy
-        
-        let state = GameState {
+        // Read verifier contract address from instance storage (set by set_verifier).
+        // Left unset if set_verifier was never called — resolve_showdown rejects it.
+        let verifier: Option<Address> = Self::get_verifier(env.clone());
+
+        // Attestation threshold: ask the verifier for each player's verified
+        // history and reject any player below it.
+        let min_attestations = Self::get_min_player_attestations(env.clone());
+        if min_attestations > 0 {
+            let Some(verifier) = verifier.as_ref() else {
+                panic_with_error!(&env, PokerError::VerifierNotConfigured);
+            };
+            for address in addresses.iter() {
+                let count: u32 = env.invoke_contract(
+                    verifier,
+                    &Symbol::new(&env, "get_player_attestation_count"),
+                    (address,).into_val(&env),
+                );
+                Self::ensure(&env, count >= min_attestations, TableError::InsufficientAttestations);
+            }
+        }
+
+        // Table configuration (bet limits, hole cards, ...) copied into the game at init
+        let rules = Self::get_table_rules(env.clone());
+
+        let mut state = GameState {
             game_id: game_id.clone(),
             players,
             pot: 0,
@@ -92,126 +380,1189 @@ impl PokerGameContract {
             player1_proof_hash: BytesN::from_array(&env, &ZERO_COMMITMENT),
             player2_proof_hash: BytesN::from_array(&env, &ZERO_COMMITMENT),
             verifier_contract: verifier,
+            min_bet: rules.min_bet,
+            max_bet: rules.max_bet,
+            deck_commitment: BytesN::from_array(&env, &ZERO_COMMITMENT),
+            deck_seed: BytesN::from_array(&env, &ZERO_COMMITMENT),
+            chip_supply: starting_chips.checked_mul(addresses.len() as i128)
+                .unwrap_or_else(|| panic_with_error!(&env, TableError::ArithmeticOverflow)),
+            hole_cards: rules.hole_cards,
+            hand_ordering: rules.hand_ordering,
+            require_attestation: rules.require_attestation,
+            ante: rules.ante,
         };
-        
-        env.storage().instance().set(&GAME_STATE, &state);
+        state.current_player = Self::first_to_act(&state);
+
+        Self::save_game(&env, &state);
+        env.storage().persistent().remove(&Self::raise_key(&game_id));
+        env.storage().persistent().remove(&Self::seeds_key(&game_id));
+        env.storage().persistent().remove(&Self::cipher_key(&game_id));
+        match Self::get_limit_structure(env.clone()) {
+            Some(limits) => env.storage().persistent().set(&Self::limit_key(&game_id), &limits),
+            None => env.storage().persistent().remove(&Self::limit_key(&game_id)),
+        }
+        // The first game on a fresh deploy is written in the current layout
+        if !env.storage().instance().has(&symbol_short!("STARTED")) {
+            env.storage().instance().set(&symbol_short!("SCHEMA"), &VERSION);
+        }
+        // Locks set_verifier to the admin from now on
+        env.storage().instance().set(&symbol_short!("STARTED"), &true);
         state
     }
 
+    /// Storage layout version this build reads and writes.
+    pub fn version(_env: Env) -> u32 {
+        VERSION
+    }
+
+    /// Storage layout version of the data already on-chain. A deploy with
+    /// no games yet reports `VERSION`; one that ran games before the marker
+    /// existed, or still holds its table under the schema-1 `GAME_STATE`
+    /// key, reports 1. Anything below `version()` needs `migrate`.
+    pub fn get_schema_version(env: Env) -> u32 {
+        let storage = env.storage().instance();
+        storage.get(&symbol_short!("SCHEMA")).unwrap_or_else(|| {
+            let legacy = storage.has(&symbol_short!("STARTED")) || storage.has(&LEGACY_GAME_STATE);
+            if legacy { 1 } else { VERSION }
+        })
+    }
+
+    /// Bring stored data up to `VERSION` (admin only) and return the new
+    /// schema version. Emits `("migrate", from)` with the new version when a
+    /// step ran; a no-op when already current.
+    ///
+    /// From schema 1, a table still under the instance `GAME_STATE` key is
+    /// moved to per-game storage under its own `game_id`, with the fields
+    /// added since filled from the current table rules, and the old key is
+    /// removed.
+    pub fn migrate(env: Env, admin: Address) -> u32 {
+        Self::require_admin(&env, &admin);
+        let from = Self::get_schema_version(env.clone());
+        if from >= VERSION {
+            return from;
+        }
+
+        // 1 → 2: the single instance-stored table moves to its game_id key
+        let legacy: Option<LegacyGameState> = env.storage().instance().get(&LEGACY_GAME_STATE);
+        if let Some(legacy) = legacy {
+            let state = Self::upgrade_legacy_game(&env, legacy);
+            Self::save_game(&env, &state);
+            env.storage().instance().remove(&LEGACY_GAME_STATE);
+            env.storage().instance().set(&symbol_short!("STARTED"), &true);
+        }
+        env.storage().instance().set(&symbol_short!("SCHEMA"), &VERSION);
+        env.events().publish((symbol_short!("migrate"), from), VERSION);
+        VERSION
+    }
+
+    /// Drop a game's state so the game_id can be initialized again, even
+    /// mid-hand (admin only). Chips and pot of the dropped game are lost;
+    /// meant for recovering a stuck table.
+    pub fn force_reset(env: Env, admin: Address, game_id: BytesN<32>) {
+        Self::require_admin(&env, &admin);
+        Self::load_game(&env, &game_id);
+        env.storage().persistent().remove(&Self::game_key(&game_id));
+        env.storage().persistent().remove(&Self::raise_key(&game_id));
+        env.storage().persistent().remove(&Self::seeds_key(&game_id));
+        env.storage().persistent().remove(&Self::cipher_key(&game_id));
+        env.storage().persistent().remove(&Self::limit_key(&game_id));
+        env.events().publish((symbol_short!("reset"), game_id), admin);
+    }
+
     /// Set the noir_verifier contract address (call once after deploy).
+    ///
+    /// Open until the first game is initialized; after that only the admin
+    /// may change it, and each change is published as a `verifier` event.
+    /// Games already running keep the verifier captured at their init.
+    ///
+    /// If the verifier exposes `verifier_version`, it must report
+    /// `VERIFIER_INTERFACE` (`VerifierNotConfigured` otherwise) and the
+    /// version is stored; older verifiers without it are accepted as-is.
+    ///
+    /// With `set_verifier_ping` enabled, the verifier must also answer `ping`,
+    /// so a wrong address fails here (`VerifierNotConfigured`) rather than at
+    /// the first showdown.
     pub fn set_verifier(env: Env, verifier: Address) {
+        let started: bool = env.storage().instance().get(&symbol_short!("STARTED")).unwrap_or(false);
+        if started {
+            let admin: Address = env.storage().instance()
+                .get(&symbol_short!("ADMIN"))
+                .unwrap_or_else(|| panic_with_error!(&env, PokerError::AdminNotConfigured));
+            admin.require_auth();
+        }
+        if Self::is_verifier_ping(env.clone()) {
+            let pinged = env.try_invoke_contract::<u32, soroban_sdk::Error>(
+                &verifier,
+                &Symbol::new(&env, "ping"),
+                Vec::new(&env),
+            );
+            Self::ensure(&env, matches!(pinged, Ok(Ok(_))), PokerError::VerifierNotConfigured);
+        }
+        if started {
+            env.events().publish(
+                (symbol_short!("verifier"), symbol_short!("changed")),
+                (Self::get_verifier(env.clone()), verifier.clone()),
+            );
+        }
+        let reported = env.try_invoke_contract::<u32, soroban_sdk::Error>(
+            &verifier,
+            &Symbol::new(&env, "verifier_version"),
+            Vec::new(&env),
+        );
+        match reported {
+            Ok(Ok(version)) => {
+                Self::ensure(&env, version == VERIFIER_INTERFACE, PokerError::VerifierNotConfigured);
+                env.storage().instance().set(&symbol_short!("VERIF_VER"), &version);
+            }
+            _ => env.storage().instance().remove(&symbol_short!("VERIF_VER")),
+        }
         env.storage().instance().set(&symbol_short!("VERIFIER"), &verifier);
     }
+
+    /// Require `set_verifier` to health-check new verifiers via `ping`.
+    pub fn set_verifier_ping(env: Env, admin: Address, enabled: bool) {
+        Self::require_admin(&env, &admin);
+
+        env.storage().instance().set(&symbol_short!("PING_VER"), &enabled);
+    }
+
+    /// Whether `set_verifier` pings new verifiers (default: false).
+    pub fn is_verifier_ping(env: Env) -> bool {
+        env.storage().instance().get(&symbol_short!("PING_VER")).unwrap_or(false)
+    }
+
+    /// Interface version reported by the configured verifier, or `None`
+    /// if it predates `verifier_version`.
+    pub fn get_verifier_version(env: Env) -> Option<u32> {
+        env.storage().instance().get(&symbol_short!("VERIF_VER"))
+    }
+
+    /// Active rule set: the configured values (or their defaults) that the
+    /// next `init_game` copies into a new game.
+    pub fn get_table_rules(env: Env) -> TableRules {
+        let storage = env.storage().instance();
+        let (min_bet, max_bet): (i128, i128) = storage
+            .get(&symbol_short!("BET_LIM"))
+            .unwrap_or((DEFAULT_MIN_BET, i128::MAX));
+        let limits = Self::get_limit_structure(env.clone());
+        TableRules {
+            min_bet,
+            max_bet,
+            small_bet: limits.as_ref().map_or(0, |l| l.small_bet),
+            big_bet: limits.map_or(0, |l| l.big_bet),
+            ante: storage.get(&symbol_short!("ANTE")).unwrap_or(0),
+            rake_bps: storage.get(&symbol_short!("RAKE_BPS")).unwrap_or(0),
+            hand_ordering: storage.get(&symbol_short!("ORDERING")).unwrap_or(HandOrdering::HighWins),
+            hole_cards: storage.get(&symbol_short!("HOLE_CNT")).unwrap_or(HOLDEM_HOLE_CARDS),
+            require_attestation: storage.get(&symbol_short!("REQ_ATT")).unwrap_or(true),
+            require_reveal: storage.get(&symbol_short!("REQ_REVL")).unwrap_or(false),
+        }
+    }
+
+    /// Get the configured noir_verifier contract address, if any.
+    pub fn get_verifier(env: Env) -> Option<Address> {
+        env.storage().instance().get(&symbol_short!("VERIFIER"))
+    }
+
+    /// Set the table admin (operator).
+    /// The first call claims the role; afterwards only the current admin can hand it over.
+    pub fn set_admin(env: Env, admin: Address) {
+        if let Some(current) = env.storage().instance().get::<_, Address>(&symbol_short!("ADMIN")) {
+            current.require_auth();
+        }
+        admin.require_auth();
+        env.storage().instance().set(&symbol_short!("ADMIN"), &admin);
+    }
+
+    /// Configure the house rake taken from each showdown pot.
+    /// `basis_points` is capped at MAX_RAKE_BPS (10%); 0 disables the rake.
+    pub fn set_rake(env: Env, admin: Address, basis_points: u32, fee_collector: Address) {
+        Self::require_admin(&env, &admin);
+        Self::ensure(&env, basis_points <= MAX_RAKE_BPS, PokerError::RakeTooHigh);
+
+        env.storage().instance().set(&symbol_short!("RAKE_BPS"), &basis_points);
+        env.storage().instance().set(&symbol_short!("RAKE_TO"), &fee_collector);
+    }
+
+    /// Configure the bet limits applied to games initialized afterwards.
+    /// Bets below `min_bet` are rejected unless they put the player all-in;
+    /// bets above `max_bet` (the table limit) are always rejected.
+    pub fn set_bet_limits(env: Env, admin: Address, min_bet: i128, max_bet: i128) {
+        Self::require_admin(&env, &admin);
+        Self::ensure(&env, min_bet > 0, PokerError::InvalidBetLimits);
+        Self::ensure(&env, max_bet >= min_bet, PokerError::InvalidBetLimits);
+
+        env.storage().instance().set(&symbol_short!("BET_LIM"), &(min_bet, max_bet));
+    }
+
+    /// Switch games initialized afterwards to fixed-limit betting, or back
+    /// to no-limit with `None`. Under a limit structure each bet or raise
+    /// must be exactly `small_bet` preflop/flop and `big_bet` turn/river,
+    /// unless it puts the player all-in.
+    pub fn set_limit_structure(env: Env, admin: Address, limits: Option<LimitStructure>) {
+        Self::require_admin(&env, &admin);
+        match limits {
+            Some(limits) => {
+                Self::ensure(&env, limits.small_bet > 0, PokerError::InvalidBetLimits);
+                Self::ensure(&env, limits.big_bet >= limits.small_bet, PokerError::InvalidBetLimits);
+                env.storage().instance().set(&symbol_short!("LIMIT"), &limits);
+            }
+            None => env.storage().instance().remove(&symbol_short!("LIMIT")),
+        }
+    }
+
+    /// Limit structure applied to new games, if the table is fixed-limit.
+    pub fn get_limit_structure(env: Env) -> Option<LimitStructure> {
+        env.storage().instance().get(&symbol_short!("LIMIT"))
+    }
+
+    /// Set the ante collected from every player by `collect_antes`, on top
+    /// of the blinds (0 disables antes). Copied into each game at init.
+    pub fn set_ante(env: Env, admin: Address, amount: i128) {
+        Self::require_admin(&env, &admin);
+        Self::ensure(&env, amount >= 0, PokerError::InvalidAnte);
+
+        env.storage().instance().set(&symbol_short!("ANTE"), &amount);
+    }
+
+    /// Move the dealer button of a game to `seat`. On a split pot the odd
+    /// chip goes to the first tied seat left of the button, so this decides
+    /// who receives it.
+    pub fn set_dealer_button(env: Env, admin: Address, game_id: BytesN<32>, seat: u32) {
+        Self::require_admin(&env, &admin);
+        let mut state = Self::load_game(&env, &game_id);
+        Self::ensure(&env, seat < state.players.len(), PokerError::PlayerNotFound);
+
+        state.dealer_button = seat;
+        state.current_player = Self::first_to_act(&state);
+        Self::save_game(&env, &state);
+    }
+
+    /// Set the hole-card count for new games (2 = Hold'em, 4 = Omaha).
+    /// Copied into each game at init, like the bet limits.
+    pub fn set_hole_cards(env: Env, admin: Address, count: u32) {
+        Self::require_admin(&env, &admin);
+        Self::ensure(&env, (HOLDEM_HOLE_CARDS..=MAX_HOLE_CARDS).contains(&count), PokerError::UnsupportedHoleCards);
+
+        env.storage().instance().set(&symbol_short!("HOLE_CNT"), &count);
+    }
+
+    /// Set whether higher or lower ranks win in new games (lowball uses
+    /// `LowWins`). Copied into each game at init.
+    pub fn set_hand_ordering(env: Env, admin: Address, ordering: HandOrdering) {
+        Self::require_admin(&env, &admin);
+        env.storage().instance().set(&symbol_short!("ORDERING"), &ordering);
+    }
+
+    /// Set whether showdown proofs in new games must carry a recorded
+    /// zkVerify attestation (default true). Casual tables can turn this off
+    /// to accept unattested proofs. Copied into each game at init.
+    pub fn set_require_attestation(env: Env, admin: Address, required: bool) {
+        Self::require_admin(&env, &admin);
+        env.storage().instance().set(&symbol_short!("REQ_ATT"), &required);
+    }
+
+    /// Set the largest `starting_chips` a new game may be created with.
+    pub fn set_max_starting_chips(env: Env, admin: Address, max: i128) {
+        Self::require_admin(&env, &admin);
+        Self::ensure(&env, max > 0, PokerError::InvalidStartingChips);
+
+        env.storage().instance().set(&symbol_short!("MAX_CHIP"), &max);
+    }
+
+    /// Largest `starting_chips` accepted by `init_game`.
+    pub fn get_max_starting_chips(env: Env) -> i128 {
+        env.storage().instance()
+            .get(&symbol_short!("MAX_CHIP"))
+            .unwrap_or(DEFAULT_MAX_STARTING_CHIPS)
+    }
+
+    /// Set the highest rank a showdown claim may make (default 9, no cap).
+    /// `resolve_showdown` rejects higher claims with `InvalidRank`.
+    pub fn set_max_claimable_rank(env: Env, admin: Address, rank: u32) {
+        Self::require_admin(&env, &admin);
+        Self::ensure(&env, rank <= 9, PokerError::InvalidRank);
+
+        env.storage().instance().set(&symbol_short!("MAX_RANK"), &rank);
+    }
+
+    /// Highest rank `resolve_showdown` accepts.
+    pub fn get_max_claimable_rank(env: Env) -> u32 {
+        env.storage().instance().get(&symbol_short!("MAX_RANK")).unwrap_or(9)
+    }
+
+    /// Set a suspicion threshold: contested claims above `rank` still
+    /// resolve, but publish a `flagged` event for off-chain review.
+    pub fn set_flag_rank(env: Env, admin: Address, rank: u32) {
+        Self::require_admin(&env, &admin);
+        Self::ensure(&env, rank <= 9, PokerError::InvalidRank);
+
+        env.storage().instance().set(&symbol_short!("FLAG_RANK"), &rank);
+    }
+
+    /// Suspicion threshold set by `set_flag_rank`, if any.
+    pub fn get_flag_rank(env: Env) -> Option<u32> {
+        env.storage().instance().get(&symbol_short!("FLAG_RANK"))
+    }
+
+    /// Require every seated player to hold at least `min` verified
+    /// attestations on the verifier before `init_game` admits them (0 = off).
+    pub fn set_min_player_attestations(env: Env, admin: Address, min: u32) {
+        Self::require_admin(&env, &admin);
+
+        env.storage().instance().set(&symbol_short!("MIN_ATT"), &min);
+    }
+
+    /// Attestation threshold set by `set_min_player_attestations`, 0 if unset.
+    pub fn get_min_player_attestations(env: Env) -> u32 {
+        env.storage().instance().get(&symbol_short!("MIN_ATT")).unwrap_or(0)
+    }
+
+    /// Configure how many ledgers a hand may sit without any action before
+    /// `reclaim` can refund it.
+    pub fn set_abandon_window(env: Env, admin: Address, ledgers: u32) {
+        Self::require_admin(&env, &admin);
+        Self::ensure(&env, ledgers > 0, PokerError::InvalidTimeout);
+
+        env.storage().instance().set(&symbol_short!("ABANDON"), &ledgers);
+    }
+
+    /// Configure how many ledgers a player may take to act before
+    /// `force_timeout_fold` can fold them.
+    pub fn set_action_timeout(env: Env, admin: Address, ledgers: u32) {
+        Self::require_admin(&env, &admin);
+        Self::ensure(&env, ledgers > 0, PokerError::InvalidTimeout);
+
+        env.storage().instance().set(&symbol_short!("TIMEOUT"), &ledgers);
+    }
+
+    /// Emergency stop: while paused, every state-mutating game action panics.
+    /// Read-only queries and admin configuration keep working.
+    pub fn set_paused(env: Env, admin: Address, paused: bool) {
+        Self::require_admin(&env, &admin);
+        env.storage().instance().set(&symbol_short!("PAUSED"), &paused);
+        env.events().publish((symbol_short!("paused"),), paused);
+    }
+
+    /// Whether the table is currently paused.
+    pub fn is_paused(env: Env) -> bool {
+        env.storage().instance().get(&symbol_short!("PAUSED")).unwrap_or(false)
+    }
+
+    /// Require both players to pass `verify_reveal` before `resolve_showdown`.
+    pub fn set_require_reveal(env: Env, admin: Address, required: bool) {
+        Self::require_admin(&env, &admin);
+        env.storage().instance().set(&symbol_short!("REQ_REVL"), &required);
+    }
+
+    /// Disqualify instead of reverting: with this on, a player whose proof
+    /// fails verification at showdown forfeits the pot to the other player
+    /// (published as a `dq` event) rather than the showdown reverting.
+    pub fn set_disqualify_on_fail(env: Env, admin: Address, enabled: bool) {
+        Self::require_admin(&env, &admin);
+        env.storage().instance().set(&symbol_short!("DQ_FAIL"), &enabled);
+    }
+
+    /// Whether a failed showdown proof disqualifies instead of reverting.
+    pub fn is_disqualify_on_fail(env: Env) -> bool {
+        env.storage().instance().get(&symbol_short!("DQ_FAIL")).unwrap_or(false)
+    }
+
+    /// Set how much the contract logs: 0 silences `log!` output,
+    /// `LOG_INFO` (1) keeps per-call summaries and `LOG_DEBUG` (2,
+    /// the default) adds the step-by-step showdown trace. Published events
+    /// are unaffected.
+    pub fn set_log_level(env: Env, admin: Address, level: u32) {
+        Self::require_admin(&env, &admin);
+        env.storage().instance().set(&symbol_short!("LOG_LVL"), &level);
+    }
+
+    /// Current log level (see `set_log_level`).
+    pub fn get_log_level(env: Env) -> u32 {
+        Self::log_level(&env)
+    }
+
+    /// Total rake credited to the fee collector so far.
+    pub fn get_collected_rake(env: Env) -> i128 {
+        env.storage().instance().get(&symbol_short!("RAKE_ACC")).unwrap_or(0)
+    }
     
+    /// Commit to the shuffle before dealing: `deck_commitment = SHA-256(seed)`.
+    /// Can only be set once per game.
+    pub fn commit_deck(env: Env, game_id: BytesN<32>, deck_commitment: BytesN<32>) {
+        Self::require_not_paused(&env);
+        let zero = BytesN::from_array(&env, &ZERO_COMMITMENT);
+        Self::ensure(&env, deck_commitment != zero, PokerError::ZeroCommitment);
+
+        let mut state = Self::load_game(&env, &game_id);
+        Self::ensure(&env, state.is_active, PokerError::NotActive);
+        Self::ensure(&env, state.deck_commitment == zero, PokerError::DeckAlreadyCommitted);
+
+        state.deck_commitment = deck_commitment.clone();
+        Self::save_game(&env, &state);
+        env.events().publish((symbol_short!("deck"), symbol_short!("commit")), deck_commitment);
+    }
+
+    /// Reveal the shuffle seed once the hand is over.
+    /// Asserts SHA-256(seed) matches the committed deck and publishes the seed
+    /// so players can reconstruct and audit the shuffle.
+    pub fn reveal_deck(env: Env, game_id: BytesN<32>, seed: BytesN<32>) {
+        let mut state = Self::load_game(&env, &game_id);
+        let zero = BytesN::from_array(&env, &ZERO_COMMITMENT);
+        Self::ensure(&env, state.deck_commitment != zero, PokerError::DeckNotCommitted);
+        Self::ensure(&env, !state.is_active, PokerError::HandInProgress);
+
+        let computed: BytesN<32> = env.crypto().sha256(&seed.clone().into()).into();
+        Self::ensure(&env, computed == state.deck_commitment, PokerError::DeckSeedMismatch);
+
+        // Whatever board was revealed must be the one this seed deals
+        let board = Self::board_from_seed(&env, &seed);
+        for (i, card) in state.community_cards.iter().enumerate() {
+            Self::ensure(&env, board.get(i as u32) == Some(card), PokerError::DeckSeedMismatch);
+        }
+
+        state.deck_seed = seed.clone();
+        Self::save_game(&env, &state);
+        env.events().publish((symbol_short!("deck"), symbol_short!("reveal")), seed);
+    }
+
+    /// Commit to this player's share of the shuffle entropy before the
+    /// deal: `seed_commitment = SHA-256(seed)`. Once per player per hand.
+    pub fn submit_seed_commitment(env: Env, game_id: BytesN<32>, player: Address, seed_commitment: BytesN<32>) {
+        Self::require_not_paused(&env);
+        let zero = BytesN::from_array(&env, &ZERO_COMMITMENT);
+        Self::ensure(&env, seed_commitment != zero, PokerError::ZeroCommitment);
+
+        let state = Self::load_game(&env, &game_id);
+        Self::ensure(&env, state.is_active, PokerError::NotActive);
+        Self::ensure(&env, state.community_cards.is_empty(), PokerError::WrongRound);
+        let seat = Self::seat_of(&state, &player);
+        let mut seeds = Self::load_seeds(&env, &state);
+        let (committed, _) = seeds.get(seat).unwrap();
+        Self::ensure(&env, committed == zero, PokerError::DeckAlreadyCommitted);
+
+        seeds.set(seat, (seed_commitment, zero));
+        env.storage().persistent().set(&Self::seeds_key(&game_id), &seeds);
+        env.events().publish((symbol_short!("seed"), symbol_short!("commit")), player);
+    }
+
+    /// Reveal this player's shuffle seed. Only allowed once every seat has
+    /// committed, so nobody can pick a seed after seeing another's.
+    pub fn reveal_seed(env: Env, game_id: BytesN<32>, player: Address, seed: BytesN<32>) {
+        Self::require_not_paused(&env);
+        let zero = BytesN::from_array(&env, &ZERO_COMMITMENT);
+        let state = Self::load_game(&env, &game_id);
+        let seat = Self::seat_of(&state, &player);
+        let mut seeds = Self::load_seeds(&env, &state);
+        Self::ensure(&env, seeds.iter().all(|(c, _)| c != zero), PokerError::DeckNotCommitted);
+
+        let (committed, _) = seeds.get(seat).unwrap();
+        let computed: BytesN<32> = env.crypto().sha256(&seed.clone().into()).into();
+        Self::ensure(&env, computed == committed, PokerError::DeckSeedMismatch);
+
+        seeds.set(seat, (committed, seed.clone()));
+        env.storage().persistent().set(&Self::seeds_key(&game_id), &seeds);
+        env.events().publish((symbol_short!("seed"), symbol_short!("reveal")), (player, seed));
+    }
+
+    /// Shuffle seed for the hand: SHA-256 of every player's revealed seed
+    /// in seat order. Panics with `NotRevealed` until all seeds are in.
+    pub fn compute_shuffle_seed(env: Env, game_id: BytesN<32>) -> BytesN<32> {
+        let zero = BytesN::from_array(&env, &ZERO_COMMITMENT);
+        let state = Self::load_game(&env, &game_id);
+
+        let mut input = Bytes::new(&env);
+        for (_, seed) in Self::load_seeds(&env, &state).iter() {
+            Self::ensure(&env, seed != zero, PokerError::NotRevealed);
+            input.append(&seed.into());
+        }
+        env.crypto().sha256(&input).into()
+    }
+
     /// Submit card commitment: SHA-256(hole_cards || salt).
     /// Must be called by BOTH players before showdown.
     /// Rejects zero commitments — a valid SHA-256 hash is always non-zero.
     pub fn submit_commitment(
         env: Env,
+        game_id: BytesN<32>,
         player: Address,
         commitment: BytesN<32>,
     ) {
+        Self::require_not_paused(&env);
         // Reject zero commitment — prevents bypassing the scheme
         let zero = BytesN::from_array(&env, &ZERO_COMMITMENT);
-        assert!(commitment != zero, "Cannot submit zero commitment");
+        Self::ensure(&env, commitment != zero, PokerError::ZeroCommitment);
 
-        let mut state: GameState = env.storage().instance().get(&GAME_STATE).unwrap();
-        
-        // Find player and update commitment
-        let mut found = false;
-        for i in 0..state.players.len() {
-            let mut p = state.players.get(i).unwrap();
-            if p.address == player {
-                p.commitment = commitment;
-                state.players.set(i, p);
-                found = true;
-                break;
-            }
-        }
-        assert!(found, "Player not found in game");
+        let mut state = Self::load_game(&env, &game_id);
         
-        env.storage().instance().set(&GAME_STATE, &state);
-        log!(&env, "submit_commitment: player={:?}", player);
+        let i = Self::seat_of(&state, &player);
+        let mut p = state.players.get(i).unwrap();
+        p.commitment = commitment;
+        p.revealed = false;
+        p.last_action_ledger = env.ledger().sequence();
+        state.players.set(i, p);
+
+        Self::save_game(&env, &state);
+        log_at!(&env, LOG_INFO, "submit_commitment: player={:?}", player);
     }
     
+    /// Reveal hole cards and check them against the stored commitment.
+    ///
+    /// `cards` holds the table's `hole_cards` card indices (2 for Hold'em,
+    /// 4 for Omaha). Recomputes the commitment with `sha256_commitment` (see
+    /// there for the exact byte layout) and asserts it equals the value
+    /// submitted via `submit_commitment`, then marks the player as revealed.
+    pub fn verify_reveal(
+        env: Env,
+        game_id: BytesN<32>,
+        player: Address,
+        cards: Bytes,
+        salt: BytesN<32>,
+    ) {
+        Self::require_not_paused(&env);
+        let mut state = Self::load_game(&env, &game_id);
+        let i = Self::seat_of(&state, &player);
+        let mut p = state.players.get(i).unwrap();
+
+        let zero = BytesN::from_array(&env, &ZERO_COMMITMENT);
+        Self::ensure(&env, p.commitment != zero, PokerError::MissingCommitment);
+        Self::ensure(&env, cards.len() == state.hole_cards, PokerError::WrongHoleCardCount);
+        let computed = Self::sha256_commitment(&env, &cards, &salt);
+        Self::ensure(&env, computed == p.commitment, PokerError::CardsMismatch);
+        Self::claim_salt(&env, &game_id, &player, &salt, &p.commitment);
+
+        p.revealed = true;
+        state.players.set(i, p);
+        Self::save_game(&env, &state);
+        env.events().publish((symbol_short!("reveal"), player), cards);
+    }
+
+    /// Store `player`'s hole cards encrypted under a reveal key, alongside
+    /// the commitment already submitted for them.
+    ///
+    /// `ciphertext` is `cards || salt` (the table's `hole_cards` + 32 bytes,
+    /// the `sha256_commitment` layout) XORed with the keystream SHA-256(key || block) for 32-bit
+    /// big-endian block counters. Publishing the key after the hand with
+    /// `reveal_encrypted_cards` lets anyone check the cards against it.
+    pub fn submit_encrypted_cards(
+        env: Env,
+        game_id: BytesN<32>,
+        player: Address,
+        ciphertext: Bytes,
+    ) {
+        Self::require_not_paused(&env);
+        let state = Self::load_game(&env, &game_id);
+        Self::ensure(&env, state.is_active, PokerError::NotActive);
+        let p = state.players.get(Self::seat_of(&state, &player)).unwrap();
+
+        let zero = BytesN::from_array(&env, &ZERO_COMMITMENT);
+        Self::ensure(&env, p.commitment != zero, PokerError::MissingCommitment);
+        Self::ensure(&env, ciphertext.len() == state.hole_cards + 32, PokerError::WrongHoleCardCount);
+
+        let key = Self::cipher_key(&game_id);
+        let mut stored: Map<Address, EncryptedCards> = env.storage().persistent()
+            .get(&key)
+            .unwrap_or_else(|| Map::new(&env));
+        stored.set(player, EncryptedCards { commitment: p.commitment, ciphertext });
+        env.storage().persistent().set(&key, &stored);
+    }
+
+    /// Encrypted hole cards `player` submitted for the current hand, if any.
+    pub fn get_encrypted_cards(env: Env, game_id: BytesN<32>, player: Address) -> Option<EncryptedCards> {
+        let stored: Option<Map<Address, EncryptedCards>> = env.storage().persistent().get(&Self::cipher_key(&game_id));
+        stored.and_then(|m| m.get(player))
+    }
+
+    /// Publish the reveal key for `player`'s encrypted hole cards once the
+    /// hand is over. Anyone may call it.
+    ///
+    /// Decrypts the stored ciphertext and panics with `CardsMismatch` unless
+    /// the recovered `cards || salt` hashes to the stored commitment. On
+    /// success emits `("unsealed", player)` with `(game_id, cards, key)` and
+    /// returns the cards.
+    pub fn reveal_encrypted_cards(
+        env: Env,
+        game_id: BytesN<32>,
+        player: Address,
+        key: BytesN<32>,
+    ) -> Bytes {
+        let state = Self::load_game(&env, &game_id);
+        Self::ensure(&env, !state.is_active, PokerError::HandInProgress);
+        let sealed = Self::get_encrypted_cards(env.clone(), game_id.clone(), player.clone())
+            .unwrap_or_else(|| panic_with_error!(&env, PokerError::MissingCommitment));
+
+        // The plaintext is the commitment preimage itself
+        let plain = Self::keystream_xor(&env, &key, &sealed.ciphertext);
+        let cards = plain.slice(0..state.hole_cards);
+        let computed: BytesN<32> = env.crypto().sha256(&plain).into();
+        Self::ensure(&env, computed == sealed.commitment, PokerError::CardsMismatch);
+
+        env.events().publish((symbol_short!("unsealed"), player), (game_id, cards.clone(), key));
+        cards
+    }
+
+    /// Check hole cards against the stored commitment without revealing.
+    ///
+    /// Read-only counterpart of `verify_reveal`: takes the table's
+    /// `hole_cards` cards, recomputes the commitment over the canonical
+    /// `cards || salt` preimage (see `sha256_commitment`) and panics with
+    /// `CardsMismatch` unless it equals the submitted value.
+    /// Nothing is recorded, so the salt can still be revealed later.
+    pub fn verify_commitment_onchain(
+        env: Env,
+        game_id: BytesN<32>,
+        player: Address,
+        cards: Bytes,
+        salt: BytesN<32>,
+    ) -> bool {
+        let state = Self::load_game(&env, &game_id);
+        let p = state.players.get(Self::seat_of(&state, &player)).unwrap();
+
+        let zero = BytesN::from_array(&env, &ZERO_COMMITMENT);
+        Self::ensure(&env, p.commitment != zero, PokerError::MissingCommitment);
+        Self::ensure(&env, cards.len() == state.hole_cards, PokerError::WrongHoleCardCount);
+        let computed = Self::sha256_commitment(&env, &cards, &salt);
+        Self::ensure(&env, computed == p.commitment, PokerError::CardsMismatch);
+        true
+    }
+
     /// Place a bet
     pub fn place_bet(
         env: Env,
+        game_id: BytesN<32>,
         player: Address,
         amount: i128,
     ) {
+        Self::require_not_paused(&env);
         // No require_auth — trusted deployer signs on behalf of players (hackathon MVP)
-        let mut state: GameState = env.storage().instance().get(&GAME_STATE).unwrap();
+        let mut state = Self::load_game(&env, &game_id);
+        
+        let i = Self::seat_of(&state, &player);
+        let mut p = state.players.get(i).unwrap();
+        Self::ensure(&env, !p.has_folded, PokerError::PlayerFolded);
+        Self::ensure(&env, amount > 0, PokerError::InvalidAmount);
+        Self::ensure(&env, p.chips >= amount, PokerError::InsufficientChips);
+        Self::ensure(&env, amount <= state.max_bet, PokerError::BetAboveLimit);
+        Self::ensure(&env, amount >= state.min_bet || amount == p.chips, PokerError::BetBelowMinimum);
+
+        // No-limit: a raise must be at least the previous raise,
+        // unless it puts the player all-in (which does not reopen it)
+        let to_call = Self::chip_sub(&env, Self::highest_bet(&state), p.current_bet);
+        let raise = Self::chip_sub(&env, amount, to_call);
+
+        // Fixed-limit: a bet or raise is exactly this street's increment
+        let limits: Option<LimitStructure> = env.storage().persistent().get(&Self::limit_key(&game_id));
+        if let (Some(limits), true) = (limits, raise > 0 && amount < p.chips) {
+            let increment = match state.current_round {
+                BettingRound::Preflop | BettingRound::Flop => limits.small_bet,
+                _ => limits.big_bet,
+            };
+            Self::ensure(&env, raise <= increment, PokerError::BetAboveLimit);
+            Self::ensure(&env, raise >= increment, PokerError::BetBelowMinimum);
+        }
+        if raise > 0 {
+            let last_raise: i128 = env.storage().persistent().get(&Self::raise_key(&game_id)).unwrap_or(0);
+            if amount < p.chips {
+                Self::ensure(&env, raise >= last_raise, PokerError::BetBelowMinimum);
+            }
+            if raise >= last_raise {
+                env.storage().persistent().set(&Self::raise_key(&game_id), &raise);
+            }
+        }
+
+        p.chips = Self::chip_sub(&env, p.chips, amount);
+        p.current_bet = Self::chip_add(&env, p.current_bet, amount);
+        p.all_in = p.chips == 0;
+        p.last_action_ledger = env.ledger().sequence();
+        state.pot = Self::chip_add(&env, state.pot, amount);
+        state.players.set(i, p);
+
+
+        // Move to next player still in the hand
+        state.current_player = Self::next_active_player(&state, state.current_player);
         
-        // Find player and update bet
+        Self::save_game(&env, &state);
+    }
+    
+    /// Collect the game's ante from every player still in the hand.
+    ///
+    /// Must run at the start of a hand, before any bet reaches the pot.
+    /// A player who cannot cover the ante puts in what they have and is
+    /// marked `all_in`. Antes are dead money: they do not count toward
+    /// `current_bet`, so they are never refunded as an uncalled bet.
+    pub fn collect_antes(env: Env, game_id: BytesN<32>) -> i128 {
+        Self::require_not_paused(&env);
+        let mut state = Self::load_game(&env, &game_id);
+        Self::ensure(&env, state.is_active, PokerError::NotActive);
+        Self::ensure(&env, state.ante > 0, PokerError::NoAnte);
+        Self::ensure(&env, state.pot == 0, PokerError::BetsAlreadyPlaced);
+
         for i in 0..state.players.len() {
             let mut p = state.players.get(i).unwrap();
-            if p.address == player {
-                assert!(p.chips >= amount, "Insufficient chips");
-                p.chips -= amount;
-                p.current_bet += amount;
-                state.pot += amount;
-                state.players.set(i, p);
-                break;
+            if p.has_folded {
+                continue;
             }
+            let paid = state.ante.min(p.chips);
+            p.chips = Self::chip_sub(&env, p.chips, paid);
+            p.all_in = p.chips == 0;
+            state.pot = Self::chip_add(&env, state.pot, paid);
+            state.players.set(i, p);
         }
+        if state.players.get(state.current_player).unwrap().all_in {
+            state.current_player = Self::next_active_player(&state, state.current_player);
+        }
+
+        let collected = state.pot;
+        Self::save_game(&env, &state);
+        env.events().publish((symbol_short!("antes"), game_id), collected);
+        collected
+    }
+
+    /// Push the player's entire remaining stack into the pot.
+    ///
+    /// Same as `place_bet` with the full chip count: the player is marked
+    /// `all_in` and skipped for the rest of the hand's turns. If their total
+    /// ends up short of the other seat's, `resolve_showdown` refunds the
+    /// uncalled excess.
+    pub fn all_in(env: Env, game_id: BytesN<32>, player: Address) {
+        let state = Self::load_game(&env, &game_id);
+        let p = state.players.get(Self::seat_of(&state, &player)).unwrap();
+        Self::ensure(&env, !p.has_folded, PokerError::PlayerFolded);
+        Self::ensure(&env, p.chips > 0, PokerError::NoChips);
+
+        Self::place_bet(env.clone(), game_id, player.clone(), p.chips);
+        env.events().publish((symbol_short!("all_in"), player), p.chips);
+    }
+
+    /// Fold hand
+    pub fn fold(
+        env: Env,
+        game_id: BytesN<32>,
+        player: Address,
+    ) {
+        Self::require_not_paused(&env);
+        // No require_auth — trusted deployer signs on behalf of players
+        let mut state = Self::load_game(&env, &game_id);
+        Self::ensure(&env, state.is_active, PokerError::NotActive);
         
-        // Move to next player
-        state.current_player = (state.current_player + 1) % 2;
-        
-        env.storage().instance().set(&GAME_STATE, &state);
+        let i = Self::seat_of(&state, &player);
+        let mut p = state.players.get(i).unwrap();
+        p.has_folded = true;
+        p.last_action_ledger = env.ledger().sequence();
+        state.players.set(i, p);
+        // Someone must still be in the hand, or the pot has no owner
+        Self::ensure(&env, !Self::active_seats(&env, &state).is_empty(), PokerError::NoActivePlayers);
+
+        // Folding in turn passes the action on, as after a bet
+        if state.current_player == i {
+            state.current_player = Self::next_active_player(&state, i);
+        }
+
+        Self::save_game(&env, &state);
+        env.events().publish((symbol_short!("fold"), player), game_id);
+    }
+
+    /// Fold a player who has not acted within the configured timeout.
+    ///
+    /// Anyone may call this. `player` must be the player whose turn it is, and
+    /// more than `set_action_timeout` ledgers must have passed since the last
+    /// action at the table. If only one player is left in the hand, they are
+    /// awarded the pot and the hand is settled as after a showdown: bets and
+    /// commitments are cleared and the result is added to the hand history.
+    pub fn force_timeout_fold(env: Env, game_id: BytesN<32>, player: Address) {
+        Self::require_not_paused(&env);
+        let timeout: u32 = env.storage().instance()
+            .get(&symbol_short!("TIMEOUT"))
+            .unwrap_or_else(|| panic_with_error!(&env, PokerError::TimeoutNotConfigured));
+        let mut state = Self::load_game(&env, &game_id);
+        Self::ensure(&env, state.is_active, PokerError::NotActive);
+
+        let idx = state.current_player;
+        let mut p = state.players.get(idx).unwrap();
+        Self::ensure(&env, p.address == player, PokerError::NotPlayersTurn);
+        Self::ensure(&env, !p.has_folded, PokerError::PlayerFolded);
+
+        // The turn started with the most recent action by anyone at the table
+        let turn_started = state.players.iter()
+            .map(|q| q.last_action_ledger)
+            .max()
+            .unwrap_or(0);
+        Self::ensure(&env, env.ledger().sequence() > turn_started.saturating_add(timeout), PokerError::TimeoutNotReached);
+
+        p.has_folded = true;
+        state.players.set(idx, p);
+        state.current_player = Self::next_active_player(&state, idx);
+
+        // Last player standing collects the pot
+        let remaining: Vec<u32> = Self::active_seats(&env, &state);
+        if remaining.len() == 1 {
+            let seat = remaining.get(0).unwrap();
+            let mut payouts: Vec<i128> = Vec::new(&env);
+            for i in 0..state.players.len() {
+                payouts.push_back(if i == seat { state.pot } else { 0 });
+            }
+            Self::record_stats(&env, &state, &payouts, &remaining);
+            let mut w = state.players.get(seat).unwrap();
+            let pot = state.pot;
+            w.chips = Self::chip_add(&env, w.chips, pot);
+            state.players.set(seat, w.clone());
+            // No proofs were shown for an uncontested pot
+            let zero = BytesN::from_array(&env, &ZERO_COMMITMENT);
+            state.player1_proof_hash = zero.clone();
+            state.player2_proof_hash = zero;
+            Self::settle_hand(&env, &mut state, &w.address, pot, pot, 0, 0);
+            Self::ensure(&env, Self::chips_balanced(&state), PokerError::ChipConservation);
+        }
+
+        Self::save_game(&env, &state);
+        env.events().publish((symbol_short!("timeout"), player), idx);
+    }
+    
+    /// Close a hand nobody has acted on for the abandon window, without a
+    /// showdown. Anyone may call it.
+    ///
+    /// Each player gets their bets back; antes left in the pot are split
+    /// evenly as in a tie. Stacks stay on the table, so token-backed chips
+    /// can then be taken out with `withdraw`. Emits `("refunded", player)`
+    /// with each player's stack after the refund.
+    pub fn reclaim(env: Env, game_id: BytesN<32>) {
+        Self::require_not_paused(&env);
+        let window: u32 = env.storage().instance()
+            .get(&symbol_short!("ABANDON"))
+            .unwrap_or_else(|| panic_with_error!(&env, PokerError::TimeoutNotConfigured));
+        let mut state = Self::load_game(&env, &game_id);
+        Self::ensure(&env, state.is_active, PokerError::NotActive);
+
+        let last_action = state.players.iter().map(|p| p.last_action_ledger).max().unwrap_or(0);
+        Self::ensure(&env, env.ledger().sequence() > last_action.saturating_add(window), PokerError::TimeoutNotReached);
+
+        let mut seats = Vec::new(&env);
+        let mut bets = 0;
+        for i in 0..state.players.len() {
+            seats.push_back(i);
+            bets = Self::chip_add(&env, bets, state.players.get(i).unwrap().current_bet);
+        }
+        let antes = Self::split_pot(&env, Self::chip_sub(&env, state.pot, bets), &seats, state.dealer_button, state.players.len());
+
+        for i in 0..state.players.len() {
+            let mut p = state.players.get(i).unwrap();
+            p.chips = Self::chip_add(&env, p.chips, Self::chip_add(&env, p.current_bet, antes.get(i).unwrap()));
+            p.current_bet = 0;
+            p.all_in = false;
+            env.events().publish((symbol_short!("refunded"), p.address.clone()), p.chips);
+            state.players.set(i, p);
+        }
+        state.pot = 0;
+        state.is_active = false;
+        Self::ensure(&env, Self::chips_balanced(&state), PokerError::ChipConservation);
+        Self::save_game(&env, &state);
+    }
+
+    /// Set the Stellar token (SAC) contract that backs chips, one chip per
+    /// `10^chip_decimals` token units.
+    pub fn set_token(env: Env, admin: Address, token: Address) {
+        Self::require_admin(&env, &admin);
+        env.storage().instance().set(&symbol_short!("TOKEN"), &token);
+    }
+
+    /// Set how many token decimal places one chip covers: a chip is worth
+    /// `10^decimals` token units (0 = 1:1, the default; at most 18).
+    /// Set it before any deposit — existing stacks are not rescaled.
+    pub fn set_chip_decimals(env: Env, admin: Address, decimals: u32) {
+        Self::require_admin(&env, &admin);
+        Self::ensure(&env, decimals <= MAX_CHIP_DECIMALS, PokerError::InvalidAmount);
+        env.storage().instance().set(&symbol_short!("CHIP_DEC"), &decimals);
+    }
+
+    /// Token decimal places covered by one chip.
+    pub fn get_chip_decimals(env: Env) -> u32 {
+        env.storage().instance().get(&symbol_short!("CHIP_DEC")).unwrap_or(0)
+    }
+
+    /// Token units backing `chips` chips.
+    pub fn chips_to_token(env: Env, chips: i128) -> i128 {
+        chips.checked_mul(Self::chip_scale(&env))
+            .unwrap_or_else(|| panic_with_error!(&env, TableError::ArithmeticOverflow))
+    }
+
+    /// Chips worth `amount` token units. Rejects amounts that are not a
+    /// whole number of chips.
+    pub fn token_to_chips(env: Env, amount: i128) -> i128 {
+        let scale = Self::chip_scale(&env);
+        Self::ensure(&env, amount % scale == 0, PokerError::InvalidAmount);
+        amount / scale
+    }
+
+    /// Transfer `amount` tokens from `player` into the contract and credit
+    /// the matching number of chips (see `token_to_chips`) to their seat.
+    pub fn deposit(env: Env, game_id: BytesN<32>, player: Address, amount: i128) {
+        Self::require_not_paused(&env);
+        player.require_auth();
+        Self::ensure(&env, amount > 0, PokerError::InvalidAmount);
+        let chips = Self::token_to_chips(env.clone(), amount);
+
+        let mut state = Self::load_game(&env, &game_id);
+        let i = Self::seat_of(&state, &player);
+        let mut p = state.players.get(i).unwrap();
+
+        Self::token_client(&env).transfer(&player, &env.current_contract_address(), &amount);
+
+        p.chips = Self::chip_add(&env, p.chips, chips);
+        state.players.set(i, p);
+        state.chip_supply = Self::chip_add(&env, state.chip_supply, chips);
+        Self::save_game(&env, &state);
+        Self::credit_backed(&env, &game_id, chips);
+        env.events().publish((symbol_short!("deposit"), player), amount);
+    }
+
+    /// Set the stack size below which a player may `rebuy`. Busted players
+    /// (0 chips) may always rebuy.
+    pub fn set_rebuy_threshold(env: Env, admin: Address, threshold: i128) {
+        Self::require_admin(&env, &admin);
+        Self::ensure(&env, threshold >= 0, PokerError::InvalidAmount);
+        env.storage().instance().set(&symbol_short!("REBUY_TH"), &threshold);
+    }
+
+    /// Credit `amount` chips to a busted or short-stacked player between
+    /// hands. When a chip token is configured the same number of tokens is
+    /// transferred in from the player, as with `deposit`.
+    pub fn rebuy(env: Env, game_id: BytesN<32>, player: Address, amount: i128) {
+        Self::require_not_paused(&env);
+        player.require_auth();
+        Self::ensure(&env, amount > 0, PokerError::InvalidAmount);
+
+        let mut state = Self::load_game(&env, &game_id);
+        Self::ensure(&env, !state.is_active, PokerError::HandInProgress);
+        let i = Self::seat_of(&state, &player);
+        let mut p = state.players.get(i).unwrap();
+        let threshold: i128 = env.storage().instance().get(&symbol_short!("REBUY_TH")).unwrap_or(0);
+        Self::ensure(&env, p.chips == 0 || p.chips < threshold, PokerError::RebuyNotAllowed);
+
+        if env.storage().instance().has(&symbol_short!("TOKEN")) {
+            let tokens = Self::chips_to_token(env.clone(), amount);
+            Self::token_client(&env).transfer(&player, &env.current_contract_address(), &tokens);
+            Self::credit_backed(&env, &game_id, amount);
+        }
+
+        p.chips = Self::chip_add(&env, p.chips, amount);
+        p.all_in = false;
+        state.players.set(i, p);
+        state.chip_supply = Self::chip_add(&env, state.chip_supply, amount);
+        Self::save_game(&env, &state);
+        env.events().publish((symbol_short!("rebuy"), player), amount);
+    }
+
+    /// Transfer `amount` tokens out of the contract to `player` and debit
+    /// the matching number of chips (see `token_to_chips`) from their seat.
+    /// Only between hands, like `rebuy`.
+    ///
+    /// Chips from `init_game` are not backed by tokens: a table pays out at
+    /// most what was paid in through `deposit` and `rebuy` (`UnbackedChips`).
+    pub fn withdraw(env: Env, game_id: BytesN<32>, player: Address, amount: i128) {
+        Self::require_not_paused(&env);
+        player.require_auth();
+        Self::ensure(&env, amount > 0, PokerError::InvalidAmount);
+
+        let chips = Self::token_to_chips(env.clone(), amount);
+
+        let mut state = Self::load_game(&env, &game_id);
+        // A stack emptied mid-hand would look all-in to settle_contributions
+        Self::ensure(&env, !state.is_active, PokerError::HandInProgress);
+        let i = Self::seat_of(&state, &player);
+        let mut p = state.players.get(i).unwrap();
+        Self::ensure(&env, p.chips >= chips, PokerError::InsufficientChips);
+        let backed = Self::get_backed_chips(env.clone(), game_id.clone());
+        Self::ensure(&env, chips <= backed, TableError::UnbackedChips);
+
+        p.chips = Self::chip_sub(&env, p.chips, chips);
+        state.players.set(i, p);
+        state.chip_supply = Self::chip_sub(&env, state.chip_supply, chips);
+        Self::save_game(&env, &state);
+        env.storage().persistent().set(&Self::backed_key(&game_id), &Self::chip_sub(&env, backed, chips));
+
+        Self::token_client(&env).transfer(&env.current_contract_address(), &player, &amount);
+        env.events().publish((symbol_short!("withdraw"), player), amount);
+    }
+
+    /// Chips at `game_id` paid for in tokens and not yet withdrawn.
+    pub fn get_backed_chips(env: Env, game_id: BytesN<32>) -> i128 {
+        env.storage().persistent().get(&Self::backed_key(&game_id)).unwrap_or(0)
+    }
+
+    /// Deal the flop: append three cards to an empty board (Preflop → Flop).
+    /// Cards are checked against the deck seed when `reveal_deck` is called.
+    pub fn reveal_flop(env: Env, game_id: BytesN<32>, cards: (u32, u32, u32)) {
+        let mut state = Self::load_street(&env, &game_id, BettingRound::Preflop);
+        Self::ensure(&env, state.community_cards.is_empty(), PokerError::WrongRound);
+        for card in [cards.0, cards.1, cards.2] {
+            Self::push_board_card(&env, &mut state, card);
+        }
+        Self::enter_round(&env, &mut state, BettingRound::Flop);
+        Self::save_game(&env, &state);
+    }
+
+    /// Deal the turn: append the fourth board card (Flop → Turn).
+    pub fn reveal_turn(env: Env, game_id: BytesN<32>, card: u32) {
+        let mut state = Self::load_street(&env, &game_id, BettingRound::Flop);
+        Self::push_board_card(&env, &mut state, card);
+        Self::enter_round(&env, &mut state, BettingRound::Turn);
+        Self::save_game(&env, &state);
+    }
+
+    /// Deal the river: append the fifth and last board card (Turn → River).
+    pub fn reveal_river(env: Env, game_id: BytesN<32>, card: u32) {
+        let mut state = Self::load_street(&env, &game_id, BettingRound::Turn);
+        Self::push_board_card(&env, &mut state, card);
+        Self::enter_round(&env, &mut state, BettingRound::River);
+        Self::save_game(&env, &state);
+    }
+
+    /// Helper: start betting round `round`: seat the first actor and clear
+    /// the raise increment carried over from the previous round.
+    fn enter_round(env: &Env, state: &mut GameState, round: BettingRound) {
+        state.current_round = round;
+        state.current_player = Self::first_to_act(state);
+        env.storage().persistent().remove(&Self::raise_key(&state.game_id));
+    }
+
+    /// Helper: load an active game that must currently be in `round`.
+    fn load_street(env: &Env, game_id: &BytesN<32>, round: BettingRound) -> GameState {
+        Self::require_not_paused(env);
+        let state = Self::load_game(env, game_id);
+        Self::ensure(env, state.is_active, PokerError::NotActive);
+        Self::ensure(env, state.current_round == round, PokerError::WrongRound);
+        state
+    }
+
+    /// Helper: append one valid, not yet dealt card to the board.
+    fn push_board_card(env: &Env, state: &mut GameState, card: u32) {
+        Self::ensure(env, card < DECK_SIZE, PokerError::InvalidCard);
+        Self::ensure(env, !state.community_cards.contains(card), PokerError::InvalidCard);
+        Self::ensure(env, state.community_cards.len() < MAX_BOARD_CARDS, PokerError::TooManyCommunityCards);
+        state.community_cards.push_back(card);
     }
-    
-    /// Fold hand
-    pub fn fold(
-        env: Env,
-        player: Address,
-    ) {
-        // No require_auth — trusted deployer signs on behalf of players
-        let mut state: GameState = env.storage().instance().get(&GAME_STATE).unwrap();
-        
-        for i in 0..state.players.len() {
-            let mut p = state.players.get(i).unwrap();
-            if p.address == player {
-                p.has_folded = true;
-                state.players.set(i, p);
-                break;
-            }
+
+    /// Helper: the five board cards dealt by `seed` — the first five cards of
+    /// a Fisher-Yates shuffle whose i-th swap is drawn from SHA-256(seed || i).
+    fn board_from_seed(env: &Env, seed: &BytesN<32>) -> Vec<u32> {
+        let mut deck = [0u32; DECK_SIZE as usize];
+        for (i, card) in deck.iter_mut().enumerate() {
+            *card = i as u32;
         }
-        
-        env.storage().instance().set(&GAME_STATE, &state);
+
+        let mut board = Vec::new(env);
+        for i in 0..MAX_BOARD_CARDS {
+            let mut input = Bytes::from_array(env, &seed.to_array());
+            input.push_back(i as u8);
+            let digest = env.crypto().sha256(&input).to_array();
+            let draw = u32::from_be_bytes([digest[0], digest[1], digest[2], digest[3]]);
+
+            let j = i + draw % (DECK_SIZE - i);
+            deck.swap(i as usize, j as usize);
+            board.push_back(deck[i as usize]);
+        }
+        board
     }
-    
-    /// Reveal community cards
+
+    /// Reveal the next street given the whole board so far: 3 cards deal
+    /// the flop, 4 the turn, 5 the river.
+    ///
+    /// Same rules as `reveal_flop` / `reveal_turn` / `reveal_river`: only the
+    /// next street may be dealt, cards already on the board must be repeated
+    /// unchanged, and new cards go through the same validity checks (and are
+    /// checked against the deck seed by `reveal_deck`).
     pub fn reveal_community_cards(
         env: Env,
+        game_id: BytesN<32>,
         cards: Vec<u32>,
     ) {
-        let mut state: GameState = env.storage().instance().get(&GAME_STATE).unwrap();
-        state.community_cards = cards;
-        env.storage().instance().set(&GAME_STATE, &state);
+        let (from, round) = match cards.len() {
+            3 => (BettingRound::Preflop, BettingRound::Flop),
+            4 => (BettingRound::Flop, BettingRound::Turn),
+            5 => (BettingRound::Turn, BettingRound::River),
+            n if n > MAX_BOARD_CARDS => panic_with_error!(&env, PokerError::TooManyCommunityCards),
+            _ => panic_with_error!(&env, PokerError::WrongRound),
+        };
+        let mut state = Self::load_street(&env, &game_id, from);
+        let dealt = state.community_cards.len();
+        let street = if round == BettingRound::Flop { 3 } else { 1 };
+        Self::ensure(&env, dealt + street == cards.len(), PokerError::WrongRound);
+        for i in 0..dealt {
+            Self::ensure(&env, cards.get(i) == state.community_cards.get(i), PokerError::WrongRound);
+        }
+        for i in dealt..cards.len() {
+            Self::push_board_card(&env, &mut state, cards.get(i).unwrap());
+        }
+        Self::enter_round(&env, &mut state, round);
+        Self::save_game(&env, &state);
     }
     
+    /// Resolve several tables' showdowns in one transaction, e.g. a
+    /// tournament round, returning each table's winner in input order.
+    ///
+    /// Each entry goes through `resolve_showdown` unchanged; if any fails
+    /// the whole batch reverts. At most MAX_SHOWDOWN_BATCH (4) entries are
+    /// accepted (`BatchTooLarge` otherwise).
+    pub fn resolve_showdowns_batch(env: Env, results: Vec<ShowdownInput>) -> Vec<Address> {
+        Self::ensure(&env, results.len() <= MAX_SHOWDOWN_BATCH, TableError::BatchTooLarge);
+        let mut winners = Vec::new(&env);
+        for r in results.iter() {
+            winners.push_back(Self::resolve_showdown(
+                env.clone(),
+                r.game_id,
+                r.player1_proof, r.player1_rank, r.player1_cards, r.player1_salt,
+                r.player2_proof, r.player2_rank, r.player2_cards, r.player2_salt,
+            ));
+        }
+        winners
+    }
+
+    /// Predict the outcome `resolve_showdown` would reach for these ranks,
+    /// without verifying proofs, moving chips or writing state.
+    ///
+    /// Applies the same rank checks, folded-player handling and
+    /// `HandOrdering` as the real showdown. Kickers need the hole cards, so
+    /// a rank tie is reported as `Tie` even where the showdown might settle
+    /// it on kickers.
+    pub fn simulate_showdown(env: Env, game_id: BytesN<32>, player1_rank: u32, player2_rank: u32) -> Winner {
+        let state = Self::load_game(&env, &game_id);
+        Self::ensure(&env, state.is_active, PokerError::NotActive);
+        let max_rank = Self::get_max_claimable_rank(env.clone());
+        Self::ensure(&env, player1_rank <= 9 && player1_rank <= max_rank, PokerError::InvalidRank);
+        Self::ensure(&env, player2_rank <= 9 && player2_rank <= max_rank, PokerError::InvalidRank);
+
+        let p1_active = !state.players.get(0).unwrap().has_folded;
+        let p2_active = !state.players.get(1).unwrap().has_folded;
+        Self::ensure(&env, p1_active || p2_active, PokerError::NoActivePlayers);
+        let order = Self::rank_order(&state, player1_rank, player2_rank);
+        let seats = Self::winning_seats(&env, p1_active, p2_active, order);
+        match (seats.len(), seats.get(0)) {
+            (1, Some(0)) => Winner::Player1,
+            (1, _) => Winner::Player2,
+            _ => Winner::Tie,
+        }
+    }
+
     /// End game and declare winner
     pub fn end_game(
         env: Env,
+        game_id: BytesN<32>,
         winner: Address,
     ) -> Address {
+        Self::require_not_paused(&env);
         // No require_auth — deployer calls this after determining winner
-        let mut state: GameState = env.storage().instance().get(&GAME_STATE).unwrap();
+        let mut state = Self::load_game(&env, &game_id);
         
         // Transfer pot to winner
-        for i in 0..state.players.len() {
-            let mut p = state.players.get(i).unwrap();
-            if p.address == winner {
-                p.chips += state.pot;
-                state.players.set(i, p);
-                break;
-            }
-        }
+        let i = Self::seat_of(&state, &winner);
+        let mut p = state.players.get(i).unwrap();
+        p.chips = Self::chip_add(&env, p.chips, state.pot);
+        state.players.set(i, p);
+        Self::set_last_result(&env, &winner, state.pot);
+        state.pot = 0;
         
         state.is_active = false;
-        env.storage().instance().set(&GAME_STATE, &state);
+        Self::save_game(&env, &state);
         
         winner
     }
@@ -219,8 +1570,11 @@ impl PokerGameContract {
     /// Resolve showdown with ZK proofs — SHA-256 commit-reveal + on-chain verify.
     ///
     /// Security flow:
-    ///   1. Game must be active.
-    ///   2. Both players must have submitted a non-zero commitment.
+    ///   1. Game must be active and heads-up (multi-way showdown is not
+    ///      supported yet).
+    ///   2. Both players must have submitted a non-zero commitment. If one of
+    ///      them folded, the pot is uncontested: steps 2-6 are skipped and
+    ///      the remaining player wins without a proof.
     ///   3. **ON-CHAIN SHA-256 VERIFICATION:** Recompute SHA-256(hole_cards || salt)
     ///      for each player and assert it matches the stored commitment.
     ///      This cryptographically proves the revealed cards are the same
@@ -231,151 +1585,249 @@ impl PokerGameContract {
     ///   7. Determine winner by comparing verified ranks.
     pub fn resolve_showdown(
         env: Env,
+        game_id: BytesN<32>,
         player1_proof: BytesN<128>,
         player1_rank: u32,
-        player1_cards: BytesN<2>,
+        player1_cards: Bytes,
         player1_salt: BytesN<32>,
         player2_proof: BytesN<128>,
         player2_rank: u32,
-        player2_cards: BytesN<2>,
+        player2_cards: Bytes,
         player2_salt: BytesN<32>,
     ) -> Address {
-        let mut state: GameState = env.storage().instance().get(&GAME_STATE).unwrap();
+        Self::require_not_paused(&env);
+
+        // ── 0. Reentrancy guard around the cross-contract verifier calls ─
+        //  Cleared before returning; a panic reverts the whole tx (and the lock).
+        let lock_key = symbol_short!("LOCK");
+        let locked: bool = env.storage().instance().get(&lock_key).unwrap_or(false);
+        Self::ensure(&env, !locked, PokerError::Reentrant);
+        env.storage().instance().set(&lock_key, &true);
+
+        let mut state = Self::load_game(&env, &game_id);
 
         // ── 1. Game must be active ────────────────────────────────────────
-        log!(&env, "🔍 [1/6] is_active={}", state.is_active);
-        assert!(state.is_active, "Game is not active");
+        log_at!(&env, LOG_DEBUG, "🔍 [1/6] is_active={}", state.is_active);
+        Self::ensure(&env, state.is_active, PokerError::NotActive);
+        // Showdown settles seats 0 and 1 only; on a multi-way table the other
+        // seats' bets would be split between them, so it is rejected.
+        Self::ensure(&env, state.players.len() == 2, PokerError::InvalidPlayerCount);
+        let verifier_addr = state.verifier_contract.clone()
+            .unwrap_or_else(|| panic_with_error!(&env, PokerError::VerifierNotConfigured));
 
-        // ── 2. Both players MUST have committed cards (hard assert) ──────
+        // ── 2. Players still in the hand MUST have committed cards ───────
+        //  A folded player has no hand to prove, so their commitment, proof
+        //  and verifier call are all skipped. With only one player left the
+        //  pot is uncontested and nobody has to prove anything.
         let zero = BytesN::from_array(&env, &ZERO_COMMITMENT);
         let p1 = state.players.get(0).unwrap();
         let p2 = state.players.get(1).unwrap();
+        let p1_active = !p1.has_folded;
+        let p2_active = !p2.has_folded;
+        Self::ensure(&env, p1_active || p2_active, PokerError::NoActivePlayers);
+        let contested = p1_active && p2_active;
         let p1_has_commit = p1.commitment != zero;
         let p2_has_commit = p2.commitment != zero;
-        log!(&env, "🔍 [2/6] p1_commit={} p2_commit={}", p1_has_commit, p2_has_commit);
-        assert!(p1_has_commit, "Player 1 has not submitted a card commitment");
-        assert!(p2_has_commit, "Player 2 has not submitted a card commitment");
+        log_at!(&env, LOG_DEBUG, "🔍 [2/6] p1_commit={} p2_commit={}", p1_has_commit, p2_has_commit);
+        Self::ensure(&env, !contested || p1_has_commit, PokerError::MissingCommitment);
+        Self::ensure(&env, !contested || p2_has_commit, PokerError::MissingCommitment);
+
+        // ── 2b. Optional: contesting players revealed via verify_reveal ──
+        let require_reveal: bool = env.storage().instance()
+            .get(&symbol_short!("REQ_REVL"))
+            .unwrap_or(false);
+        if require_reveal {
+            Self::ensure(&env, !contested || p1.revealed, PokerError::NotRevealed);
+            Self::ensure(&env, !contested || p2.revealed, PokerError::NotRevealed);
+        }
 
         // ── 3. SHA-256 re-check removed ────────────────────────────────────────
         //  noir_verifier already verifies Poseidon2(cards, salt) == commitment,
         //  card range [0-51], no duplicates, rank validity, and proof existence.
         //  Re-running SHA-256 here caused mismatches when the salt encoding path
         //  diverged between commit and reveal — removed to keep the flow clean.
-        log!(&env, "\u{2705} [3/6] commitment presence verified \u{2014} noir_verifier will check integrity");
+        //  Tables wanting the check on-chain use verify_reveal (enforced by
+        //  set_require_reveal) or verify_commitment_onchain, which share the
+        //  one canonical preimage documented on sha256_commitment.
+        log_at!(&env, LOG_DEBUG, "\u{2705} [3/6] commitment presence verified \u{2014} noir_verifier will check integrity");
+
+        // ── 3b. Salts may not be reused across hands at this table ───────
+        if contested {
+            Self::claim_salt(&env, &game_id, &p1.address, &player1_salt, &p1.commitment);
+            Self::claim_salt(&env, &game_id, &p2.address, &player2_salt, &p2.commitment);
+        }
 
         // ── 4. Proof blobs must be non-trivial ───────────────────────────
         let p1_arr = player1_proof.to_array();
         let p2_arr = player2_proof.to_array();
         let p1_nonzero = p1_arr.iter().any(|b| *b != 0);
         let p2_nonzero = p2_arr.iter().any(|b| *b != 0);
-        log!(&env, "🔍 [4/6] proof_nonzero p1={} p2={}", p1_nonzero, p2_nonzero);
-        assert!(p1_nonzero, "Player 1 proof is empty (all zero)");
-        assert!(p2_nonzero, "Player 2 proof is empty (all zero)");
+        log_at!(&env, LOG_DEBUG, "🔍 [4/6] proof_nonzero p1={} p2={}", p1_nonzero, p2_nonzero);
+        Self::ensure(&env, !contested || p1_nonzero, PokerError::EmptyProof);
+        Self::ensure(&env, !contested || p2_nonzero, PokerError::EmptyProof);
 
         // ── 5. Validate rank range (0-9) ─────────────────────────────────
-        log!(&env, "🔍 [5/6] rank p1={} p2={}", player1_rank, player2_rank);
-        assert!(player1_rank <= 9, "Invalid player 1 rank");
-        assert!(player2_rank <= 9, "Invalid player 2 rank");
+        log_at!(&env, LOG_DEBUG, "🔍 [5/6] rank p1={} p2={}", player1_rank, player2_rank);
+        Self::ensure(&env, player1_rank <= 9, PokerError::InvalidRank);
+        Self::ensure(&env, player2_rank <= 9, PokerError::InvalidRank);
+
+        // ── 5b. Table cap on claims, and flags for suspicious ones ───────
+        let max_rank = Self::get_max_claimable_rank(env.clone());
+        Self::ensure(&env, player1_rank <= max_rank, PokerError::InvalidRank);
+        Self::ensure(&env, player2_rank <= max_rank, PokerError::InvalidRank);
+        if let (true, Some(flag_rank)) = (contested, Self::get_flag_rank(env.clone())) {
+            for (player, rank) in [(&p1, player1_rank), (&p2, player2_rank)] {
+                if rank > flag_rank {
+                    env.events().publish(
+                        (symbol_short!("flagged"), player.address.clone()),
+                        (game_id.clone(), rank),
+                    );
+                }
+            }
+        }
 
         // ── 6-7. Cross-contract call to noir_verifier ──────────────────
-        //  Both SHA-256 commitments are verified above (hard assert).
-        //  Pass the stored commitment directly — no recomputed fallback.
-        log!(&env, "🔍 [6/6] calling noir_verifier cross-contract");
-        let verifier_addr = state.verifier_contract.clone();
-        let fn_name = Symbol::new(&env, "verify_proof");
+        //  Only for players still in the hand. If everyone else folded the
+        //  sole remaining player takes the pot without a proof.
+        log_at!(&env, LOG_DEBUG, "🔍 [6/6] calling noir_verifier cross-contract");
+        //  Hold'em uses verify_and_get_rank and takes the rank the verifier
+        //  returns; other variants go through verify_proof_variant with the
+        //  game's hole-card count and keep the claimed rank. Both get the
+        //  game_id, so an attestation recorded for another game is rejected,
+        //  and whether this table requires a zkVerify attestation.
+        //  A failed proof normally reverts the showdown; with
+        //  set_disqualify_on_fail it disqualifies that player instead.
+        //  Verifier errors unrelated to the proof always revert.
+        let holdem = state.hole_cards == HOLDEM_HOLE_CARDS;
+        let fn_name = if holdem {
+            Symbol::new(&env, "verify_and_get_rank")
+        } else {
+            Symbol::new(&env, "verify_proof_variant")
+        };
+        let lenient = Self::is_disqualify_on_fail(env.clone());
+        let mut disqualified = [false; 2];
 
-        // Player 1 → noir_verifier.verify_proof
-        {
-            let p1_id = Self::player_id_bytes32(&env, 0);
+        // Player 1 → noir_verifier
+        let player1_rank = if contested {
+            let p1_id = Self::player_id_bytes32(&env, &p1.address);
             let mut args1: Vec<soroban_sdk::Val> = Vec::new(&env);
-            args1.push_back(player1_cards.clone().into_val(&env));
+            Self::push_hole_cards(&env, &mut args1, &player1_cards, state.hole_cards);
             args1.push_back(player1_salt.clone().into_val(&env));
             args1.push_back(p1.commitment.clone().into_val(&env));
             args1.push_back(player1_rank.into_val(&env));
             args1.push_back(player1_proof.clone().into_val(&env));
             args1.push_back(p1_id.into_val(&env));
+            args1.push_back(game_id.clone().into_val(&env));
+            args1.push_back(state.require_attestation.into_val(&env));
 
-            let result_p1: bool = env.invoke_contract(&verifier_addr, &fn_name, args1);
-            assert!(result_p1, "Player 1 noir_verifier returned false");
-            log!(&env, "✅ Player 1 noir_verifier verify_proof → true");
-        }
+            let verified = Self::verify_hand(&env, &verifier_addr, &fn_name, holdem, args1, player1_rank, lenient);
+            disqualified[0] = verified.is_none();
+            log_at!(&env, LOG_DEBUG, "✅ Player 1 noir_verifier verified rank={:?}", verified);
+            verified.unwrap_or(player1_rank)
+        } else {
+            player1_rank
+        };
 
-        // Player 2 → noir_verifier.verify_proof
-        {
-            let p2_id = Self::player_id_bytes32(&env, 1);
+        // Player 2 → noir_verifier
+        let player2_rank = if contested {
+            let p2_id = Self::player_id_bytes32(&env, &p2.address);
             let mut args2: Vec<soroban_sdk::Val> = Vec::new(&env);
-            args2.push_back(player2_cards.clone().into_val(&env));
+            Self::push_hole_cards(&env, &mut args2, &player2_cards, state.hole_cards);
             args2.push_back(player2_salt.clone().into_val(&env));
             args2.push_back(p2.commitment.clone().into_val(&env));
             args2.push_back(player2_rank.into_val(&env));
             args2.push_back(player2_proof.clone().into_val(&env));
             args2.push_back(p2_id.into_val(&env));
+            args2.push_back(game_id.clone().into_val(&env));
+            args2.push_back(state.require_attestation.into_val(&env));
 
-            let result_p2: bool = env.invoke_contract(&verifier_addr, &fn_name, args2);
-            assert!(result_p2, "Player 2 noir_verifier returned false");
-            log!(&env, "✅ Player 2 noir_verifier verify_proof → true");
-        }
+            let verified = Self::verify_hand(&env, &verifier_addr, &fn_name, holdem, args2, player2_rank, lenient);
+            disqualified[1] = verified.is_none();
+            log_at!(&env, LOG_DEBUG, "✅ Player 2 noir_verifier verified rank={:?}", verified);
+            verified.unwrap_or(player2_rank)
+        } else {
+            player2_rank
+        };
+        Self::ensure(&env, player1_rank <= 9 && player2_rank <= 9, PokerError::InvalidRank);
 
-        log!(&env, "✅ Both noir_verifier proofs verified via cross-contract call");
+        log_at!(&env, LOG_DEBUG, "✅ noir_verifier proofs verified for contested showdown={}", contested);
+
+        // ── 7b. A disqualified player is treated as folded ───────────────
+        //  If both proofs failed there is no honest player to award.
+        Self::ensure(&env, !(disqualified[0] && disqualified[1]), PokerError::VerifierFalse);
+        for seat in 0..2u32 {
+            if disqualified[seat as usize] {
+                let mut p = state.players.get(seat).unwrap();
+                p.has_folded = true;
+                env.events().publish((symbol_short!("dq"), p.address.clone()), game_id.clone());
+                state.players.set(seat, p);
+            }
+        }
+        let p1_active = p1_active && !disqualified[0];
+        let p2_active = p2_active && !disqualified[1];
+        let contested = p1_active && p2_active;
 
         // Store proof hashes for auditability
-        let p1_arr = player1_proof.to_array();
-        let p2_arr = player2_proof.to_array();
+        state.player1_proof_hash = Self::proof_hash(&env, &player1_proof);
+        state.player2_proof_hash = Self::proof_hash(&env, &player2_proof);
 
-        let mut p1_hash = [0u8; 32];
-        let mut p2_hash = [0u8; 32];
-        p1_hash.copy_from_slice(&p1_arr[..32]);
-        p2_hash.copy_from_slice(&p2_arr[..32]);
-        state.player1_proof_hash = BytesN::from_array(&env, &p1_hash);
-        state.player2_proof_hash = BytesN::from_array(&env, &p2_hash);
+        // ── 8. Settle contributions, take rake, then determine winner ────
+        //  A folded player's bets are dead money, so only a contested pot
+        //  needs matched contributions.
+        if contested {
+            Self::settle_contributions(&mut state);
+        }
+        let pot = state.pot;
+        let rake = Self::take_rake(&env, state.pot);
+        let payout = Self::chip_sub(&env, state.pot, rake);
+        state.chip_supply = Self::chip_sub(&env, state.chip_supply, rake);
 
-        // ── 8. Determine winner ──────────────────────────────────────────
-        let winner_address: Address;
-        
-        if player1_rank > player2_rank {
-            winner_address = state.players.get(0).unwrap().address.clone();
-            let mut p1 = state.players.get(0).unwrap();
-            p1.chips += state.pot;
-            state.players.set(0, p1);
-        } else if player2_rank > player1_rank {
-            winner_address = state.players.get(1).unwrap().address.clone();
-            let mut p2 = state.players.get(1).unwrap();
-            p2.chips += state.pot;
-            state.players.set(1, p2);
+        // Seats holding the best rank share the pot (a tie chops it);
+        // an uncontested pot goes to the player who did not fold.
+        // A Hold'em rank tie on a full board is first settled on kickers,
+        // so only identical best hands (e.g. both play the board) chop.
+        let kickers = if contested
+            && holdem
+            && state.hand_ordering == HandOrdering::HighWins
+            && player1_rank == player2_rank
+            && state.community_cards.len() == MAX_BOARD_CARDS
+        {
+            Self::compare_kickers(&env, &verifier_addr, &player1_cards, &player2_cards, &state.community_cards)
         } else {
-            // Tie - split pot
-            winner_address = state.players.get(0).unwrap().address.clone();
-            let half_pot = state.pot / 2;
-            
-            let mut p1 = state.players.get(0).unwrap();
-            p1.chips += half_pot;
-            state.players.set(0, p1);
-            
-            let mut p2 = state.players.get(1).unwrap();
-            p2.chips += state.pot - half_pot;
-            state.players.set(1, p2);
+            0
+        };
+        let order = Self::rank_order(&state, player1_rank, player2_rank).then(kickers.cmp(&0));
+        let tied = Self::winning_seats(&env, p1_active, p2_active, order);
+        let payouts = Self::split_pot(&env, payout, &tied, state.dealer_button, state.players.len());
+        for seat in 0..state.players.len() {
+            let mut p = state.players.get(seat).unwrap();
+            p.chips = Self::chip_add(&env, p.chips, payouts.get(seat).unwrap());
+            state.players.set(seat, p);
         }
+        let winner_address = state.players.get(tied.get(0).unwrap()).unwrap().address.clone();
+        Self::record_stats(&env, &state, &payouts, &tied);
         
         // Snapshot commitments before reset (for event emission)
         let p1_commit_snapshot = state.players.get(0).unwrap().commitment.clone();
         let p2_commit_snapshot = state.players.get(1).unwrap().commitment.clone();
 
-        // Reset commitments and current_bet for next hand
-        let zero_c = BytesN::from_array(&env, &ZERO_COMMITMENT);
-        let mut cp1 = state.players.get(0).unwrap();
-        cp1.commitment = zero_c.clone();
-        cp1.current_bet = 0;
-        state.players.set(0, cp1);
-        let mut cp2 = state.players.get(1).unwrap();
-        cp2.commitment = zero_c;
-        cp2.current_bet = 0;
-        state.players.set(1, cp2);
-
-        state.pot = 0;
-        state.is_active = false;
-        env.storage().instance().set(&GAME_STATE, &state);
+        // ── 9. Reset the table for the next hand, append to hand history ──
+        Self::settle_hand(&env, &mut state, &winner_address, pot, payout, player1_rank, player2_rank);
+        Self::ensure(&env, Self::chips_balanced(&state), PokerError::ChipConservation);
+        Self::save_game(&env, &state);
 
-        // ── 9. Emit on-chain event for auditability ──────────────────────
+        // ── 10. Emit on-chain events for auditability ────────────────────
+        // One participation event per seat so loser and winner can each
+        // filter on their own address; the showdown event is emitted last.
+        for seat in 0..2u32 {
+            env.events().publish(
+                (symbol_short!("played"), state.players.get(seat).unwrap().address),
+                (state.game_id.clone(), payouts.get(seat).unwrap()),
+            );
+        }
+        // Contract-wide showdown counter, so indexers can spot gaps or reordering
+        let showdown_seq: u64 = env.storage().instance().get(&symbol_short!("SD_SEQ")).unwrap_or(0);
+        env.storage().instance().set(&symbol_short!("SD_SEQ"), &(showdown_seq + 1));
         env.events().publish(
             (symbol_short!("showdown"), winner_address.clone()),
             (
@@ -385,27 +1837,685 @@ impl PokerGameContract {
                 state.player2_proof_hash.clone(),
                 p1_commit_snapshot.clone(),
                 p2_commit_snapshot.clone(),
+                rake,
+                payouts,            // chips awarded per seat, odd chip included
+                tied.len() > 1,     // was_tie
+                showdown_seq,       // 0 for the contract's first showdown, +1 per showdown after
             ),
         );
 
-        log!(&env, "resolve_showdown: winner={:?} p1_rank={} p2_rank={}", winner_address, player1_rank, player2_rank);
-        
+        log_at!(&env, LOG_INFO, "resolve_showdown: winner={:?} p1_rank={} p2_rank={}", winner_address, player1_rank, player2_rank);
+
+        env.storage().instance().remove(&lock_key);
         winner_address
     }
 
-    /// Helper: derive a deterministic BytesN<32> identifier for a player.
-    /// Soroban guest has no API to serialize Address to raw bytes, so we
-    /// SHA-256 a tagged player index to produce a unique, non-zero ID.
-    fn player_id_bytes32(env: &Env, player_index: u32) -> BytesN<32> {
-        let mut preimage = Bytes::new(env);
-        preimage.extend_from_array(b"zkpoker_player_");
-        preimage.extend_from_array(&player_index.to_be_bytes());
+    /// Helper: seat that opens the betting in the current round.
+    ///
+    /// Heads-up the button posts the small blind, so it acts first preflop
+    /// and last after the flop. With more seats the player left of the big
+    /// blind opens preflop and the player left of the button opens later
+    /// rounds. Seats that cannot act are skipped.
+    fn first_to_act(state: &GameState) -> u32 {
+        let n = state.players.len();
+        let button = state.dealer_button;
+        let preflop = state.current_round == BettingRound::Preflop;
+        let seat = match (n, preflop) {
+            (2, true) => button,
+            (2, false) => (button + 1) % 2,
+            (_, true) => (button + 3) % n,
+            (_, false) => (button + 1) % n,
+        };
+
+        let p = state.players.get(seat).unwrap();
+        if p.has_folded || p.all_in {
+            Self::next_active_player(state, seat)
+        } else {
+            seat
+        }
+    }
+
+    /// Helper: seat index of the next player after `from` who can still act
+    /// (not folded, not all-in), wrapping around the table. Returns `from` if
+    /// nobody else can act.
+    fn next_active_player(state: &GameState, from: u32) -> u32 {
+        let n = state.players.len();
+        for step in 1..n {
+            let i = (from + step) % n;
+            let p = state.players.get(i).unwrap();
+            if !p.has_folded && !p.all_in {
+                return i;
+            }
+        }
+        from
+    }
+
+    /// Helper: seat index of `player`, panicking if they are not at the table.
+    fn seat_of(state: &GameState, player: &Address) -> u32 {
+        state.players.iter()
+            .position(|p| p.address == *player)
+            .unwrap_or_else(|| panic_with_error!(state.players.env(), PokerError::PlayerNotFound)) as u32
+    }
+
+    /// Helper: client for the configured chip token.
+    fn token_client(env: &Env) -> token::Client<'_> {
+        let token: Address = env.storage().instance()
+            .get(&symbol_short!("TOKEN"))
+            .unwrap_or_else(|| panic_with_error!(env, PokerError::TokenNotConfigured));
+        token::Client::new(env, &token)
+    }
+
+    /// Helper: token units per chip, `10^chip_decimals`.
+    fn chip_scale(env: &Env) -> i128 {
+        10i128.pow(Self::get_chip_decimals(env.clone()))
+    }
+
+    /// Helper: seat indices of players who have not folded.
+    fn active_seats(env: &Env, state: &GameState) -> Vec<u32> {
+        let mut seats = Vec::new(env);
+        for i in 0..state.players.len() {
+            if !state.players.get(i).unwrap().has_folded {
+                seats.push_back(i);
+            }
+        }
+        seats
+    }
+
+    /// Helper: split `pot` evenly between the `tied` seats. Leftover chips
+    /// (`pot % n`) go one each to tied seats clockwise from the dealer button,
+    /// starting with the seat immediately to its left.
+    /// Returns the amount awarded to every seat (0 for seats not in `tied`).
+    fn split_pot(env: &Env, pot: i128, tied: &Vec<u32>, dealer_button: u32, num_seats: u32) -> Vec<i128> {
+        let share = pot / tied.len() as i128;
+        let mut remainder = pot % tied.len() as i128;
+
+        let mut payouts = Vec::new(env);
+        for _ in 0..num_seats {
+            payouts.push_back(0i128);
+        }
+        for step in 1..=num_seats {
+            let seat = (dealer_button + step) % num_seats;
+            if tied.contains(seat) {
+                let extra = if remainder > 0 { 1 } else { 0 };
+                remainder -= extra;
+                payouts.set(seat, share + extra);
+            }
+        }
+        payouts
+    }
+
+    /// Helper: make sure both showdown seats put in the same amount.
+    /// If the shorter contribution came from an all-in player, the uncalled
+    /// excess is refunded to the other player; otherwise the betting round
+    /// is not settled and the showdown is rejected.
+    fn settle_contributions(state: &mut GameState) {
+        Self::ensure(state.players.env(), state.players.len() == 2, PokerError::InvalidPlayerCount);
+        let bet0 = state.players.get(0).unwrap().current_bet;
+        let bet1 = state.players.get(1).unwrap().current_bet;
+        if bet0 == bet1 {
+            return;
+        }
+
+        let (over, short) = if bet0 > bet1 { (0, 1) } else { (1, 0) };
+        Self::ensure(state.players.env(), state.players.get(short).unwrap().chips == 0, PokerError::BetsNotSettled);
+
+        let env = state.players.env().clone();
+        let excess = Self::chip_sub(&env, bet0.max(bet1), bet0.min(bet1));
+        let mut p = state.players.get(over).unwrap();
+        p.chips = Self::chip_add(&env, p.chips, excess);
+        p.current_bet = Self::chip_sub(&env, p.current_bet, excess);
+        state.players.set(over, p);
+        state.pot = Self::chip_sub(&env, state.pot, excess);
+    }
+
+    /// Helper: append the hole-card arguments for the verifier call —
+    /// `BytesN<2>` for Hold'em, `(Bytes, count)` for other variants.
+    fn push_hole_cards(env: &Env, args: &mut Vec<soroban_sdk::Val>, cards: &Bytes, count: u32) {
+        Self::ensure(env, cards.len() == count, PokerError::WrongHoleCardCount);
+        if count == HOLDEM_HOLE_CARDS {
+            let pair: BytesN<2> = cards.clone().try_into().unwrap();
+            args.push_back(pair.into_val(env));
+        } else {
+            args.push_back(cards.clone().into_val(env));
+            args.push_back(count.into_val(env));
+        }
+    }
+
+    /// Helper: run one player's showdown verification and return the rank
+    /// to use. When `lenient`, a failing proof returns `None` instead of
+    /// reverting the showdown; any other verifier error (a consumed proof,
+    /// configuration, an unreachable verifier) still reverts it with
+    /// `VerifierFault`.
+    fn verify_hand(
+        env: &Env,
+        verifier: &Address,
+        fn_name: &Symbol,
+        holdem: bool,
+        args: Vec<soroban_sdk::Val>,
+        claimed_rank: u32,
+        lenient: bool,
+    ) -> Option<u32> {
+        if !lenient {
+            if holdem {
+                return Some(env.invoke_contract(verifier, fn_name, args));
+            }
+            let verified: bool = env.invoke_contract(verifier, fn_name, args);
+            Self::ensure(env, verified, PokerError::VerifierFalse);
+            return Some(claimed_rank);
+        }
+        let failure = if holdem {
+            match env.try_invoke_contract::<u32, soroban_sdk::Error>(verifier, fn_name, args) {
+                Ok(Ok(rank)) => return Some(rank),
+                Err(Ok(err)) => Some(err),
+                _ => None,
+            }
+        } else {
+            match env.try_invoke_contract::<bool, soroban_sdk::Error>(verifier, fn_name, args) {
+                Ok(Ok(true)) => return Some(claimed_rank),
+                Ok(Ok(false)) => return None,
+                Err(Ok(err)) => Some(err),
+                _ => None,
+            }
+        };
+        let proof_failed = failure.is_some_and(|err| {
+            err.is_type(soroban_sdk::xdr::ScErrorType::Contract) && PROOF_FAILURE_CODES.contains(&err.get_code())
+        });
+        Self::ensure(env, proof_failed, TableError::VerifierFault);
+        None
+    }
+
+    /// Helper: compare two ranks under the game's `HandOrdering`; `Greater`
+    /// means player 1 holds the better hand. Under LowWins the scale is
+    /// flipped so "higher" still means better.
+    fn rank_order(state: &GameState, player1_rank: u32, player2_rank: u32) -> core::cmp::Ordering {
+        match state.hand_ordering {
+            HandOrdering::HighWins => player1_rank.cmp(&player2_rank),
+            HandOrdering::LowWins => player2_rank.cmp(&player1_rank),
+        }
+    }
+
+    /// Helper: seats taking the pot given who is still in the hand and how
+    /// their hands compare. Both seats are returned on a tie.
+    fn winning_seats(env: &Env, p1_active: bool, p2_active: bool, order: core::cmp::Ordering) -> Vec<u32> {
+        let mut seats: Vec<u32> = Vec::new(env);
+        if p1_active && (!p2_active || order.is_ge()) {
+            seats.push_back(0);
+        }
+        if p2_active && (!p1_active || order.is_le()) {
+            seats.push_back(1);
+        }
+        seats
+    }
+
+    /// Helper: order two Hold'em hands of equal rank on kickers via the
+    /// verifier's `compare_hands` (1, -1, or 0 for identical best hands).
+    /// Verifiers without it report 0, leaving the tie to be chopped.
+    fn compare_kickers(env: &Env, verifier: &Address, cards1: &Bytes, cards2: &Bytes, board: &Vec<u32>) -> i32 {
+        let hole1: BytesN<2> = cards1.clone().try_into().unwrap();
+        let hole2: BytesN<2> = cards2.clone().try_into().unwrap();
+        let mut args: Vec<soroban_sdk::Val> = Vec::new(env);
+        args.push_back(hole1.into_val(env));
+        args.push_back(hole2.into_val(env));
+        args.push_back(board.into_val(env));
+        match env.try_invoke_contract::<i32, soroban_sdk::Error>(verifier, &Symbol::new(env, "compare_hands"), args) {
+            Ok(Ok(order)) => order,
+            _ => 0,
+        }
+    }
+
+    /// Helper: bind `salt` to the commitment it was revealed for. A player
+    /// may reveal the same salt again for the same commitment (e.g. at
+    /// verify_reveal and then at showdown), but reusing it for a different
+    /// commitment in this game panics — reused salts weaken hiding.
+    fn claim_salt(env: &Env, game_id: &BytesN<32>, player: &Address, salt: &BytesN<32>, commitment: &BytesN<32>) {
+        let key = (symbol_short!("SALT"), game_id.clone(), player.clone(), salt.clone());
+        if let Some(used_for) = env.storage().persistent().get::<_, BytesN<32>>(&key) {
+            Self::ensure(env, used_for == *commitment, PokerError::SaltReused);
+        } else {
+            env.storage().persistent().set(&key, commitment);
+        }
+    }
+
+    /// Helper: storage key for a table's token-backed chip total.
+    fn backed_key(game_id: &BytesN<32>) -> (Symbol, BytesN<32>) {
+        (symbol_short!("BACKED"), game_id.clone())
+    }
+
+    /// Helper: add `chips` bought with tokens to the table's backed total.
+    fn credit_backed(env: &Env, game_id: &BytesN<32>, chips: i128) {
+        let backed = Self::get_backed_chips(env.clone(), game_id.clone());
+        env.storage().persistent().set(&Self::backed_key(game_id), &Self::chip_add(env, backed, chips));
+    }
+
+    /// Helper: storage key for a hand's `EncryptedCards`, by player.
+    fn cipher_key(game_id: &BytesN<32>) -> (Symbol, BytesN<32>) {
+        (symbol_short!("CIPHER"), game_id.clone())
+    }
+
+    /// Helper: XOR `data` with the keystream SHA-256(key || block_be_u32),
+    /// 32 bytes per block. Encrypts and decrypts alike.
+    fn keystream_xor(env: &Env, key: &BytesN<32>, data: &Bytes) -> Bytes {
+        let mut out = Bytes::new(env);
+        let mut block = 0u32;
+        while out.len() < data.len() {
+            let mut seed = Bytes::from_array(env, &key.to_array());
+            seed.extend_from_array(&block.to_be_bytes());
+            let pad: BytesN<32> = env.crypto().sha256(&seed).into();
+            for byte in pad.to_array() {
+                if out.len() == data.len() {
+                    break;
+                }
+                out.push_back(data.get(out.len()).unwrap() ^ byte);
+            }
+            block += 1;
+        }
+        out
+    }
+
+    /// Helper: storage key for a hand's per-seat `(seed_commitment, seed)`
+    /// pairs; zero means not yet committed / revealed.
+    fn seeds_key(game_id: &BytesN<32>) -> (Symbol, BytesN<32>) {
+        (symbol_short!("SEEDS"), game_id.clone())
+    }
+
+    /// Helper: the hand's seed pairs, one per seat.
+    fn load_seeds(env: &Env, state: &GameState) -> Vec<(BytesN<32>, BytesN<32>)> {
+        env.storage().persistent().get(&Self::seeds_key(&state.game_id)).unwrap_or_else(|| {
+            let zero = BytesN::from_array(env, &ZERO_COMMITMENT);
+            let mut seeds = Vec::new(env);
+            for _ in 0..state.players.len() {
+                seeds.push_back((zero.clone(), zero.clone()));
+            }
+            seeds
+        })
+    }
+
+    /// Helper: largest `current_bet` at the table.
+    fn highest_bet(state: &GameState) -> i128 {
+        state.players.iter().map(|p| p.current_bet).max().unwrap_or(0)
+    }
+
+    /// Helper: storage key for the size of the last full raise in a game's
+    /// current betting round (kept outside `GameState`).
+    fn raise_key(game_id: &BytesN<32>) -> (Symbol, BytesN<32>) {
+        (symbol_short!("RAISE"), game_id.clone())
+    }
+
+    /// Helper: storage key for a game's fixed-limit bet sizes, copied from
+    /// `set_limit_structure` at init (kept outside `GameState`).
+    fn limit_key(game_id: &BytesN<32>) -> (Symbol, BytesN<32>) {
+        (symbol_short!("LIMIT"), game_id.clone())
+    }
+
+    /// Helper: storage key for a table's state. Each game_id is an
+    /// independent table, so one deployment can host many concurrent games.
+    fn game_key(game_id: &BytesN<32>) -> (Symbol, BytesN<32>) {
+        (symbol_short!("GAME"), game_id.clone())
+    }
+
+    /// Helper: a schema-1 table in the current layout. Fields the old layout
+    /// lacked take the current table rules, as a new game would.
+    fn upgrade_legacy_game(env: &Env, legacy: LegacyGameState) -> GameState {
+        let rules = Self::get_table_rules(env.clone());
+        let mut players = Vec::new(env);
+        let mut chip_supply = legacy.pot;
+        for p in legacy.players.iter() {
+            chip_supply = Self::chip_add(env, chip_supply, p.chips);
+            players.push_back(Player {
+                address: p.address,
+                chips: p.chips,
+                commitment: p.commitment,
+                current_bet: p.current_bet,
+                has_folded: p.has_folded,
+                last_action_ledger: env.ledger().sequence(),
+                revealed: false,
+                all_in: false,
+            });
+        }
+        GameState {
+            game_id: legacy.game_id,
+            players,
+            pot: legacy.pot,
+            community_cards: legacy.community_cards,
+            current_round: legacy.current_round,
+            dealer_button: legacy.dealer_button,
+            current_player: legacy.current_player,
+            is_active: legacy.is_active,
+            player1_proof_hash: legacy.player1_proof_hash,
+            player2_proof_hash: legacy.player2_proof_hash,
+            verifier_contract: Some(legacy.verifier_contract),
+            min_bet: rules.min_bet,
+            max_bet: rules.max_bet,
+            deck_commitment: BytesN::from_array(env, &ZERO_COMMITMENT),
+            deck_seed: BytesN::from_array(env, &ZERO_COMMITMENT),
+            chip_supply,
+            hole_cards: HOLDEM_HOLE_CARDS, // schema 1 only dealt Hold'em
+            hand_ordering: HandOrdering::HighWins,
+            require_attestation: false,
+            ante: 0,
+        }
+    }
+
+    /// Helper: load a table's state.
+    fn load_game(env: &Env, game_id: &BytesN<32>) -> GameState {
+        env.storage().persistent()
+            .get(&Self::game_key(game_id))
+            .unwrap_or_else(|| panic_with_error!(env, PokerError::GameNotFound))
+    }
+
+    /// Helper: persist a table's state under its game_id.
+    fn save_game(env: &Env, state: &GameState) {
+        env.storage().persistent().set(&Self::game_key(&state.game_id), state);
+    }
+
+    /// Helper: stacks plus pot equal the tracked chip supply.
+    fn chips_balanced(state: &GameState) -> bool {
+        let total = state.players.iter().try_fold(state.pot, |sum, p| sum.checked_add(p.chips));
+        total == Some(state.chip_supply)
+    }
+
+    /// Helper: `a + b` for chip and pot amounts, panicking with
+    /// `ArithmeticOverflow` rather than wrapping.
+    fn chip_add(env: &Env, a: i128, b: i128) -> i128 {
+        a.checked_add(b).unwrap_or_else(|| panic_with_error!(env, TableError::ArithmeticOverflow))
+    }
+
+    /// Helper: `a - b` for chip and pot amounts, panicking with
+    /// `ArithmeticOverflow` rather than wrapping.
+    fn chip_sub(env: &Env, a: i128, b: i128) -> i128 {
+        a.checked_sub(b).unwrap_or_else(|| panic_with_error!(env, TableError::ArithmeticOverflow))
+    }
+
+    /// Helper: close a hand whose pot has been paid out. Clears every seat's
+    /// bet, commitment and reveal, ends the hand, and records the result in
+    /// the hand history and as the last winner. `pot` is before rake,
+    /// `payout` what the winners were paid.
+    fn settle_hand(
+        env: &Env,
+        state: &mut GameState,
+        winner: &Address,
+        pot: i128,
+        payout: i128,
+        player1_rank: u32,
+        player2_rank: u32,
+    ) {
+        let zero = BytesN::from_array(env, &ZERO_COMMITMENT);
+        for seat in 0..state.players.len() {
+            let mut p = state.players.get(seat).unwrap();
+            p.commitment = zero.clone();
+            p.current_bet = 0;
+            p.revealed = false;
+            p.all_in = false;
+            state.players.set(seat, p);
+        }
+        state.pot = 0;
+        state.is_active = false;
+
+        Self::record_hand(env, HandResult {
+            game_id: state.game_id.clone(),
+            winner: winner.clone(),
+            pot,
+            player1_rank,
+            player2_rank,
+            player1_proof_hash: state.player1_proof_hash.clone(),
+            player2_proof_hash: state.player2_proof_hash.clone(),
+            timestamp: env.ledger().timestamp(),
+        });
+        Self::set_last_result(env, winner, payout);
+    }
+
+    /// Helper: append a resolved hand to the persistent history log.
+    fn record_hand(env: &Env, hand: HandResult) {
+        let index: u32 = env.storage().persistent().get(&symbol_short!("HIST_CNT")).unwrap_or(0);
+        env.storage().persistent().set(&(symbol_short!("HIST"), index), &hand);
+        env.storage().persistent().set(&symbol_short!("HIST_CNT"), &(index + 1));
+    }
+
+    /// Helper: remember the latest winner and award for `get_last_winner` /
+    /// `get_last_pot`.
+    fn set_last_result(env: &Env, winner: &Address, pot: i128) {
+        env.storage().instance().set(&symbol_short!("LAST_WIN"), winner);
+        env.storage().instance().set(&symbol_short!("LAST_POT"), &pot);
+    }
+
+    /// Helper: the attestation lookup hash at the front of a proof.
+    fn proof_hash(env: &Env, proof: &BytesN<128>) -> BytesN<32> {
+        let bytes: [u8; PROOF_LEN] = proof.to_array();
+        let mut hash = [0u8; PROOF_HASH_LEN];
+        hash.copy_from_slice(&bytes[..PROOF_HASH_LEN]);
+        BytesN::from_array(env, &hash)
+    }
+
+    /// Helper: configured log level, `LOG_DEBUG` unless set.
+    fn log_level(env: &Env) -> u32 {
+        env.storage().instance().get(&symbol_short!("LOG_LVL")).unwrap_or(LOG_DEBUG)
+    }
+
+    /// Helper: panic with `error` unless `condition` holds.
+    fn ensure(env: &Env, condition: bool, error: impl Into<soroban_sdk::Error>) {
+        if !condition {
+            panic_with_error!(env, error);
+        }
+    }
+
+    /// Helper: add one finished hand to every seat's `PlayerStats`. A seat's
+    /// result is what it was paid minus what it bet this hand; seats in
+    /// `winners` count as winning the hand.
+    fn record_stats(env: &Env, state: &GameState, payouts: &Vec<i128>, winners: &Vec<u32>) {
+        for seat in 0..state.players.len() {
+            let p = state.players.get(seat).unwrap();
+            let key = (symbol_short!("STAT"), p.address.clone());
+            let mut stats: PlayerStats = env.storage().persistent().get(&key).unwrap_or_default();
+            let net = Self::chip_sub(env, payouts.get(seat).unwrap(), p.current_bet);
+
+            stats.hands_played += 1;
+            if winners.contains(seat) {
+                stats.hands_won += 1;
+            }
+            if net > 0 {
+                stats.chips_won = Self::chip_add(env, stats.chips_won, net);
+            } else {
+                stats.chips_lost = Self::chip_sub(env, stats.chips_lost, net);
+            }
+            env.storage().persistent().set(&key, &stats);
+        }
+    }
+
+    /// Helper: panic if the operator has paused the table.
+    fn require_not_paused(env: &Env) {
+        let paused: bool = env.storage().instance().get(&symbol_short!("PAUSED")).unwrap_or(false);
+        Self::ensure(env, !paused, PokerError::Paused);
+    }
+
+    /// Helper: panic unless `admin` is the configured admin and has authorized the call.
+    fn require_admin(env: &Env, admin: &Address) {
+        let stored: Address = env.storage().instance()
+            .get(&symbol_short!("ADMIN"))
+            .unwrap_or_else(|| panic_with_error!(env, PokerError::AdminNotConfigured));
+        Self::ensure(env, stored == *admin, PokerError::NotAdmin);
+        admin.require_auth();
+    }
+
+    /// Helper: compute the rake for `pot` (pot * bps / 10000) and credit it
+    /// to the fee collector's running total. Returns 0 when no rake is set.
+    fn take_rake(env: &Env, pot: i128) -> i128 {
+        let bps: u32 = env.storage().instance().get(&symbol_short!("RAKE_BPS")).unwrap_or(0);
+        let rake = pot.checked_mul(bps as i128)
+            .unwrap_or_else(|| panic_with_error!(env, TableError::ArithmeticOverflow)) / 10_000;
+        if rake > 0 {
+            let collected: i128 = env.storage().instance().get(&symbol_short!("RAKE_ACC")).unwrap_or(0);
+            env.storage().instance().set(&symbol_short!("RAKE_ACC"), &Self::chip_add(env, collected, rake));
+
+            let collector: Address = env.storage().instance().get(&symbol_short!("RAKE_TO")).unwrap();
+            env.events().publish((symbol_short!("rake"), collector), rake);
+        }
+        rake
+    }
+
+    /// Helper: canonical card commitment, SHA-256 over a `hole_cards` + 32
+    /// byte preimage:
+    ///
+    /// ```text
+    /// [card0: 1 byte]..[cardN-1: 1 byte][salt: 32 bytes]
+    /// ```
+    ///
+    /// Cards are raw deck indices (0-51) and the salt is the raw 32 bytes —
+    /// no hex/string encoding and no length prefix. Clients must hash exactly
+    /// these bytes when calling `submit_commitment`.
+    fn sha256_commitment(env: &Env, cards: &Bytes, salt: &BytesN<32>) -> BytesN<32> {
+        let mut preimage = cards.clone();
+        preimage.extend_from_array(&salt.to_array());
         env.crypto().sha256(&preimage).into()
     }
+
+    /// Helper: derive a deterministic BytesN<32> identifier for a player.
+    /// Same scheme as `noir_verifier::player_id`: the trailing 32 bytes of
+    /// the address XDR (an account's ed25519 key or a contract's hash), so
+    /// attestations a player records themselves match at showdown.
+    fn player_id_bytes32(env: &Env, player: &Address) -> BytesN<32> {
+        let xdr = player.to_xdr(env);
+        xdr.slice(xdr.len() - 32..).try_into().unwrap()
+    }
     
     /// Get current game state
-    pub fn get_game_state(env: Env) -> GameState {
-        env.storage().instance().get(&GAME_STATE).unwrap()
+    pub fn get_game_state(env: Env, game_id: BytesN<32>) -> GameState {
+        Self::load_game(&env, &game_id)
+    }
+
+    /// States of several tables at once, in the order of `game_ids`.
+    ///
+    /// At most MAX_GAME_BATCH (20) ids are accepted (`BatchTooLarge`
+    /// otherwise). Unknown ids are dropped when `skip_unknown` is set,
+    /// otherwise they panic with `GameNotFound`.
+    pub fn get_game_states(env: Env, game_ids: Vec<BytesN<32>>, skip_unknown: bool) -> Vec<GameState> {
+        Self::ensure(&env, game_ids.len() <= MAX_GAME_BATCH, TableError::BatchTooLarge);
+        let mut states = Vec::new(&env);
+        for game_id in game_ids.iter() {
+            let stored: Option<GameState> = env.storage().persistent().get(&Self::game_key(&game_id));
+            match stored {
+                Some(state) => states.push_back(state),
+                None if skip_unknown => {}
+                None => panic_with_error!(&env, PokerError::GameNotFound),
+            }
+        }
+        states
+    }
+
+    /// Number of showdowns recorded in the hand history
+    pub fn get_hand_count(env: Env) -> u32 {
+        env.storage().persistent().get(&symbol_short!("HIST_CNT")).unwrap_or(0)
+    }
+
+    /// Get a resolved hand from the history by index
+    pub fn get_hand(env: Env, index: u32) -> HandResult {
+        env.storage().persistent()
+            .get(&(symbol_short!("HIST"), index))
+            .unwrap_or_else(|| panic_with_error!(&env, PokerError::HandNotFound))
+    }
+
+    /// The most recent `n` resolved hands, newest first.
+    ///
+    /// `n` is capped at MAX_RECENT_HANDS (50); fewer hands are returned when
+    /// the history is shorter.
+    pub fn get_recent_hands(env: Env, n: u32) -> Vec<HandResult> {
+        let count = Self::get_hand_count(env.clone());
+        let take = n.min(MAX_RECENT_HANDS).min(count);
+
+        let mut hands = Vec::new(&env);
+        for index in (count - take..count).rev() {
+            hands.push_back(Self::get_hand(env.clone(), index));
+        }
+        hands
+    }
+
+    /// Cumulative stats for `player`; all zero if they never finished a hand.
+    pub fn get_player_stats(env: Env, player: Address) -> PlayerStats {
+        env.storage().persistent()
+            .get(&(symbol_short!("STAT"), player))
+            .unwrap_or_default()
+    }
+
+    /// Winner of the most recently resolved hand, across all games.
+    pub fn get_last_winner(env: Env) -> Address {
+        env.storage().instance()
+            .get(&symbol_short!("LAST_WIN"))
+            .unwrap_or_else(|| panic_with_error!(&env, PokerError::NoHandResolved))
+    }
+
+    /// Chips awarded in the most recently resolved hand (after rake).
+    pub fn get_last_pot(env: Env) -> i128 {
+        env.storage().instance()
+            .get(&symbol_short!("LAST_POT"))
+            .unwrap_or_else(|| panic_with_error!(&env, PokerError::NoHandResolved))
+    }
+
+    /// Check that every chip at the table is accounted for:
+    /// sum(player chips) + pot == starting stacks + deposits - withdrawals - rake.
+    pub fn check_chip_invariant(env: Env, game_id: BytesN<32>) -> bool {
+        Self::chips_balanced(&Self::get_game_state(env, game_id))
+    }
+
+    /// Get the current pot
+    pub fn get_pot(env: Env, game_id: BytesN<32>) -> i128 {
+        Self::get_game_state(env, game_id).pot
+    }
+
+    /// Proof hashes `(player1, player2)` from the game's last showdown;
+    /// both are zero until a showdown has been resolved.
+    pub fn get_proof_hashes(env: Env, game_id: BytesN<32>) -> (BytesN<32>, BytesN<32>) {
+        let state = Self::get_game_state(env, game_id);
+        (state.player1_proof_hash, state.player2_proof_hash)
+    }
+
+    /// Get the current betting round
+    pub fn get_current_round(env: Env, game_id: BytesN<32>) -> BettingRound {
+        Self::get_game_state(env, game_id).current_round
+    }
+
+    /// Get the address of the player whose turn it is
+    pub fn get_current_player(env: Env, game_id: BytesN<32>) -> Address {
+        let state = Self::get_game_state(env, game_id);
+        state.players.get(state.current_player).unwrap().address
+    }
+
+    /// Seat index of `player` in a game; panics with `PlayerNotFound` if
+    /// they are not seated there.
+    pub fn player_index(env: Env, game_id: BytesN<32>, player: Address) -> u32 {
+        let state = Self::load_game(&env, &game_id);
+        Self::seat_of(&state, &player)
+    }
+
+    /// Address seated at `index` in a game; panics with `PlayerNotFound`
+    /// past the last seat.
+    pub fn player_address(env: Env, game_id: BytesN<32>, index: u32) -> Address {
+        let state = Self::load_game(&env, &game_id);
+        state.players.get(index)
+            .unwrap_or_else(|| panic_with_error!(&env, PokerError::PlayerNotFound))
+            .address
+    }
+
+    /// Address of the player who should act next. If the stored seat has
+    /// since folded, the turn passes to the next player still in the hand.
+    pub fn whose_turn(env: Env, game_id: BytesN<32>) -> Address {
+        let state = Self::get_game_state(env, game_id);
+        let mut seat = state.current_player;
+        if state.players.get(seat).unwrap().has_folded {
+            seat = Self::next_active_player(&state, seat);
+        }
+        state.players.get(seat).unwrap().address
+    }
+
+    /// Whether `player` is the one `whose_turn` reports.
+    pub fn is_player_turn(env: Env, game_id: BytesN<32>, player: Address) -> bool {
+        Self::whose_turn(env, game_id) == player
+    }
+
+    /// Chips `player` must add to match the highest bet this round;
+    /// 0 once they are matched.
+    pub fn amount_to_call(env: Env, game_id: BytesN<32>, player: Address) -> i128 {
+        let state = Self::load_game(&env, &game_id);
+        let seat = Self::seat_of(&state, &player);
+        Self::chip_sub(&env, Self::highest_bet(&state), state.players.get(seat).unwrap().current_bet)
     }
 }
 
@@ -1,7 +1,7 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, Address, Env, Bytes, BytesN};
+use soroban_sdk::{testutils::{Address as _, Events, Ledger, Logs}, xdr::{ScErrorCode, ScErrorType}, Address, Env, Bytes, BytesN, Error, TryFromVal, Vec};
 
 /// Helper: compute SHA-256(hole_cards || salt) matching the contract's verify_commitment
 fn make_sha256_commitment(env: &Env, cards: [u8; 2], salt: [u8; 32]) -> BytesN<32> {
@@ -11,6 +11,11 @@ fn make_sha256_commitment(env: &Env, cards: [u8; 2], salt: [u8; 32]) -> BytesN<3
     env.crypto().sha256(&preimage).into()
 }
 
+/// Helper: game_id of the table used by most tests
+fn table(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[1u8; 32])
+}
+
 #[test]
 fn test_init_game() {
     let env = Env::default();
@@ -43,9 +48,9 @@ fn test_place_bet() {
     env.mock_all_auths();
     
     client.init_game(&game_id, &player1, &player2, &1000);
-    client.place_bet(&player1, &100);
+    client.place_bet(&table(&env), &player1, &100);
     
-    let state = client.get_game_state();
+    let state = client.get_game_state(&table(&env));
     assert_eq!(state.pot, 100);
 }
 
@@ -63,15 +68,15 @@ fn test_submit_commitment() {
     client.init_game(&game_id, &player1, &player2, &1000);
 
     let commitment = BytesN::from_array(&env, &[0xABu8; 32]);
-    client.submit_commitment(&player1, &commitment);
+    client.submit_commitment(&table(&env), &player1, &commitment);
 
-    let state = client.get_game_state();
+    let state = client.get_game_state(&table(&env));
     let p1 = state.players.get(0).unwrap();
     assert_eq!(p1.commitment, commitment);
 }
 
 #[test]
-#[should_panic(expected = "Cannot submit zero commitment")]
+#[should_panic(expected = "Error(Contract, #23)")] // PokerError::ZeroCommitment
 fn test_reject_zero_commitment() {
     let env = Env::default();
     let contract_id = env.register_contract(None, PokerGameContract);
@@ -83,7 +88,7 @@ fn test_reject_zero_commitment() {
     env.mock_all_auths();
 
     client.init_game(&game_id, &player1, &player2, &1000);
-    client.submit_commitment(&player1, &BytesN::from_array(&env, &[0u8; 32]));
+    client.submit_commitment(&table(&env), &player1, &BytesN::from_array(&env, &[0u8; 32]));
 }
 
 #[test]
@@ -104,8 +109,8 @@ fn test_resolve_showdown_with_sha256_verify() {
     client.set_verifier(&verifier_id);
 
     client.init_game(&game_id, &player1, &player2, &1000);
-    client.place_bet(&player1, &100);
-    client.place_bet(&player2, &100);
+    client.place_bet(&table(&env), &player1, &100);
+    client.place_bet(&table(&env), &player2, &100);
 
     // Cards and salts
     let p1_cards: [u8; 2] = [14, 13]; // Ace, King
@@ -116,19 +121,20 @@ fn test_resolve_showdown_with_sha256_verify() {
     // Submit SHA-256 commitments
     let p1_commit = make_sha256_commitment(&env, p1_cards, p1_salt);
     let p2_commit = make_sha256_commitment(&env, p2_cards, p2_salt);
-    client.submit_commitment(&player1, &p1_commit);
-    client.submit_commitment(&player2, &p2_commit);
+    client.submit_commitment(&table(&env), &player1, &p1_commit);
+    client.submit_commitment(&table(&env), &player2, &p2_commit);
 
     // Non-zero proof blobs
     let proof = BytesN::from_array(&env, &[1u8; 128]);
 
     // resolve_showdown with card reveal + salt
     let winner = client.resolve_showdown(
+        &table(&env),
         &proof, &5,
-        &BytesN::from_array(&env, &p1_cards),
+        &Bytes::from_array(&env, &p1_cards),
         &BytesN::from_array(&env, &p1_salt),
         &proof, &3,
-        &BytesN::from_array(&env, &p2_cards),
+        &Bytes::from_array(&env, &p2_cards),
         &BytesN::from_array(&env, &p2_salt),
     );
     assert_eq!(winner, player1); // rank 5 > 3
@@ -158,19 +164,20 @@ fn test_resolve_showdown_wrong_cards_caught_by_noir_verifier() {
     let real_salt = [42u8; 32];
     let p1_commit = make_sha256_commitment(&env, [14, 13], real_salt);
     let p2_commit = make_sha256_commitment(&env, [7, 8], [99u8; 32]);
-    client.submit_commitment(&player1, &p1_commit);
-    client.submit_commitment(&player2, &p2_commit);
+    client.submit_commitment(&table(&env), &player1, &p1_commit);
+    client.submit_commitment(&table(&env), &player2, &p2_commit);
 
     let proof = BytesN::from_array(&env, &[1u8; 128]);
 
     // Claim DIFFERENT cards [14, 12] — poker_game no longer re-checks SHA-256;
     // the real noir_verifier would reject this at Poseidon2 commitment level.
     let winner = client.resolve_showdown(
+        &table(&env),
         &proof, &9,
-        &BytesN::from_array(&env, &[14u8, 12u8]),  // wrong cards (mock verifier accepts)
+        &Bytes::from_array(&env, &[14u8, 12u8]),  // wrong cards (mock verifier accepts)
         &BytesN::from_array(&env, &real_salt),
         &proof, &3,
-        &BytesN::from_array(&env, &[7u8, 8u8]),
+        &Bytes::from_array(&env, &[7u8, 8u8]),
         &BytesN::from_array(&env, &[99u8; 32]),
     );
     // Mock verifier always returns true → resolves by rank
@@ -178,7 +185,7 @@ fn test_resolve_showdown_wrong_cards_caught_by_noir_verifier() {
 }
 
 #[test]
-#[should_panic(expected = "Player 2 has not submitted a card commitment")]
+#[should_panic(expected = "Error(Contract, #2)")] // PokerError::MissingCommitment
 fn test_resolve_showdown_rejects_missing_commitment() {
     let env = Env::default();
     let verifier_id = env.register_contract(None, test_helpers::MockNoirVerifier);
@@ -192,26 +199,27 @@ fn test_resolve_showdown_rejects_missing_commitment() {
 
     client.set_verifier(&verifier_id);
     client.init_game(&game_id, &player1, &player2, &1000);
-    client.place_bet(&player1, &100);
+    client.place_bet(&table(&env), &player1, &100);
 
     // Only P1 submits commitment; P2 is zero → hard assert panics
     let p1_cards: [u8; 2] = [14, 13];
     let p1_salt = [42u8; 32];
-    client.submit_commitment(&player1, &make_sha256_commitment(&env, p1_cards, p1_salt));
+    client.submit_commitment(&table(&env), &player1, &make_sha256_commitment(&env, p1_cards, p1_salt));
 
     let proof = BytesN::from_array(&env, &[1u8; 128]);
     client.resolve_showdown(
+        &table(&env),
         &proof, &5,
-        &BytesN::from_array(&env, &p1_cards),
+        &Bytes::from_array(&env, &p1_cards),
         &BytesN::from_array(&env, &p1_salt),
         &proof, &3,
-        &BytesN::from_array(&env, &[7u8, 8u8]),
+        &Bytes::from_array(&env, &[7u8, 8u8]),
         &BytesN::from_array(&env, &[0u8; 32]),
     );
 }
 
 #[test]
-#[should_panic(expected = "Player 1 proof is empty")]
+#[should_panic(expected = "Error(Contract, #3)")] // PokerError::EmptyProof
 fn test_resolve_showdown_rejects_zero_proof() {
     let env = Env::default();
     let verifier_id = env.register_contract(None, test_helpers::MockNoirVerifier);
@@ -228,41 +236,3258 @@ fn test_resolve_showdown_rejects_zero_proof() {
 
     let p1_salt = [42u8; 32];
     let p2_salt = [99u8; 32];
-    client.submit_commitment(&player1, &make_sha256_commitment(&env, [14, 13], p1_salt));
-    client.submit_commitment(&player2, &make_sha256_commitment(&env, [7, 8], p2_salt));
+    client.submit_commitment(&table(&env), &player1, &make_sha256_commitment(&env, [14, 13], p1_salt));
+    client.submit_commitment(&table(&env), &player2, &make_sha256_commitment(&env, [7, 8], p2_salt));
 
     let zero_proof = BytesN::from_array(&env, &[0u8; 128]);
     let valid_proof = BytesN::from_array(&env, &[1u8; 128]);
     // P1 proof is all-zero → should panic
     client.resolve_showdown(
+        &table(&env),
         &zero_proof, &5,
-        &BytesN::from_array(&env, &[14u8, 13u8]),
+        &Bytes::from_array(&env, &[14u8, 13u8]),
         &BytesN::from_array(&env, &p1_salt),
         &valid_proof, &3,
-        &BytesN::from_array(&env, &[7u8, 8u8]),
+        &Bytes::from_array(&env, &[7u8, 8u8]),
         &BytesN::from_array(&env, &p2_salt),
     );
 }
 
-/// Mock noir_verifier for testing — always returns true
-mod test_helpers {
-    use soroban_sdk::{contract, contractimpl, Env, BytesN};
+/// Helper: register poker_game + mock verifier, init a 1000-chip game, both
+/// players bet 100 and submit commitments. Returns (client, player1, player2).
+fn setup_showdown(env: &Env) -> (PokerGameContractClient<'_>, Address, Address) {
+    let verifier_id = env.register_contract(None, test_helpers::MockNoirVerifier);
+    let contract_id = env.register_contract(None, PokerGameContract);
+    let client = PokerGameContractClient::new(env, &contract_id);
 
-    #[contract]
-    pub struct MockNoirVerifier;
+    let player1 = Address::generate(env);
+    let player2 = Address::generate(env);
+    let game_id = BytesN::from_array(env, &[1u8; 32]);
+    env.mock_all_auths();
 
-    #[contractimpl]
-    impl MockNoirVerifier {
-        pub fn verify_proof(
-            _env: Env,
-            _hole_cards: BytesN<2>,
-            _salt: BytesN<32>,
-            _commitment: BytesN<32>,
-            _claimed_rank: u32,
-            _proof_bytes: BytesN<128>,
-            _player: BytesN<32>,
-        ) -> bool {
-            true
+    client.set_verifier(&verifier_id);
+    client.init_game(&game_id, &player1, &player2, &1000);
+    client.place_bet(&table(env), &player1, &100);
+    client.place_bet(&table(env), &player2, &100);
+
+    client.submit_commitment(&table(env), &player1, &make_sha256_commitment(env, [14, 13], [42u8; 32]));
+    client.submit_commitment(&table(env), &player2, &make_sha256_commitment(env, [7, 8], [99u8; 32]));
+
+    (client, player1, player2)
+}
+
+/// Helper: resolve the showdown set up by `setup_showdown` with the given ranks.
+fn resolve_with_ranks(env: &Env, client: &PokerGameContractClient, p1_rank: u32, p2_rank: u32) -> Address {
+    let proof = BytesN::from_array(env, &[1u8; 128]);
+    client.resolve_showdown(
+        &table(env),
+        &proof, &p1_rank,
+        &Bytes::from_array(env, &[14u8, 13u8]),
+        &BytesN::from_array(env, &[42u8; 32]),
+        &proof, &p2_rank,
+        &Bytes::from_array(env, &[7u8, 8u8]),
+        &BytesN::from_array(env, &[99u8; 32]),
+    )
+}
+
+#[test]
+fn test_resolve_showdown_with_rake() {
+    let env = Env::default();
+    let (client, player1, _player2) = setup_showdown(&env);
+
+    let admin = Address::generate(&env);
+    let collector = Address::generate(&env);
+    client.set_admin(&admin);
+    client.set_rake(&admin, &250, &collector);
+
+    let winner = resolve_with_ranks(&env, &client, 5, 3);
+    assert_eq!(winner, player1);
+
+    // Pot 200 → 2.5% rake = 5, winner receives 195
+    let state = client.get_game_state(&table(&env));
+    assert_eq!(state.players.get(0).unwrap().chips, 900 + 195);
+    assert_eq!(state.players.get(1).unwrap().chips, 900);
+    assert_eq!(state.pot, 0);
+    assert_eq!(client.get_collected_rake(), 5);
+}
+
+#[test]
+fn test_resolve_showdown_without_rake_pays_full_pot() {
+    let env = Env::default();
+    let (client, player1, _player2) = setup_showdown(&env);
+
+    let winner = resolve_with_ranks(&env, &client, 5, 3);
+    assert_eq!(winner, player1);
+
+    let state = client.get_game_state(&table(&env));
+    assert_eq!(state.players.get(0).unwrap().chips, 1100);
+    assert_eq!(client.get_collected_rake(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #18)")] // PokerError::RakeTooHigh
+fn test_set_rake_rejects_excessive_rate() {
+    let env = Env::default();
+    let (client, _player1, _player2) = setup_showdown(&env);
+
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.set_rake(&admin, &1_001, &admin);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #9)")] // PokerError::PlayerFolded
+fn test_place_bet_rejects_folded_player() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PokerGameContract);
+    let client = PokerGameContractClient::new(&env, &contract_id);
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let game_id = BytesN::from_array(&env, &[1u8; 32]);
+    env.mock_all_auths();
+
+    client.init_game(&game_id, &player1, &player2, &1000);
+    client.fold(&table(&env), &player1);
+    client.place_bet(&table(&env), &player1, &100);
+}
+
+#[test]
+fn test_turn_rotation_skips_folded_player() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PokerGameContract);
+    let client = PokerGameContractClient::new(&env, &contract_id);
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let player3 = Address::generate(&env);
+    let game_id = BytesN::from_array(&env, &[1u8; 32]);
+    env.mock_all_auths();
+
+    let mut seats = Vec::new(&env);
+    seats.push_back(player1.clone());
+    seats.push_back(player2.clone());
+    seats.push_back(player3.clone());
+    client.init_game_multi(&game_id, &seats, &1000);
+
+    // Seat 1 folds; seat 0 bets → turn must skip seat 1 and land on seat 2
+    client.fold(&table(&env), &player2);
+    client.place_bet(&table(&env), &player1, &100);
+    assert_eq!(client.get_game_state(&table(&env)).current_player, 2);
+
+    // Seat 2 bets → wraps back to seat 0, skipping seat 1 again
+    client.place_bet(&table(&env), &player3, &100);
+    assert_eq!(client.get_game_state(&table(&env)).current_player, 0);
+}
+
+/// Helper: init a 1000-chip heads-up game with bet limits [min_bet, max_bet].
+fn setup_with_bet_limits(env: &Env, min_bet: i128, max_bet: i128) -> (PokerGameContractClient<'_>, Address, Address) {
+    let contract_id = env.register_contract(None, PokerGameContract);
+    let client = PokerGameContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let player1 = Address::generate(env);
+    let player2 = Address::generate(env);
+    env.mock_all_auths();
+
+    client.set_admin(&admin);
+    client.set_bet_limits(&admin, &min_bet, &max_bet);
+    client.init_game(&BytesN::from_array(env, &[1u8; 32]), &player1, &player2, &1000);
+
+    (client, player1, player2)
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #12)")] // PokerError::BetBelowMinimum
+fn test_place_bet_rejects_sub_minimum() {
+    let env = Env::default();
+    let (client, player1, _player2) = setup_with_bet_limits(&env, 20, 500);
+    client.place_bet(&table(&env), &player1, &10);
+}
+
+#[test]
+fn test_place_bet_allows_all_in_below_minimum() {
+    let env = Env::default();
+    let (client, player1, _player2) = setup_with_bet_limits(&env, 20, 500);
+
+    // Player 1 bets down to a 10-chip stack
+    client.place_bet(&table(&env), &player1, &495);
+    client.place_bet(&table(&env), &player1, &495);
+
+    // 10 < min_bet, but it is the whole stack → legal all-in
+    client.place_bet(&table(&env), &player1, &10);
+    let state = client.get_game_state(&table(&env));
+    assert_eq!(state.players.get(0).unwrap().chips, 0);
+    assert_eq!(state.pot, 1000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #11)")] // PokerError::BetAboveLimit
+fn test_place_bet_rejects_over_maximum() {
+    let env = Env::default();
+    let (client, player1, _player2) = setup_with_bet_limits(&env, 20, 500);
+    client.place_bet(&table(&env), &player1, &501);
+}
+
+#[test]
+fn test_force_timeout_fold_awards_pot_to_opponent() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PokerGameContract);
+    let client = PokerGameContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    env.mock_all_auths();
+
+    client.set_admin(&admin);
+    client.set_action_timeout(&admin, &10);
+    client.init_game(&BytesN::from_array(&env, &[1u8; 32]), &player1, &player2, &1000);
+
+    // Player 1 bets, then player 2 walks away
+    client.place_bet(&table(&env), &player1, &100);
+    let acted_at = env.ledger().sequence();
+    env.ledger().set_sequence_number(acted_at + 11);
+
+    client.force_timeout_fold(&table(&env), &player2);
+
+    let state = client.get_game_state(&table(&env));
+    assert!(state.players.get(1).unwrap().has_folded);
+    assert_eq!(state.players.get(0).unwrap().chips, 1000);
+    assert_eq!(state.pot, 0);
+    assert!(!state.is_active);
+}
+
+#[test]
+fn test_force_timeout_fold_settles_hand_like_showdown() {
+    let env = Env::default();
+    let (client, player1, player2) = setup_showdown(&env);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.set_action_timeout(&admin, &10);
+
+    // Both players bet and committed; the one to act walks away
+    let idle = client.get_current_player(&table(&env));
+    let winner = if idle == player1 { player2 } else { player1 };
+    env.ledger().set_sequence_number(env.ledger().sequence() + 11);
+    client.force_timeout_fold(&table(&env), &idle);
+
+    // Bets and commitments are cleared for the next hand
+    let zero = BytesN::from_array(&env, &[0u8; 32]);
+    let state = client.get_game_state(&table(&env));
+    for p in state.players.iter() {
+        assert_eq!(p.current_bet, 0);
+        assert_eq!(p.commitment, zero);
+    }
+
+    // The result is in the hand history and the last-winner record
+    assert_eq!(client.get_hand_count(), 1);
+    let hand = client.get_hand(&0);
+    assert_eq!(hand.winner, winner);
+    assert_eq!(hand.pot, 200);
+    assert_eq!(client.get_last_winner(), winner);
+    assert_eq!(client.get_last_pot(), 200);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #34)")] // PokerError::TimeoutNotReached
+fn test_force_timeout_fold_rejects_before_timeout() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PokerGameContract);
+    let client = PokerGameContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    env.mock_all_auths();
+
+    client.set_admin(&admin);
+    client.set_action_timeout(&admin, &10);
+    client.init_game(&BytesN::from_array(&env, &[1u8; 32]), &player1, &player2, &1000);
+
+    client.place_bet(&table(&env), &player1, &100);
+    env.ledger().set_sequence_number(env.ledger().sequence() + 10);
+    client.force_timeout_fold(&table(&env), &player2);
+}
+
+#[test]
+fn test_force_timeout_fold_with_max_timeout_never_fires() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PokerGameContract);
+    let client = PokerGameContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    env.mock_all_auths();
+
+    client.set_admin(&admin);
+    client.set_action_timeout(&admin, &u32::MAX);
+    client.init_game(&table(&env), &player1, &player2, &1000);
+    env.ledger().set_sequence_number(1_000);
+    client.place_bet(&table(&env), &player1, &100);
+
+    // turn start + timeout would overflow u32
+    env.ledger().set_sequence_number(2_000);
+    assert_eq!(
+        client.try_force_timeout_fold(&table(&env), &player2),
+        Err(Ok(PokerError::TimeoutNotReached.into()))
+    );
+}
+
+#[test]
+fn test_pause_blocks_mutations_until_unpaused() {
+    let env = Env::default();
+    let (client, player1, player2) = setup_showdown(&env);
+
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.set_paused(&admin, &true);
+    assert!(client.is_paused());
+
+    // Every mutating entry point is rejected while paused
+    let commitment = make_sha256_commitment(&env, [14, 13], [42u8; 32]);
+    assert!(client.try_place_bet(&table(&env), &player1, &10).is_err());
+    assert!(client.try_fold(&table(&env), &player2).is_err());
+    assert!(client.try_submit_commitment(&table(&env), &player1, &commitment).is_err());
+    let proof = BytesN::from_array(&env, &[1u8; 128]);
+    assert!(client.try_resolve_showdown(
+        &table(&env),
+        &proof, &5,
+        &Bytes::from_array(&env, &[14u8, 13u8]),
+        &BytesN::from_array(&env, &[42u8; 32]),
+        &proof, &3,
+        &Bytes::from_array(&env, &[7u8, 8u8]),
+        &BytesN::from_array(&env, &[99u8; 32]),
+    ).is_err());
+
+    // Read-only queries still work and nothing moved
+    let state = client.get_game_state(&table(&env));
+    assert_eq!(state.pot, 200);
+    assert!(!state.players.get(1).unwrap().has_folded);
+
+    client.set_paused(&admin, &false);
+    client.place_bet(&table(&env), &player1, &100);
+    client.place_bet(&table(&env), &player2, &100);
+    assert_eq!(client.get_game_state(&table(&env)).pot, 400);
+    assert_eq!(resolve_with_ranks(&env, &client, 5, 3), player1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #45)")] // PokerError::Paused
+fn test_pause_rejects_place_bet() {
+    let env = Env::default();
+    let (client, player1, _player2) = setup_showdown(&env);
+
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.set_paused(&admin, &true);
+    client.place_bet(&table(&env), &player1, &10);
+}
+
+/// Helper: heads-up game backed by a Stellar asset; each player is minted 500 tokens.
+fn setup_with_token(env: &Env) -> (PokerGameContractClient<'_>, token::Client<'_>, Address, Address) {
+    let contract_id = env.register_contract(None, PokerGameContract);
+    let client = PokerGameContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let player1 = Address::generate(env);
+    let player2 = Address::generate(env);
+    env.mock_all_auths();
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let asset = token::StellarAssetClient::new(env, &sac.address());
+    asset.mint(&player1, &500);
+    asset.mint(&player2, &500);
+
+    client.set_admin(&admin);
+    client.set_token(&admin, &sac.address());
+    client.init_game(&BytesN::from_array(env, &[1u8; 32]), &player1, &player2, &1);
+
+    (client, token::Client::new(env, &sac.address()), player1, player2)
+}
+
+#[test]
+fn test_deposit_credits_chips_and_withdraw_pays_out() {
+    let env = Env::default();
+    let (client, tok, player1, _player2) = setup_with_token(&env);
+
+    // Table opened with 1 chip per seat
+    client.deposit(&table(&env), &player1, &300);
+    assert_eq!(client.get_game_state(&table(&env)).players.get(0).unwrap().chips, 301);
+    assert_eq!(tok.balance(&player1), 200);
+    assert_eq!(tok.balance(&client.address), 300);
+
+    // Stacks can only leave the table between hands
+    client.end_game(&table(&env), &player1);
+    client.withdraw(&table(&env), &player1, &120);
+    assert_eq!(client.get_game_state(&table(&env)).players.get(0).unwrap().chips, 181);
+    assert_eq!(tok.balance(&player1), 320);
+    assert_eq!(tok.balance(&client.address), 180);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #10)")] // PokerError::InsufficientChips
+fn test_withdraw_rejects_over_balance() {
+    let env = Env::default();
+    let (client, _tok, player1, _player2) = setup_with_token(&env);
+
+    client.deposit(&table(&env), &player1, &100);
+    client.end_game(&table(&env), &player1);
+    client.withdraw(&table(&env), &player1, &102);
+}
+
+#[test]
+fn test_withdraw_capped_to_deposited_tokens() {
+    let env = Env::default();
+    let (client, tok, player1, player2) = setup_with_token(&env);
+    client.deposit(&table(&env), &player1, &100);
+    client.end_game(&table(&env), &player1);
+    assert_eq!(client.get_backed_chips(&table(&env)), 100);
+
+    // The chip each seat opened with was never paid for
+    assert_eq!(
+        client.try_withdraw(&table(&env), &player1, &101),
+        Err(Ok(TableError::UnbackedChips.into()))
+    );
+
+    // A table anyone can open with free chips holds no tokens to drain
+    let other = BytesN::from_array(&env, &[9u8; 32]);
+    client.init_game(&other, &player2, &Address::generate(&env), &1000);
+    client.end_game(&other, &player2);
+    assert_eq!(
+        client.try_withdraw(&other, &player2, &100),
+        Err(Ok(TableError::UnbackedChips.into()))
+    );
+
+    client.withdraw(&table(&env), &player1, &100);
+    assert_eq!(tok.balance(&player1), 500);
+    assert_eq!(tok.balance(&client.address), 0);
+    assert_eq!(client.get_backed_chips(&table(&env)), 0);
+}
+
+#[test]
+fn test_withdraw_rejected_mid_hand() {
+    let env = Env::default();
+    let (client, tok, player1, player2) = setup_with_token(&env);
+    client.deposit(&table(&env), &player1, &300);
+    client.deposit(&table(&env), &player2, &300);
+    client.place_bet(&table(&env), &player1, &100);
+
+    // Emptying the stack now would make player 2 look all-in at showdown
+    assert_eq!(
+        client.try_withdraw(&table(&env), &player2, &301),
+        Err(Ok(PokerError::HandInProgress.into()))
+    );
+    assert_eq!(client.get_game_state(&table(&env)).players.get(1).unwrap().chips, 301);
+    assert_eq!(tok.balance(&player2), 200);
+}
+
+#[test]
+fn test_split_pot_three_way_tie() {
+    let env = Env::default();
+
+    let mut tied = Vec::new(&env);
+    tied.push_back(0u32);
+    tied.push_back(1u32);
+    tied.push_back(2u32);
+
+    // Button on seat 0 → seat 1 (left of button) receives the odd chip
+    let payouts = PokerGameContract::split_pot(&env, 100, &tied, 0, 3);
+    assert_eq!(payouts.get(0).unwrap(), 33);
+    assert_eq!(payouts.get(1).unwrap(), 34);
+    assert_eq!(payouts.get(2).unwrap(), 33);
+    assert_eq!(payouts.iter().sum::<i128>(), 100);
+
+    // Two leftover chips go to the first two tied seats clockwise from the button
+    let payouts = PokerGameContract::split_pot(&env, 101, &tied, 2, 3);
+    assert_eq!(payouts.get(0).unwrap(), 34);
+    assert_eq!(payouts.get(1).unwrap(), 34);
+    assert_eq!(payouts.get(2).unwrap(), 33);
+}
+
+#[test]
+fn test_resolve_showdown_tie_splits_pot() {
+    let env = Env::default();
+    let (client, player1, _player2) = setup_showdown(&env);
+
+    let winner = resolve_with_ranks(&env, &client, 4, 4);
+    assert_eq!(winner, player1);
+
+    let state = client.get_game_state(&table(&env));
+    assert_eq!(state.players.get(0).unwrap().chips, 1000);
+    assert_eq!(state.players.get(1).unwrap().chips, 1000);
+}
+
+#[test]
+fn test_lightweight_getters_match_state() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PokerGameContract);
+    let client = PokerGameContractClient::new(&env, &contract_id);
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    env.mock_all_auths();
+
+    client.init_game(&BytesN::from_array(&env, &[1u8; 32]), &player1, &player2, &1000);
+    assert_eq!(client.get_current_round(&table(&env)), BettingRound::Preflop);
+    assert_eq!(client.get_current_player(&table(&env)), player1);
+
+    client.place_bet(&table(&env), &player1, &100);
+    let state = client.get_game_state(&table(&env));
+    assert_eq!(client.get_pot(&table(&env)), state.pot);
+    assert_eq!(client.get_pot(&table(&env)), 100);
+    assert_eq!(client.get_current_player(&table(&env)), state.players.get(state.current_player).unwrap().address);
+    assert_eq!(client.get_current_player(&table(&env)), player2);
+
+    let mut flop = Vec::new(&env);
+    flop.push_back(0u32);
+    flop.push_back(14u32);
+    flop.push_back(28u32);
+    client.reveal_community_cards(&table(&env), &flop);
+    assert_eq!(client.get_current_round(&table(&env)), client.get_game_state(&table(&env)).current_round);
+    assert_eq!(client.get_current_round(&table(&env)), BettingRound::Flop);
+}
+
+#[test]
+fn test_reveal_community_cards_follows_street_order() {
+    let env = Env::default();
+    let (client, _player1, _player2) = setup_showdown(&env);
+    let board = |cards: &[u32]| {
+        let mut v = Vec::new(&env);
+        for c in cards {
+            v.push_back(*c);
+        }
+        v
+    };
+
+    // Skipping the flop, or dealing a bad card, is rejected
+    assert_eq!(client.try_reveal_community_cards(&table(&env), &board(&[0, 14, 28, 40])), Err(Ok(PokerError::WrongRound.into())));
+    assert_eq!(client.try_reveal_community_cards(&table(&env), &board(&[0, 14, 14])), Err(Ok(PokerError::InvalidCard.into())));
+    client.reveal_community_cards(&table(&env), &board(&[0, 14, 28]));
+
+    // The flop cannot be replaced, or dealt twice
+    assert_eq!(client.try_reveal_community_cards(&table(&env), &board(&[1, 14, 28, 40])), Err(Ok(PokerError::WrongRound.into())));
+    assert_eq!(client.try_reveal_community_cards(&table(&env), &board(&[1, 2, 3])), Err(Ok(PokerError::WrongRound.into())));
+    client.reveal_community_cards(&table(&env), &board(&[0, 14, 28, 40]));
+    client.reveal_community_cards(&table(&env), &board(&[0, 14, 28, 40, 51]));
+
+    let state = client.get_game_state(&table(&env));
+    assert_eq!(state.current_round, BettingRound::River);
+    assert_eq!(state.community_cards, board(&[0, 14, 28, 40, 51]));
+}
+
+#[test]
+fn test_hand_history_records_each_showdown() {
+    let env = Env::default();
+    let (client, player1, player2) = setup_showdown(&env);
+    assert_eq!(client.get_hand_count(), 0);
+
+    // Hand 1: player 1 wins a 200 pot
+    assert_eq!(resolve_with_ranks(&env, &client, 5, 3), player1);
+
+    // Hand 2: re-init the same table, player 2 wins a 100 pot
+    client.init_game(&table(&env), &player1, &player2, &1000);
+    client.place_bet(&table(&env), &player1, &50);
+    client.place_bet(&table(&env), &player2, &50);
+    client.submit_commitment(&table(&env), &player1, &make_sha256_commitment(&env, [14, 13], [42u8; 32]));
+    client.submit_commitment(&table(&env), &player2, &make_sha256_commitment(&env, [7, 8], [99u8; 32]));
+    assert_eq!(resolve_with_ranks(&env, &client, 1, 6), player2);
+
+    assert_eq!(client.get_hand_count(), 2);
+
+    let first = client.get_hand(&0);
+    assert_eq!(first.winner, player1);
+    assert_eq!(first.pot, 200);
+    assert_eq!(first.player1_rank, 5);
+    assert_eq!(first.player2_rank, 3);
+    assert_eq!(first.game_id, BytesN::from_array(&env, &[1u8; 32]));
+
+    let second = client.get_hand(&1);
+    assert_eq!(second.winner, player2);
+    assert_eq!(second.pot, 100);
+    assert_eq!(second.player1_proof_hash, BytesN::from_array(&env, &[1u8; 32]));
+}
+
+#[test]
+fn test_deck_commit_and_reveal() {
+    let env = Env::default();
+    let (client, player1, _player2) = setup_showdown(&env);
+
+    let seed = BytesN::from_array(&env, &[7u8; 32]);
+    let deck_commitment: BytesN<32> = env.crypto().sha256(&Bytes::from_array(&env, &[7u8; 32])).into();
+    client.commit_deck(&table(&env), &deck_commitment);
+
+    assert_eq!(resolve_with_ranks(&env, &client, 5, 3), player1);
+    client.reveal_deck(&table(&env), &seed);
+
+    let state = client.get_game_state(&table(&env));
+    assert_eq!(state.deck_commitment, deck_commitment);
+    assert_eq!(state.deck_seed, seed);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #27)")] // PokerError::DeckSeedMismatch
+fn test_deck_reveal_rejects_tampered_seed() {
+    let env = Env::default();
+    let (client, _player1, _player2) = setup_showdown(&env);
+
+    let deck_commitment: BytesN<32> = env.crypto().sha256(&Bytes::from_array(&env, &[7u8; 32])).into();
+    client.commit_deck(&table(&env), &deck_commitment);
+    resolve_with_ranks(&env, &client, 5, 3);
+
+    client.reveal_deck(&table(&env), &BytesN::from_array(&env, &[8u8; 32]));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #42)")] // PokerError::BetsNotSettled
+fn test_resolve_showdown_rejects_unequal_bets() {
+    let env = Env::default();
+    let (client, player1, _player2) = setup_showdown(&env);
+
+    // Player 1 raises and player 2 never calls
+    client.place_bet(&table(&env), &player1, &100);
+    resolve_with_ranks(&env, &client, 5, 3);
+}
+
+#[test]
+fn test_resolve_showdown_refunds_uncalled_excess() {
+    let env = Env::default();
+    let (client, _tok, player1, player2) = setup_with_token(&env);
+    let verifier_id = env.register_contract(None, test_helpers::MockNoirVerifier);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.set_verifier(&verifier_id);
+    client.force_reset(&admin, &table(&env));
+    client.init_game(&BytesN::from_array(&env, &[1u8; 32]), &player1, &player2, &1);
+
+    // Player 1 is all-in for 200; player 2 puts in 500 (one chip behind)
+    client.deposit(&table(&env), &player1, &199);
+    client.deposit(&table(&env), &player2, &500);
+    client.place_bet(&table(&env), &player1, &200);
+    client.place_bet(&table(&env), &player2, &500);
+    client.submit_commitment(&table(&env), &player1, &make_sha256_commitment(&env, [14, 13], [42u8; 32]));
+    client.submit_commitment(&table(&env), &player2, &make_sha256_commitment(&env, [7, 8], [99u8; 32]));
+
+    // Player 1 wins the matched 400; player 2 gets the uncalled 300 back
+    assert_eq!(resolve_with_ranks(&env, &client, 5, 3), player1);
+    let state = client.get_game_state(&table(&env));
+    assert_eq!(state.players.get(0).unwrap().chips, 400);
+    assert_eq!(state.players.get(1).unwrap().chips, 301);
+    assert_eq!(client.get_hand(&0).pot, 400);
+}
+
+#[test]
+fn test_verify_reveal_accepts_matching_cards() {
+    let env = Env::default();
+    let (client, player1, _player2) = setup_showdown(&env);
+
+    client.verify_reveal(
+        &table(&env),
+        &player1,
+        &Bytes::from_array(&env, &[14u8, 13u8]),
+        &BytesN::from_array(&env, &[42u8; 32]),
+    );
+    let state = client.get_game_state(&table(&env));
+    assert!(state.players.get(0).unwrap().revealed);
+    assert!(!state.players.get(1).unwrap().revealed);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #28)")] // PokerError::CardsMismatch
+fn test_verify_reveal_rejects_wrong_salt() {
+    let env = Env::default();
+    let (client, player1, _player2) = setup_showdown(&env);
+
+    client.verify_reveal(
+        &table(&env),
+        &player1,
+        &Bytes::from_array(&env, &[14u8, 13u8]),
+        &BytesN::from_array(&env, &[43u8; 32]),
+    );
+}
+
+#[test]
+fn test_verify_reveal_omaha_hole_cards() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PokerGameContract);
+    let client = PokerGameContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    env.mock_all_auths();
+
+    client.set_admin(&admin);
+    client.set_hole_cards(&admin, &4);
+    client.init_game(&table(&env), &player1, &player2, &1000);
+
+    let cards = Bytes::from_array(&env, &[14u8, 13, 40, 41]);
+    let salt = BytesN::from_array(&env, &[42u8; 32]);
+    let mut preimage = cards.clone();
+    preimage.extend_from_array(&salt.to_array());
+    let commitment: BytesN<32> = env.crypto().sha256(&preimage).into();
+    client.submit_commitment(&table(&env), &player1, &commitment);
+
+    // Only the table's hole-card count is accepted
+    let pair = Bytes::from_array(&env, &[14u8, 13]);
+    assert_eq!(
+        client.try_verify_reveal(&table(&env), &player1, &pair, &salt),
+        Err(Ok(PokerError::WrongHoleCardCount.into()))
+    );
+
+    assert!(client.verify_commitment_onchain(&table(&env), &player1, &cards, &salt));
+    assert_eq!(
+        client.try_verify_commitment_onchain(&table(&env), &player1, &pair, &salt),
+        Err(Ok(PokerError::WrongHoleCardCount.into()))
+    );
+    client.verify_reveal(&table(&env), &player1, &cards, &salt);
+    assert!(client.get_game_state(&table(&env)).players.get(0).unwrap().revealed);
+}
+
+#[test]
+fn test_verify_commitment_onchain_matches_helper_encoding() {
+    let env = Env::default();
+    let (client, player1, player2) = setup_showdown(&env);
+
+    // setup_showdown committed with make_sha256_commitment (cards || salt)
+    assert!(client.verify_commitment_onchain(
+        &table(&env),
+        &player2,
+        &Bytes::from_array(&env, &[7u8, 8u8]),
+        &BytesN::from_array(&env, &[99u8; 32]),
+    ));
+    let result = client.try_verify_commitment_onchain(
+        &table(&env),
+        &player1,
+        &Bytes::from_array(&env, &[14u8, 13u8]),
+        &BytesN::from_array(&env, &[43u8; 32]),
+    );
+    assert_eq!(result, Err(Ok(PokerError::CardsMismatch.into())));
+
+    // The check is read-only: nobody is marked revealed
+    let state = client.get_game_state(&table(&env));
+    assert!(!state.players.get(0).unwrap().revealed);
+    assert!(!state.players.get(1).unwrap().revealed);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #39)")] // PokerError::NotRevealed
+fn test_resolve_showdown_requires_reveal_when_enabled() {
+    let env = Env::default();
+    let (client, player1, _player2) = setup_showdown(&env);
+
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.set_require_reveal(&admin, &true);
+
+    client.verify_reveal(
+        &table(&env),
+        &player1,
+        &Bytes::from_array(&env, &[14u8, 13u8]),
+        &BytesN::from_array(&env, &[42u8; 32]),
+    );
+    resolve_with_ranks(&env, &client, 5, 3);
+}
+
+#[test]
+fn test_resolve_showdown_rejects_reentrant_verifier() {
+    let env = Env::default();
+    let verifier_id = env.register_contract(None, test_helpers::reentrant::ReentrantVerifier);
+    let contract_id = env.register_contract(None, PokerGameContract);
+    let client = PokerGameContractClient::new(&env, &contract_id);
+    test_helpers::reentrant::ReentrantVerifierClient::new(&env, &verifier_id).set_target(&contract_id, &table(&env));
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    env.mock_all_auths();
+
+    client.set_verifier(&verifier_id);
+    client.init_game(&BytesN::from_array(&env, &[1u8; 32]), &player1, &player2, &1000);
+    client.submit_commitment(&table(&env), &player1, &make_sha256_commitment(&env, [14, 13], [42u8; 32]));
+    client.submit_commitment(&table(&env), &player2, &make_sha256_commitment(&env, [7, 8], [99u8; 32]));
+
+    // Verifier calls back into resolve_showdown → must not succeed. The
+    // Soroban host rejects contract re-entry before the nested call runs, so
+    // it never reaches the LOCK guard (PokerError::Reentrant); the guard
+    // itself is covered by test_resolve_showdown_lock_blocks_nested_call.
+    resolve_with_ranks(&env, &client, 5, 3);
+    let inner = test_helpers::reentrant::ReentrantVerifierClient::new(&env, &verifier_id).inner_error();
+    let expected = Error::from_type_and_code(ScErrorType::Context, ScErrorCode::InvalidAction);
+    assert_eq!(inner, Some(expected.to_val().get_payload()));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #37)")] // PokerError::Reentrant
+fn test_resolve_showdown_lock_blocks_nested_call() {
+    let env = Env::default();
+    let (client, _player1, _player2) = setup_showdown(&env);
+
+    // Simulate being inside resolve_showdown
+    env.as_contract(&client.address, || {
+        env.storage().instance().set(&symbol_short!("LOCK"), &true);
+    });
+    resolve_with_ranks(&env, &client, 5, 3);
+}
+
+#[test]
+fn test_resolve_showdown_clears_lock() {
+    let env = Env::default();
+    let (client, _player1, _player2) = setup_showdown(&env);
+    resolve_with_ranks(&env, &client, 5, 3);
+
+    let locked = env.as_contract(&client.address, || {
+        env.storage().instance().has(&symbol_short!("LOCK"))
+    });
+    assert!(!locked);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")] // PokerError::VerifierNotConfigured
+fn test_resolve_showdown_rejects_unset_verifier() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PokerGameContract);
+    let client = PokerGameContractClient::new(&env, &contract_id);
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    env.mock_all_auths();
+
+    assert_eq!(client.get_verifier(), None);
+    let state = client.init_game(&BytesN::from_array(&env, &[1u8; 32]), &player1, &player2, &1000);
+    assert_eq!(state.verifier_contract, None);
+
+    client.submit_commitment(&table(&env), &player1, &make_sha256_commitment(&env, [14, 13], [42u8; 32]));
+    client.submit_commitment(&table(&env), &player2, &make_sha256_commitment(&env, [7, 8], [99u8; 32]));
+    resolve_with_ranks(&env, &client, 5, 3);
+}
+
+#[test]
+fn test_get_verifier_after_set() {
+    let env = Env::default();
+    let (client, _player1, _player2) = setup_showdown(&env);
+
+    let verifier = client.get_verifier().unwrap();
+    assert_eq!(client.get_game_state(&table(&env)).verifier_contract, Some(verifier));
+}
+
+#[test]
+fn test_set_verifier_records_interface_version() {
+    let env = Env::default();
+    let (client, _player1, _player2) = setup_showdown(&env);
+    assert_eq!(client.get_verifier_version(), Some(1));
+}
+
+#[test]
+fn test_set_verifier_rejects_incompatible_version() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PokerGameContract);
+    let client = PokerGameContractClient::new(&env, &contract_id);
+    let verifier_id = env.register_contract(None, test_helpers::incompatible::IncompatibleVerifier);
+
+    assert_eq!(client.try_set_verifier(&verifier_id), Err(Ok(PokerError::VerifierNotConfigured.into())));
+    assert_eq!(client.get_verifier(), None);
+    assert_eq!(client.get_verifier_version(), None);
+}
+
+#[test]
+fn test_set_verifier_accepts_unversioned_verifier() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PokerGameContract);
+    let client = PokerGameContractClient::new(&env, &contract_id);
+    let verifier_id = env.register_contract(None, test_helpers::counting::CountingVerifier);
+
+    client.set_verifier(&verifier_id);
+    assert_eq!(client.get_verifier(), Some(verifier_id));
+    assert_eq!(client.get_verifier_version(), None);
+}
+
+#[test]
+fn test_set_verifier_ping_rejects_non_verifier() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PokerGameContract);
+    let client = PokerGameContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    env.mock_all_auths();
+    client.set_admin(&admin);
+    client.set_verifier_ping(&admin, &true);
+
+    // Verifies proofs, but has no `ping`
+    let silent = env.register_contract(None, test_helpers::counting::CountingVerifier);
+    assert_eq!(client.try_set_verifier(&silent), Err(Ok(PokerError::VerifierNotConfigured.into())));
+    assert_eq!(client.get_verifier(), None);
+
+    let verifier_id = env.register_contract(None, test_helpers::MockNoirVerifier);
+    client.set_verifier(&verifier_id);
+    assert_eq!(client.get_verifier(), Some(verifier_id));
+}
+
+#[test]
+fn test_chip_invariant_holds_across_hands() {
+    let env = Env::default();
+    let (client, player1, player2) = setup_showdown(&env);
+    assert!(client.check_chip_invariant(&table(&env)));
+
+    // (p1_bet, p2_bet, p1_rank, p2_rank): win, loss, chopped pot
+    let hands = [(100, 100, 5, 3), (250, 250, 2, 7), (75, 75, 4, 4)];
+    for (i, (b1, b2, r1, r2)) in hands.iter().enumerate() {
+        if i > 0 {
+            client.init_game(&table(&env), &player1, &player2, &1000);
+            client.place_bet(&table(&env), &player1, b1);
+            client.place_bet(&table(&env), &player2, b2);
+            client.submit_commitment(&table(&env), &player1, &make_sha256_commitment(&env, [14, 13], [42u8; 32]));
+            client.submit_commitment(&table(&env), &player2, &make_sha256_commitment(&env, [7, 8], [99u8; 32]));
+        }
+        assert!(client.check_chip_invariant(&table(&env)));
+        resolve_with_ranks(&env, &client, *r1, *r2);
+
+        let state = client.get_game_state(&table(&env));
+        let total: i128 = state.players.iter().map(|p| p.chips).sum();
+        assert_eq!(total, 2 * 1000);
+        assert!(client.check_chip_invariant(&table(&env)));
+    }
+}
+
+#[test]
+fn test_end_game_moves_pot_without_creating_chips() {
+    let env = Env::default();
+    let (client, player1, _player2) = setup_showdown(&env);
+
+    client.end_game(&table(&env), &player1);
+    let state = client.get_game_state(&table(&env));
+    assert_eq!(state.pot, 0);
+    assert_eq!(state.players.get(0).unwrap().chips, 1100);
+    assert!(client.check_chip_invariant(&table(&env)));
+}
+
+#[test]
+fn test_two_tables_keep_independent_pots() {
+    let env = Env::default();
+    let (client, player1, _player2) = setup_showdown(&env);
+
+    // Second table in the same contract with different players
+    let table2 = BytesN::from_array(&env, &[2u8; 32]);
+    let player3 = Address::generate(&env);
+    let player4 = Address::generate(&env);
+    client.init_game(&table2, &player3, &player4, &500);
+    client.place_bet(&table2, &player3, &40);
+    client.place_bet(&table2, &player4, &40);
+
+    assert_eq!(client.get_pot(&table(&env)), 200);
+    assert_eq!(client.get_pot(&table2), 80);
+
+    client.fold(&table2, &player4);
+    assert!(!client.get_game_state(&table(&env)).players.get(1).unwrap().has_folded);
+
+    // Resolving table 1 leaves table 2 untouched
+    assert_eq!(resolve_with_ranks(&env, &client, 5, 3), player1);
+    assert_eq!(client.get_pot(&table(&env)), 0);
+    let state2 = client.get_game_state(&table2);
+    assert!(state2.is_active);
+    assert_eq!(state2.pot, 80);
+    assert_eq!(state2.players.get(0).unwrap().chips, 460);
+    assert!(client.check_chip_invariant(&table2));
+}
+
+#[test]
+fn test_unknown_game_id_rejected() {
+    let env = Env::default();
+    let (client, player1, _player2) = setup_showdown(&env);
+
+    let missing = BytesN::from_array(&env, &[9u8; 32]);
+    assert!(client.try_get_game_state(&missing).is_err());
+    assert!(client.try_place_bet(&missing, &player1, &10).is_err());
+}
+
+/// Helper: heads-up table backed by the call-counting mock verifier.
+fn setup_counted(env: &Env) -> (PokerGameContractClient<'_>, test_helpers::counting::CountingVerifierClient<'_>, Address, Address) {
+    let verifier_id = env.register_contract(None, test_helpers::counting::CountingVerifier);
+    let contract_id = env.register_contract(None, PokerGameContract);
+    let client = PokerGameContractClient::new(env, &contract_id);
+    let verifier = test_helpers::counting::CountingVerifierClient::new(env, &verifier_id);
+
+    let player1 = Address::generate(env);
+    let player2 = Address::generate(env);
+    client.set_verifier(&verifier_id);
+    client.init_game(&table(env), &player1, &player2, &1000);
+    (client, verifier, player1, player2)
+}
+
+#[test]
+fn test_folded_player_does_not_block_showdown() {
+    let env = Env::default();
+    let (client, verifier, player1, player2) = setup_counted(&env);
+
+    client.place_bet(&table(&env), &player1, &100);
+    client.place_bet(&table(&env), &player2, &50);
+    client.fold(&table(&env), &player2);
+    client.submit_commitment(&table(&env), &player1, &make_sha256_commitment(&env, [14, 13], [42u8; 32]));
+
+    // Player 2 never committed and sends an empty proof with a higher rank
+    let winner = client.resolve_showdown(
+        &table(&env),
+        &BytesN::from_array(&env, &[1u8; 128]), &1,
+        &Bytes::from_array(&env, &[14u8, 13u8]),
+        &BytesN::from_array(&env, &[42u8; 32]),
+        &BytesN::from_array(&env, &[0u8; 128]), &9,
+        &Bytes::from_array(&env, &[0u8, 0u8]),
+        &BytesN::from_array(&env, &[0u8; 32]),
+    );
+    assert_eq!(winner, player1);
+    assert_eq!(verifier.calls(), 0);
+
+    // Uncontested: player 1 collects the whole pot, dead money included
+    let state = client.get_game_state(&table(&env));
+    assert_eq!(state.players.get(0).unwrap().chips, 1050);
+    assert_eq!(state.players.get(1).unwrap().chips, 950);
+    assert!(client.check_chip_invariant(&table(&env)));
+}
+
+#[test]
+fn test_contested_showdown_verifies_both_proofs() {
+    let env = Env::default();
+    let (client, verifier, player1, player2) = setup_counted(&env);
+
+    client.place_bet(&table(&env), &player1, &100);
+    client.place_bet(&table(&env), &player2, &100);
+    client.submit_commitment(&table(&env), &player1, &make_sha256_commitment(&env, [14, 13], [42u8; 32]));
+    client.submit_commitment(&table(&env), &player2, &make_sha256_commitment(&env, [7, 8], [99u8; 32]));
+
+    assert_eq!(resolve_with_ranks(&env, &client, 2, 6), player2);
+    assert_eq!(verifier.calls(), 2);
+}
+
+#[test]
+fn test_fold_rejected_when_it_would_empty_the_hand() {
+    let env = Env::default();
+    let (client, _verifier, player1, player2) = setup_counted(&env);
+
+    client.fold(&table(&env), &player1);
+    assert_eq!(client.try_fold(&table(&env), &player2), Err(Ok(PokerError::NoActivePlayers.into())));
+    assert!(!client.get_game_state(&table(&env)).players.get(1).unwrap().has_folded);
+}
+
+#[test]
+fn test_fold_emits_event() {
+    let env = Env::default();
+    let (client, player1, _player2) = setup_showdown(&env);
+
+    client.fold(&table(&env), &player1);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (symbol_short!("fold"), player1).into_val(&env));
+    assert_eq!(BytesN::<32>::try_from_val(&env, &data).unwrap(), table(&env));
+}
+
+#[test]
+fn test_omaha_showdown_passes_four_hole_cards() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let verifier_id = env.register_contract(None, test_helpers::MockNoirVerifier);
+    let contract_id = env.register_contract(None, PokerGameContract);
+    let client = PokerGameContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+
+    client.set_admin(&admin);
+    client.set_verifier(&verifier_id);
+    client.set_hole_cards(&admin, &4);
+    assert!(client.try_set_hole_cards(&admin, &1).is_err());
+
+    let state = client.init_game(&table(&env), &player1, &player2, &1000);
+    assert_eq!(state.hole_cards, 4);
+    client.place_bet(&table(&env), &player1, &100);
+    client.place_bet(&table(&env), &player2, &100);
+    client.submit_commitment(&table(&env), &player1, &BytesN::from_array(&env, &[3u8; 32]));
+    client.submit_commitment(&table(&env), &player2, &BytesN::from_array(&env, &[4u8; 32]));
+
+    let proof = BytesN::from_array(&env, &[1u8; 128]);
+    let resolve = |c1: &[u8], c2: &[u8]| {
+        client.try_resolve_showdown(
+            &table(&env),
+            &proof, &6, &Bytes::from_slice(&env, c1), &BytesN::from_array(&env, &[42u8; 32]),
+            &proof, &2, &Bytes::from_slice(&env, c2), &BytesN::from_array(&env, &[99u8; 32]),
+        )
+    };
+
+    // Hold'em-sized hands are rejected at an Omaha table
+    assert!(resolve(&[14, 13], &[7, 8]).is_err());
+    assert_eq!(resolve(&[14, 13, 40, 41], &[7, 8, 20, 21]).unwrap().unwrap(), player1);
+}
+
+#[test]
+fn test_recent_hands_newest_first() {
+    let env = Env::default();
+    let (client, player1, player2) = setup_showdown(&env);
+    assert_eq!(client.get_recent_hands(&5).len(), 0);
+
+    // Three hands with pots 200, 100, 60
+    resolve_with_ranks(&env, &client, 5, 3);
+    for bet in [50, 30] {
+        client.init_game(&table(&env), &player1, &player2, &1000);
+        client.place_bet(&table(&env), &player1, &bet);
+        client.place_bet(&table(&env), &player2, &bet);
+        client.submit_commitment(&table(&env), &player1, &make_sha256_commitment(&env, [14, 13], [42u8; 32]));
+        client.submit_commitment(&table(&env), &player2, &make_sha256_commitment(&env, [7, 8], [99u8; 32]));
+        resolve_with_ranks(&env, &client, 1, 6);
+    }
+
+    let last_two = client.get_recent_hands(&2);
+    assert_eq!(last_two.len(), 2);
+    assert_eq!(last_two.get(0).unwrap().pot, 60);
+    assert_eq!(last_two.get(1).unwrap().pot, 100);
+
+    // Asking for more than exist returns the whole history
+    let all = client.get_recent_hands(&10);
+    assert_eq!(all.len(), 3);
+    assert_eq!(all.get(2).unwrap().pot, 200);
+    assert_eq!(all.get(2).unwrap().winner, player1);
+}
+
+/// Showdown event payload: (rank1, rank2, hash1, hash2, commit1, commit2, rake, payouts, was_tie, showdown_seq)
+type ShowdownEvent = (u32, u32, BytesN<32>, BytesN<32>, BytesN<32>, BytesN<32>, i128, Vec<i128>, bool, u64);
+
+/// Helper: decode the most recent event's payload as a showdown event.
+fn last_showdown_event(env: &Env) -> ShowdownEvent {
+    let (_, _, data) = env.events().all().last().unwrap();
+    ShowdownEvent::try_from_val(env, &data).unwrap()
+}
+
+#[test]
+fn test_showdown_event_reports_tie_and_split() {
+    let env = Env::default();
+    let (client, _player1, _player2) = setup_showdown(&env);
+
+    // Pot 200 - 2.5% rake (5) = 195: odd chip goes to the seat left of the button
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.set_rake(&admin, &250, &Address::generate(&env));
+    resolve_with_ranks(&env, &client, 4, 4);
+
+    let event = last_showdown_event(&env);
+    assert!(event.8);
+    assert_eq!(event.6, 5);
+    assert_eq!(event.7.get(0).unwrap(), 97);
+    assert_eq!(event.7.get(1).unwrap(), 98);
+
+    let state = client.get_game_state(&table(&env));
+    assert_eq!(state.players.get(0).unwrap().chips, 900 + 97);
+    assert_eq!(state.players.get(1).unwrap().chips, 900 + 98);
+}
+
+#[test]
+fn test_showdown_event_reports_outright_win() {
+    let env = Env::default();
+    let (client, _player1, _player2) = setup_showdown(&env);
+    resolve_with_ranks(&env, &client, 6, 2);
+
+    let event = last_showdown_event(&env);
+    assert!(!event.8);
+    assert_eq!(event.7.get(0).unwrap(), 200);
+    assert_eq!(event.7.get(1).unwrap(), 0);
+}
+
+#[test]
+fn test_showdown_events_carry_consecutive_sequence_numbers() {
+    let env = Env::default();
+    let (client, player1, player2) = setup_showdown(&env);
+    resolve_with_ranks(&env, &client, 6, 2);
+    let first = last_showdown_event(&env).9;
+
+    client.init_game(&table(&env), &player1, &player2, &1000);
+    client.place_bet(&table(&env), &player1, &100);
+    client.place_bet(&table(&env), &player2, &100);
+    client.submit_commitment(&table(&env), &player1, &make_sha256_commitment(&env, [14, 13], [43u8; 32]));
+    client.submit_commitment(&table(&env), &player2, &make_sha256_commitment(&env, [7, 8], [98u8; 32]));
+    let proof = BytesN::from_array(&env, &[1u8; 128]);
+    client.resolve_showdown(
+        &table(&env),
+        &proof, &2, &Bytes::from_array(&env, &[14u8, 13u8]), &BytesN::from_array(&env, &[43u8; 32]),
+        &proof, &6, &Bytes::from_array(&env, &[7u8, 8u8]), &BytesN::from_array(&env, &[98u8; 32]),
+    );
+
+    assert_eq!(first, 0);
+    assert_eq!(last_showdown_event(&env).9, first + 1);
+}
+
+#[test]
+fn test_set_verifier_locked_after_first_game() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PokerGameContract);
+    let client = PokerGameContractClient::new(&env, &contract_id);
+    let original = Address::generate(&env);
+    let malicious = Address::generate(&env);
+
+    // Free to set (and reset) before any game exists
+    client.set_verifier(&malicious);
+    client.set_verifier(&original);
+    client.init_game(&table(&env), &Address::generate(&env), &Address::generate(&env), &1000);
+
+    // No admin configured: nobody can swap it
+    assert!(client.try_set_verifier(&malicious).is_err());
+
+    // Admin configured but not authorizing
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    env.set_auths(&[]);
+    assert!(client.try_set_verifier(&malicious).is_err());
+    assert_eq!(client.get_verifier(), Some(original));
+}
+
+#[test]
+fn test_admin_can_change_verifier_after_first_game() {
+    let env = Env::default();
+    let (client, _player1, _player2) = setup_showdown(&env);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let replacement = Address::generate(&env);
+    client.set_verifier(&replacement);
+    assert_eq!(env.auths().len(), 1);
+    assert_eq!(env.auths()[0].0, admin);
+    assert_eq!(client.get_verifier(), Some(replacement));
+
+    // The running game keeps the verifier it was created with
+    assert_ne!(client.get_game_state(&table(&env)).verifier_contract, client.get_verifier());
+}
+
+#[test]
+fn test_all_in_pushes_full_stack() {
+    let env = Env::default();
+    let (client, player1, player2) = setup_showdown(&env);
+
+    // Each player has 900 behind after the 100 bets
+    client.all_in(&table(&env), &player1);
+    let state = client.get_game_state(&table(&env));
+    let p1 = state.players.get(0).unwrap();
+    assert_eq!(p1.chips, 0);
+    assert_eq!(p1.current_bet, 1000);
+    assert!(p1.all_in);
+    assert_eq!(state.pot, 1100);
+    assert_eq!(state.current_player, 1);
+
+    // Further actions by the all-in player are rejected
+    assert!(client.try_all_in(&table(&env), &player1).is_err());
+    assert!(client.try_place_bet(&table(&env), &player1, &1).is_err());
+
+    // Call and showdown: the all-in flag is cleared for the next hand
+    client.all_in(&table(&env), &player2);
+    assert_eq!(client.get_pot(&table(&env)), 2000);
+    resolve_with_ranks(&env, &client, 7, 2);
+    let state = client.get_game_state(&table(&env));
+    assert_eq!(state.players.get(0).unwrap().chips, 2000);
+    assert!(!state.players.get(0).unwrap().all_in);
+    assert!(!state.players.get(1).unwrap().all_in);
+}
+
+#[test]
+fn test_all_in_rejected_after_fold() {
+    let env = Env::default();
+    let (client, _player1, player2) = setup_showdown(&env);
+
+    client.fold(&table(&env), &player2);
+    assert!(client.try_all_in(&table(&env), &player2).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")] // PokerError::InvalidStartingChips
+fn test_init_game_rejects_zero_starting_chips() {
+    let env = Env::default();
+    let client = PokerGameContractClient::new(&env, &env.register_contract(None, PokerGameContract));
+    client.init_game(&table(&env), &Address::generate(&env), &Address::generate(&env), &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")] // PokerError::InvalidStartingChips
+fn test_init_game_rejects_negative_starting_chips() {
+    let env = Env::default();
+    let client = PokerGameContractClient::new(&env, &env.register_contract(None, PokerGameContract));
+    client.init_game(&table(&env), &Address::generate(&env), &Address::generate(&env), &-50);
+}
+
+#[test]
+fn test_init_game_enforces_max_starting_chips() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = PokerGameContractClient::new(&env, &env.register_contract(None, PokerGameContract));
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    client.set_admin(&admin);
+    client.set_max_starting_chips(&admin, &5_000);
+
+    assert!(client.try_init_game(&table(&env), &player1, &player2, &5_001).is_err());
+    let state = client.init_game(&table(&env), &player1, &player2, &5_000);
+    assert_eq!(state.players.get(0).unwrap().chips, 5_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #44)")] // PokerError::SaltReused
+fn test_salt_reuse_across_hands_rejected() {
+    let env = Env::default();
+    let (client, player1, player2) = setup_showdown(&env);
+    client.verify_reveal(
+        &table(&env),
+        &player1,
+        &Bytes::from_array(&env, &[14u8, 13u8]),
+        &BytesN::from_array(&env, &[42u8; 32]),
+    );
+    resolve_with_ranks(&env, &client, 5, 3);
+
+    // Next hand: new cards, same salt
+    client.init_game(&table(&env), &player1, &player2, &1000);
+    client.submit_commitment(&table(&env), &player1, &make_sha256_commitment(&env, [20, 30], [42u8; 32]));
+    client.verify_reveal(
+        &table(&env),
+        &player1,
+        &Bytes::from_array(&env, &[20u8, 30u8]),
+        &BytesN::from_array(&env, &[42u8; 32]),
+    );
+}
+
+#[test]
+fn test_fresh_salt_accepted_next_hand() {
+    let env = Env::default();
+    let (client, player1, player2) = setup_showdown(&env);
+    resolve_with_ranks(&env, &client, 5, 3);
+
+    client.init_game(&table(&env), &player1, &player2, &1000);
+    client.submit_commitment(&table(&env), &player1, &make_sha256_commitment(&env, [20, 30], [43u8; 32]));
+    client.verify_reveal(
+        &table(&env),
+        &player1,
+        &Bytes::from_array(&env, &[20u8, 30u8]),
+        &BytesN::from_array(&env, &[43u8; 32]),
+    );
+    assert!(client.get_game_state(&table(&env)).players.get(0).unwrap().revealed);
+}
+
+#[test]
+fn test_showdown_uses_verifier_returned_ranks() {
+    let env = Env::default();
+    let (client, player1, player2) = setup_showdown(&env);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    // Replace the verifier before the next game so it is captured at init
+    let verifier_id = env.register_contract(None, test_helpers::overriding::OverridingVerifier);
+    client.set_verifier(&verifier_id);
+    client.force_reset(&admin, &table(&env));
+    client.init_game(&table(&env), &player1, &player2, &1000);
+    client.place_bet(&table(&env), &player1, &100);
+    client.place_bet(&table(&env), &player2, &100);
+    client.submit_commitment(&table(&env), &player1, &make_sha256_commitment(&env, [14, 13], [42u8; 32]));
+    client.submit_commitment(&table(&env), &player2, &make_sha256_commitment(&env, [7, 8], [99u8; 32]));
+
+    // Claims 5 vs 3, but the verifier reports 4 vs 6
+    assert_eq!(resolve_with_ranks(&env, &client, 5, 3), player2);
+    let hand = client.get_hand(&0);
+    assert_eq!(hand.player1_rank, 4);
+    assert_eq!(hand.player2_rank, 6);
+}
+
+#[test]
+fn test_lowball_lower_rank_takes_pot() {
+    let env = Env::default();
+    let (client, player1, player2) = setup_showdown(&env);
+    assert_eq!(client.get_game_state(&table(&env)).hand_ordering, HandOrdering::HighWins);
+
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.set_hand_ordering(&admin, &HandOrdering::LowWins);
+    client.force_reset(&admin, &table(&env));
+    let state = client.init_game(&table(&env), &player1, &player2, &1000);
+    assert_eq!(state.hand_ordering, HandOrdering::LowWins);
+    client.place_bet(&table(&env), &player1, &100);
+    client.place_bet(&table(&env), &player2, &100);
+    client.submit_commitment(&table(&env), &player1, &make_sha256_commitment(&env, [14, 13], [42u8; 32]));
+    client.submit_commitment(&table(&env), &player2, &make_sha256_commitment(&env, [7, 8], [99u8; 32]));
+
+    assert_eq!(resolve_with_ranks(&env, &client, 5, 0), player2);
+    let state = client.get_game_state(&table(&env));
+    assert_eq!(state.players.get(0).unwrap().chips, 900);
+    assert_eq!(state.players.get(1).unwrap().chips, 1100);
+}
+
+#[test]
+fn test_require_attestation_is_per_table() {
+    let env = Env::default();
+    let (client, player1, player2) = setup_showdown(&env);
+    assert!(client.get_game_state(&table(&env)).require_attestation);
+
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    let verifier_id = env.register_contract(None, test_helpers::gated::GatedVerifier);
+    client.set_verifier(&verifier_id);
+    let play_hand = |client: &PokerGameContractClient| {
+        client.force_reset(&admin, &table(&env));
+        client.init_game(&table(&env), &player1, &player2, &1000);
+        client.place_bet(&table(&env), &player1, &100);
+        client.place_bet(&table(&env), &player2, &100);
+        client.submit_commitment(&table(&env), &player1, &make_sha256_commitment(&env, [14, 13], [42u8; 32]));
+        client.submit_commitment(&table(&env), &player2, &make_sha256_commitment(&env, [7, 8], [99u8; 32]));
+    };
+
+    // Unattested proofs are rejected at a table that requires attestation
+    play_hand(&client);
+    let proof = BytesN::from_array(&env, &[1u8; 128]);
+    assert!(client.try_resolve_showdown(
+        &table(&env),
+        &proof, &5, &Bytes::from_array(&env, &[14u8, 13u8]), &BytesN::from_array(&env, &[42u8; 32]),
+        &proof, &3, &Bytes::from_array(&env, &[7u8, 8u8]), &BytesN::from_array(&env, &[99u8; 32]),
+    ).is_err());
+
+    // ...and accepted at a casual table
+    client.set_require_attestation(&admin, &false);
+    play_hand(&client);
+    assert!(!client.get_game_state(&table(&env)).require_attestation);
+    assert_eq!(resolve_with_ranks(&env, &client, 5, 3), player1);
+
+    let stranger = Address::generate(&env);
+    assert!(client.try_set_require_attestation(&stranger, &true).is_err());
+}
+
+#[test]
+fn test_last_winner_and_pot_match_showdown() {
+    let env = Env::default();
+    let (client, player1, _player2) = setup_showdown(&env);
+    assert!(client.try_get_last_winner().is_err());
+
+    let winner = resolve_with_ranks(&env, &client, 5, 3);
+    assert_eq!(winner, player1);
+    assert_eq!(client.get_last_winner(), winner);
+    assert_eq!(client.get_last_pot(), 200);
+    assert_eq!(client.get_last_pot(), client.get_hand(&0).pot);
+}
+
+#[test]
+fn test_collect_antes_seeds_pot() {
+    let env = Env::default();
+    let (client, player1, player2) = setup_showdown(&env);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.set_ante(&admin, &25);
+    client.force_reset(&admin, &table(&env));
+    client.init_game(&table(&env), &player1, &player2, &1000);
+
+    assert_eq!(client.collect_antes(&table(&env)), 50);
+    let state = client.get_game_state(&table(&env));
+    assert_eq!(state.pot, 50);
+    for p in state.players.iter() {
+        assert_eq!(p.chips, 975);
+        assert_eq!(p.current_bet, 0);
+        assert!(!p.all_in);
+    }
+    assert!(client.check_chip_invariant(&table(&env)));
+
+    // Only once per hand, and betting continues on top of the antes
+    assert!(client.try_collect_antes(&table(&env)).is_err());
+    client.place_bet(&table(&env), &player1, &100);
+    assert_eq!(client.get_pot(&table(&env)), 150);
+
+    let stranger = Address::generate(&env);
+    assert!(client.try_set_ante(&stranger, &10).is_err());
+    assert!(client.try_set_ante(&admin, &-1).is_err());
+}
+
+#[test]
+fn test_collect_antes_puts_short_stack_all_in() {
+    let env = Env::default();
+    let (client, _tok, player1, player2) = setup_with_token(&env);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.set_ante(&admin, &10);
+    client.force_reset(&admin, &table(&env));
+    client.init_game(&table(&env), &player1, &player2, &1);
+    client.deposit(&table(&env), &player1, &100);
+
+    // Player 2 holds a single chip and posts it as a partial ante
+    assert_eq!(client.collect_antes(&table(&env)), 11);
+    let state = client.get_game_state(&table(&env));
+    let p1 = state.players.get(0).unwrap();
+    let p2 = state.players.get(1).unwrap();
+    assert_eq!((p1.chips, p1.all_in), (91, false));
+    assert_eq!((p2.chips, p2.all_in), (0, true));
+    assert!(client.check_chip_invariant(&table(&env)));
+}
+
+#[test]
+fn test_resolve_showdown_error_codes() {
+    let env = Env::default();
+    let (client, player1, player2) = setup_showdown(&env);
+    let proof = BytesN::from_array(&env, &[1u8; 128]);
+    let resolve = |client: &PokerGameContractClient, p1_proof: &BytesN<128>, p1_rank: u32, c1: &[u8], c2: &[u8]| {
+        client.try_resolve_showdown(
+            &table(&env),
+            p1_proof, &p1_rank, &Bytes::from_slice(&env, c1), &BytesN::from_array(&env, &[42u8; 32]),
+            &proof, &3, &Bytes::from_slice(&env, c2), &BytesN::from_array(&env, &[99u8; 32]),
+        )
+    };
+
+    let zero_proof = BytesN::from_array(&env, &[0u8; 128]);
+    assert_eq!(resolve(&client, &zero_proof, 5, &[14, 13], &[7, 8]), Err(Ok(PokerError::EmptyProof.into())));
+    assert_eq!(resolve(&client, &proof, 10, &[14, 13], &[7, 8]), Err(Ok(PokerError::InvalidRank.into())));
+    assert_eq!(resolve(&client, &proof, 5, &[14, 13], &[7, 8]), Ok(Ok(player1.clone())));
+    assert_eq!(resolve(&client, &proof, 5, &[14, 13], &[7, 8]), Err(Ok(PokerError::NotActive.into())));
+
+    // New hand without commitments
+    client.init_game(&table(&env), &player1, &player2, &1000);
+    client.place_bet(&table(&env), &player1, &100);
+    client.place_bet(&table(&env), &player2, &100);
+    assert_eq!(resolve(&client, &proof, 5, &[14, 13], &[7, 8]), Err(Ok(PokerError::MissingCommitment.into())));
+
+    // A verifier that rejects the proof
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.set_hole_cards(&admin, &4);
+    client.set_verifier(&env.register_contract(None, test_helpers::rejecting::RejectingVerifier));
+    client.force_reset(&admin, &table(&env));
+    client.init_game(&table(&env), &player1, &player2, &1000);
+    client.submit_commitment(&table(&env), &player1, &BytesN::from_array(&env, &[3u8; 32]));
+    client.submit_commitment(&table(&env), &player2, &BytesN::from_array(&env, &[4u8; 32]));
+    let rejected = client.try_resolve_showdown(
+        &table(&env),
+        &proof, &5, &Bytes::from_array(&env, &[14u8, 13, 40, 41]), &BytesN::from_array(&env, &[43u8; 32]),
+        &proof, &3, &Bytes::from_array(&env, &[7u8, 8, 20, 21]), &BytesN::from_array(&env, &[98u8; 32]),
+    );
+    assert_eq!(rejected, Err(Ok(PokerError::VerifierFalse.into())));
+}
+
+#[test]
+fn test_busted_player_rebuys_between_hands() {
+    let env = Env::default();
+    let (client, player1, player2) = setup_showdown(&env);
+    client.all_in(&table(&env), &player1);
+    client.all_in(&table(&env), &player2);
+    resolve_with_ranks(&env, &client, 5, 3);
+    assert_eq!(client.get_game_state(&table(&env)).players.get(1).unwrap().chips, 0);
+
+    // Player 1 has a full stack and may not rebuy
+    assert_eq!(client.try_rebuy(&table(&env), &player1, &500), Err(Ok(PokerError::RebuyNotAllowed.into())));
+    client.rebuy(&table(&env), &player2, &500);
+    let p2 = client.get_game_state(&table(&env)).players.get(1).unwrap();
+    assert_eq!(p2.chips, 500);
+    assert!(!p2.all_in);
+    assert!(client.check_chip_invariant(&table(&env)));
+}
+
+#[test]
+fn test_rebuy_rejected_mid_hand() {
+    let env = Env::default();
+    let (client, tok, player1, player2) = setup_with_token(&env);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.set_rebuy_threshold(&admin, &10);
+
+    // Short stack (1 chip) but the hand is still running
+    assert_eq!(client.try_rebuy(&table(&env), &player2, &100), Err(Ok(PokerError::HandInProgress.into())));
+
+    client.end_game(&table(&env), &player1);
+    client.rebuy(&table(&env), &player2, &100);
+    assert_eq!(client.get_game_state(&table(&env)).players.get(1).unwrap().chips, 101);
+    assert_eq!(tok.balance(&player2), 400);
+}
+
+#[test]
+fn test_rebuy_near_i128_max_fails_instead_of_wrapping() {
+    let env = Env::default();
+    let (client, player1, player2) = setup_showdown(&env);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.set_rebuy_threshold(&admin, &i128::MAX);
+    resolve_with_ranks(&env, &client, 5, 3);
+
+    // The stack itself would overflow
+    assert_eq!(
+        client.try_rebuy(&table(&env), &player1, &(i128::MAX - 100)),
+        Err(Ok(TableError::ArithmeticOverflow.into()))
+    );
+    // The stack fits, but the table's chip supply would overflow
+    assert_eq!(
+        client.try_rebuy(&table(&env), &player2, &(i128::MAX - 1000)),
+        Err(Ok(TableError::ArithmeticOverflow.into()))
+    );
+
+    let state = client.get_game_state(&table(&env));
+    assert_eq!(state.players.get(0).unwrap().chips, 1100);
+    assert_eq!(state.players.get(1).unwrap().chips, 900);
+    assert!(client.check_chip_invariant(&table(&env)));
+}
+
+#[test]
+fn test_player_stats_accumulate_across_hands() {
+    let env = Env::default();
+    let (client, player1, player2) = setup_showdown(&env);
+    assert_eq!(client.get_player_stats(&player1), PlayerStats::default());
+
+    // Hand 1: both bet 100, player 1 wins
+    resolve_with_ranks(&env, &client, 5, 3);
+
+    // Hand 2: player 1 bets 60 and folds to player 2's 60
+    client.init_game(&table(&env), &player1, &player2, &1000);
+    client.place_bet(&table(&env), &player1, &60);
+    client.place_bet(&table(&env), &player2, &60);
+    client.fold(&table(&env), &player1);
+    client.submit_commitment(&table(&env), &player2, &make_sha256_commitment(&env, [7, 8], [98u8; 32]));
+    let proof = BytesN::from_array(&env, &[1u8; 128]);
+    client.resolve_showdown(
+        &table(&env),
+        &proof, &0, &Bytes::from_array(&env, &[0u8, 0u8]), &BytesN::from_array(&env, &[0u8; 32]),
+        &proof, &3, &Bytes::from_array(&env, &[7u8, 8u8]), &BytesN::from_array(&env, &[98u8; 32]),
+    );
+
+    let s1 = client.get_player_stats(&player1);
+    assert_eq!((s1.hands_played, s1.hands_won, s1.chips_won, s1.chips_lost), (2, 1, 100, 60));
+    let s2 = client.get_player_stats(&player2);
+    assert_eq!((s2.hands_played, s2.hands_won, s2.chips_won, s2.chips_lost), (2, 1, 60, 100));
+}
+
+#[test]
+fn test_odd_chip_follows_dealer_button() {
+    // Pot 200 - 2.5% rake (5) = 195: whoever sits left of the button gets 98
+    for (button, left_of_button) in [(0u32, 1u32), (1, 0)] {
+        let env = Env::default();
+        let (client, _player1, _player2) = setup_showdown(&env);
+        let admin = Address::generate(&env);
+        client.set_admin(&admin);
+        client.set_rake(&admin, &250, &Address::generate(&env));
+        client.set_dealer_button(&admin, &table(&env), &button);
+        resolve_with_ranks(&env, &client, 4, 4);
+
+        let event = last_showdown_event(&env);
+        assert_eq!(event.7.get(left_of_button).unwrap(), 98);
+        assert_eq!(event.7.get(button).unwrap(), 97);
+    }
+}
+
+#[test]
+fn test_set_dealer_button_rejects_empty_seat() {
+    let env = Env::default();
+    let (client, _player1, _player2) = setup_showdown(&env);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    assert_eq!(
+        client.try_set_dealer_button(&admin, &table(&env), &2),
+        Err(Ok(PokerError::PlayerNotFound.into()))
+    );
+}
+
+#[test]
+fn test_showdown_emits_participation_event_per_player() {
+    let env = Env::default();
+    let (client, player1, player2) = setup_showdown(&env);
+    resolve_with_ranks(&env, &client, 6, 2);
+
+    let mut played: Vec<(Address, (BytesN<32>, i128))> = Vec::new(&env);
+    for (_, topics, data) in env.events().all().iter() {
+        if Symbol::try_from_val(&env, &topics.get(0).unwrap()) == Ok(symbol_short!("played")) {
+            let who = Address::try_from_val(&env, &topics.get(1).unwrap()).unwrap();
+            played.push_back((who, <(BytesN<32>, i128)>::try_from_val(&env, &data).unwrap()));
+        }
+    }
+    assert_eq!(played.len(), 2);
+    assert_eq!(played.get(0).unwrap(), (player1, (table(&env), 200)));
+    assert_eq!(played.get(1).unwrap(), (player2, (table(&env), 0)));
+}
+
+#[test]
+fn test_board_streets_advance_in_order() {
+    let env = Env::default();
+    let (client, _player1, _player2) = setup_showdown(&env);
+
+    assert_eq!(client.try_reveal_turn(&table(&env), &5), Err(Ok(PokerError::WrongRound.into())));
+    client.reveal_flop(&table(&env), &(0, 14, 28));
+    assert_eq!(client.get_current_round(&table(&env)), BettingRound::Flop);
+    assert_eq!(client.try_reveal_flop(&table(&env), &(1, 2, 3)), Err(Ok(PokerError::WrongRound.into())));
+    assert_eq!(client.try_reveal_turn(&table(&env), &14), Err(Ok(PokerError::InvalidCard.into())));
+    assert_eq!(client.try_reveal_turn(&table(&env), &52), Err(Ok(PokerError::InvalidCard.into())));
+
+    client.reveal_turn(&table(&env), &40);
+    assert_eq!(client.get_current_round(&table(&env)), BettingRound::Turn);
+    client.reveal_river(&table(&env), &51);
+    assert_eq!(client.get_current_round(&table(&env)), BettingRound::River);
+
+    // The board is complete: no street can add a sixth card
+    assert_eq!(client.try_reveal_river(&table(&env), &7), Err(Ok(PokerError::WrongRound.into())));
+    assert_eq!(client.try_reveal_turn(&table(&env), &7), Err(Ok(PokerError::WrongRound.into())));
+    assert_eq!(client.get_game_state(&table(&env)).community_cards.len(), 5);
+}
+
+#[test]
+fn test_reveal_deck_checks_board_against_seed() {
+    let env = Env::default();
+    let (client, player1, player2) = setup_showdown(&env);
+    let seed = BytesN::from_array(&env, &[7u8; 32]);
+    let board = PokerGameContract::board_from_seed(&env, &seed);
+    client.commit_deck(&table(&env), &env.crypto().sha256(&Bytes::from_array(&env, &[7u8; 32])).into());
+
+    // Dealer swaps the river for a card the seed did not deal
+    let fake_river = (0..52u32).find(|c| !board.contains(c)).unwrap();
+    client.reveal_flop(&table(&env), &(board.get(0).unwrap(), board.get(1).unwrap(), board.get(2).unwrap()));
+    client.reveal_turn(&table(&env), &board.get(3).unwrap());
+    client.reveal_river(&table(&env), &fake_river);
+    resolve_with_ranks(&env, &client, 5, 3);
+    assert_eq!(client.try_reveal_deck(&table(&env), &seed), Err(Ok(PokerError::DeckSeedMismatch.into())));
+
+    // Next hand deals the seed's board honestly
+    client.init_game(&table(&env), &player1, &player2, &1000);
+    client.commit_deck(&table(&env), &env.crypto().sha256(&Bytes::from_array(&env, &[7u8; 32])).into());
+    client.reveal_flop(&table(&env), &(board.get(0).unwrap(), board.get(1).unwrap(), board.get(2).unwrap()));
+    client.reveal_turn(&table(&env), &board.get(3).unwrap());
+    client.reveal_river(&table(&env), &board.get(4).unwrap());
+    client.end_game(&table(&env), &player1);
+    client.reveal_deck(&table(&env), &seed);
+    assert_eq!(client.get_game_state(&table(&env)).deck_seed, seed);
+}
+
+#[test]
+fn test_fresh_deploy_reports_current_schema() {
+    let env = Env::default();
+    let (client, _player1, _player2) = setup_showdown(&env);
+
+    assert_eq!(client.version(), VERSION);
+    assert_eq!(client.get_schema_version(), VERSION);
+
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    assert_eq!(client.migrate(&admin), VERSION);
+}
+
+#[test]
+fn test_migrate_bumps_legacy_schema() {
+    let env = Env::default();
+    let (client, _player1, _player2) = setup_showdown(&env);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    // A deploy that started games before the schema marker existed
+    env.as_contract(&client.address, || {
+        env.storage().instance().remove(&symbol_short!("SCHEMA"));
+    });
+    assert_eq!(client.get_schema_version(), 1);
+
+    assert_eq!(client.migrate(&admin), VERSION);
+    assert_eq!(client.get_schema_version(), VERSION);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (symbol_short!("migrate"), 1u32).into_val(&env));
+    assert_eq!(u32::try_from_val(&env, &data).unwrap(), VERSION);
+}
+
+#[test]
+fn test_migrate_moves_legacy_game_state() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PokerGameContract);
+    let client = PokerGameContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    env.mock_all_auths();
+    client.set_admin(&admin);
+
+    // A schema-1 deploy: one table under the instance key "GAME_STATE"
+    let zero = BytesN::from_array(&env, &[0u8; 32]);
+    let mut players = Vec::new(&env);
+    for (address, chips, bet) in [(player1.clone(), 900, 100), (player2.clone(), 950, 50)] {
+        players.push_back(LegacyPlayer {
+            address,
+            chips,
+            commitment: zero.clone(),
+            current_bet: bet,
+            has_folded: false,
+        });
+    }
+    let legacy = LegacyGameState {
+        game_id: table(&env),
+        players,
+        pot: 150,
+        community_cards: Vec::new(&env),
+        current_round: BettingRound::Preflop,
+        dealer_button: 0,
+        current_player: 1,
+        is_active: true,
+        player1_proof_hash: zero.clone(),
+        player2_proof_hash: zero,
+        verifier_contract: verifier.clone(),
+    };
+    env.as_contract(&contract_id, || {
+        env.storage().instance().set(&"GAME_STATE", &legacy);
+    });
+    assert_eq!(client.get_schema_version(), 1);
+
+    assert_eq!(client.migrate(&admin), VERSION);
+    assert_eq!(client.get_schema_version(), VERSION);
+
+    // The table now lives under its game_id and the old key is gone
+    let state = client.get_game_state(&table(&env));
+    assert_eq!(state.pot, 150);
+    assert_eq!(state.chip_supply, 2000);
+    assert_eq!(state.current_player, 1);
+    assert_eq!(state.verifier_contract, Some(verifier));
+    assert_eq!(state.players.get(0).unwrap().address, player1);
+    assert_eq!(state.players.get(1).unwrap().current_bet, 50);
+    env.as_contract(&contract_id, || {
+        assert!(!env.storage().instance().has(&"GAME_STATE"));
+    });
+
+    // and play continues on it
+    client.place_bet(&table(&env), &player2, &50);
+    assert_eq!(client.get_game_state(&table(&env)).pot, 200);
+}
+
+#[test]
+fn test_get_game_states_in_order() {
+    let env = Env::default();
+    let (client, player1, player2) = setup_showdown(&env);
+    let other = BytesN::from_array(&env, &[2u8; 32]);
+    let unknown = BytesN::from_array(&env, &[3u8; 32]);
+    client.init_game(&other, &player2, &player1, &500);
+
+    let states = client.get_game_states(&Vec::from_array(&env, [other.clone(), table(&env)]), &false);
+    assert_eq!(states.len(), 2);
+    assert_eq!(states.get(0).unwrap().game_id, other);
+    assert_eq!(states.get(0).unwrap().players.get(0).unwrap().chips, 500);
+    assert_eq!(states.get(1).unwrap().game_id, table(&env));
+
+    // Unknown ids are skipped or rejected depending on the flag
+    let ids = Vec::from_array(&env, [table(&env), unknown, other.clone()]);
+    let states = client.get_game_states(&ids, &true);
+    assert_eq!(states.len(), 2);
+    assert_eq!(states.get(1).unwrap().game_id, other);
+    assert_eq!(client.try_get_game_states(&ids, &false).err(), Some(Ok(PokerError::GameNotFound.into())));
+}
+
+#[test]
+fn test_get_game_states_rejects_oversized_batch() {
+    let env = Env::default();
+    let (client, _player1, _player2) = setup_showdown(&env);
+
+    let mut ids = Vec::new(&env);
+    for _ in 0..MAX_GAME_BATCH {
+        ids.push_back(table(&env));
+    }
+    assert_eq!(client.get_game_states(&ids, &false).len(), MAX_GAME_BATCH);
+
+    ids.push_back(table(&env));
+    assert_eq!(
+        client.try_get_game_states(&ids, &false).err(),
+        Some(Ok(TableError::BatchTooLarge.into()))
+    );
+}
+
+#[test]
+fn test_heads_up_button_acts_first_preflop_last_postflop() {
+    for button in [0u32, 1] {
+        let env = Env::default();
+        let (client, _player1, _player2) = setup_showdown(&env);
+        let admin = Address::generate(&env);
+        client.set_admin(&admin);
+        client.set_dealer_button(&admin, &table(&env), &button);
+        assert_eq!(client.get_game_state(&table(&env)).current_player, button);
+
+        client.reveal_flop(&table(&env), &(0, 14, 28));
+        assert_eq!(client.get_game_state(&table(&env)).current_player, 1 - button);
+        client.reveal_turn(&table(&env), &40);
+        assert_eq!(client.get_game_state(&table(&env)).current_player, 1 - button);
+    }
+}
+
+#[test]
+fn test_multiway_showdown_rejected() {
+    let env = Env::default();
+    let (client, player1, player2) = setup_showdown(&env);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.force_reset(&admin, &table(&env));
+    let player3 = Address::generate(&env);
+    client.init_game_multi(&table(&env), &Vec::from_array(&env, [player1.clone(), player2.clone(), player3.clone()]), &1000);
+    client.place_bet(&table(&env), &player1, &100);
+    client.place_bet(&table(&env), &player2, &100);
+    client.place_bet(&table(&env), &player3, &100);
+    client.submit_commitment(&table(&env), &player1, &make_sha256_commitment(&env, [14, 13], [42u8; 32]));
+    client.submit_commitment(&table(&env), &player2, &make_sha256_commitment(&env, [7, 8], [99u8; 32]));
+
+    let proof = BytesN::from_array(&env, &[1u8; 128]);
+    let result = client.try_resolve_showdown(
+        &table(&env),
+        &proof, &5, &Bytes::from_array(&env, &[14u8, 13u8]), &BytesN::from_array(&env, &[42u8; 32]),
+        &proof, &3, &Bytes::from_array(&env, &[7u8, 8u8]), &BytesN::from_array(&env, &[99u8; 32]),
+    );
+    assert_eq!(result.err(), Some(Ok(PokerError::InvalidPlayerCount.into())));
+    assert_eq!(client.get_pot(&table(&env)), 300);
+}
+
+#[test]
+fn test_multiway_postflop_starts_left_of_button() {
+    let env = Env::default();
+    let (client, _player1, _player2) = setup_showdown(&env);
+    let mut seats = Vec::new(&env);
+    for _ in 0..4 {
+        seats.push_back(Address::generate(&env));
+    }
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.force_reset(&admin, &table(&env));
+    client.init_game_multi(&table(&env), &seats, &1000);
+
+    // Button on seat 0: seat 3 sits left of the big blind, seat 1 left of the button
+    assert_eq!(client.get_game_state(&table(&env)).current_player, 3);
+    client.fold(&table(&env), &seats.get(1).unwrap());
+    client.reveal_flop(&table(&env), &(0, 14, 28));
+    assert_eq!(client.get_game_state(&table(&env)).current_player, 2);
+}
+
+#[test]
+fn test_get_proof_hashes_zero_until_showdown() {
+    let env = Env::default();
+    let (client, _player1, _player2) = setup_showdown(&env);
+    let zero = BytesN::from_array(&env, &[0u8; 32]);
+    assert_eq!(client.get_proof_hashes(&table(&env)), (zero.clone(), zero));
+
+    let proof1 = BytesN::from_array(&env, &[3u8; 128]);
+    let proof2 = BytesN::from_array(&env, &[4u8; 128]);
+    client.resolve_showdown(
+        &table(&env),
+        &proof1, &5, &Bytes::from_array(&env, &[14u8, 13u8]), &BytesN::from_array(&env, &[42u8; 32]),
+        &proof2, &3, &Bytes::from_array(&env, &[7u8, 8u8]), &BytesN::from_array(&env, &[99u8; 32]),
+    );
+    assert_eq!(
+        client.get_proof_hashes(&table(&env)),
+        (BytesN::from_array(&env, &[3u8; 32]), BytesN::from_array(&env, &[4u8; 32]))
+    );
+}
+
+#[test]
+fn test_chip_decimals_scale_deposit_and_withdraw() {
+    let env = Env::default();
+    let (client, tok, player1, _player2) = setup_with_token(&env);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.set_chip_decimals(&admin, &2);
+
+    assert_eq!(client.chips_to_token(&7), 700);
+    assert_eq!(client.token_to_chips(&client.chips_to_token(&7)), 7);
+
+    // 300 token units buy 3 chips on top of the 1-chip opening stack
+    client.deposit(&table(&env), &player1, &300);
+    assert_eq!(client.get_game_state(&table(&env)).players.get(0).unwrap().chips, 4);
+    client.end_game(&table(&env), &player1);
+    client.withdraw(&table(&env), &player1, &100);
+    assert_eq!(client.get_game_state(&table(&env)).players.get(0).unwrap().chips, 3);
+    assert_eq!(tok.balance(&player1), 300);
+    assert_eq!(tok.balance(&client.address), 200);
+}
+
+#[test]
+fn test_chip_decimals_reject_partial_chips() {
+    let env = Env::default();
+    let (client, _tok, player1, _player2) = setup_with_token(&env);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.set_chip_decimals(&admin, &2);
+
+    assert_eq!(client.try_token_to_chips(&250), Err(Ok(PokerError::InvalidAmount.into())));
+    assert_eq!(client.try_deposit(&table(&env), &player1, &250), Err(Ok(PokerError::InvalidAmount.into())));
+    assert_eq!(client.try_set_chip_decimals(&admin, &19), Err(Ok(PokerError::InvalidAmount.into())));
+}
+
+#[test]
+fn test_raise_must_match_previous_raise() {
+    let env = Env::default();
+    let (client, player1, player2) = setup_showdown(&env);
+
+    // Both in for 100; player 1 raises by 150
+    client.place_bet(&table(&env), &player1, &150);
+
+    // Calling 150 and raising only 100 more is short of the 150 increment
+    assert_eq!(client.try_place_bet(&table(&env), &player2, &250), Err(Ok(PokerError::BetBelowMinimum.into())));
+
+    // A re-raise of exactly 150 is the legal minimum
+    client.place_bet(&table(&env), &player2, &300);
+    assert_eq!(client.get_pot(&table(&env)), 650);
+}
+
+#[test]
+fn test_all_in_below_raise_increment_allowed() {
+    let env = Env::default();
+    let (client, player1, player2) = setup_showdown(&env);
+
+    // Player 1 raises by 700; player 2 shoves 900, a raise of only 200
+    client.place_bet(&table(&env), &player1, &700);
+    client.place_bet(&table(&env), &player2, &900);
+    let state = client.get_game_state(&table(&env));
+    assert!(state.players.get(1).unwrap().all_in);
+    assert_eq!(state.pot, 1800);
+}
+
+#[test]
+fn test_init_game_rejects_active_table() {
+    let env = Env::default();
+    let (client, player1, player2) = setup_showdown(&env);
+
+    // Hand in progress with 200 in the pot
+    assert_eq!(
+        client.try_init_game(&table(&env), &player1, &player2, &1000).err(),
+        Some(Ok(PokerError::HandInProgress.into()))
+    );
+    assert_eq!(client.get_pot(&table(&env)), 200);
+
+    // Once resolved, the table can be dealt again
+    resolve_with_ranks(&env, &client, 5, 3);
+    let state = client.init_game(&table(&env), &player1, &player2, &1000);
+    assert!(state.is_active);
+    assert_eq!(state.pot, 0);
+}
+
+#[test]
+fn test_force_reset_clears_active_table() {
+    let env = Env::default();
+    let (client, player1, player2) = setup_showdown(&env);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    client.force_reset(&admin, &table(&env));
+    assert!(client.try_get_game_state(&table(&env)).is_err());
+    assert_eq!(client.init_game(&table(&env), &player1, &player2, &500).pot, 0);
+}
+
+#[test]
+fn test_shuffle_seed_combines_both_reveals() {
+    let env = Env::default();
+    let (client, player1, player2) = setup_showdown(&env);
+    let seed1 = BytesN::from_array(&env, &[0x11; 32]);
+    let seed2 = BytesN::from_array(&env, &[0x22; 32]);
+    let commit = |seed: &BytesN<32>| -> BytesN<32> { env.crypto().sha256(&seed.clone().into()).into() };
+
+    client.submit_seed_commitment(&table(&env), &player1, &commit(&seed1));
+    // Nobody reveals until every seat has committed
+    assert_eq!(client.try_reveal_seed(&table(&env), &player1, &seed1), Err(Ok(PokerError::DeckNotCommitted.into())));
+    client.submit_seed_commitment(&table(&env), &player2, &commit(&seed2));
+
+    client.reveal_seed(&table(&env), &player1, &seed1);
+    assert_eq!(client.try_compute_shuffle_seed(&table(&env)).err(), Some(Ok(PokerError::NotRevealed.into())));
+    client.reveal_seed(&table(&env), &player2, &seed2);
+
+    let mut both = Bytes::from_array(&env, &[0x11; 32]);
+    both.append(&Bytes::from_array(&env, &[0x22; 32]));
+    let expected: BytesN<32> = env.crypto().sha256(&both).into();
+    assert_eq!(client.compute_shuffle_seed(&table(&env)), expected);
+}
+
+#[test]
+fn test_reveal_seed_rejects_mismatch() {
+    let env = Env::default();
+    let (client, player1, player2) = setup_showdown(&env);
+    let commit = |byte: u8| -> BytesN<32> { env.crypto().sha256(&Bytes::from_array(&env, &[byte; 32])).into() };
+
+    client.submit_seed_commitment(&table(&env), &player1, &commit(0x11));
+    client.submit_seed_commitment(&table(&env), &player2, &commit(0x22));
+    assert_eq!(
+        client.try_submit_seed_commitment(&table(&env), &player1, &commit(0x33)),
+        Err(Ok(PokerError::DeckAlreadyCommitted.into()))
+    );
+    assert_eq!(
+        client.try_reveal_seed(&table(&env), &player1, &BytesN::from_array(&env, &[0x22; 32])),
+        Err(Ok(PokerError::DeckSeedMismatch.into()))
+    );
+}
+
+#[test]
+fn test_reclaim_refunds_abandoned_hand() {
+    let env = Env::default();
+    let (client, tok, player1, player2) = setup_with_token(&env);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.set_abandon_window(&admin, &100);
+
+    // Both fund 300 and bet 100; then the table goes silent
+    client.deposit(&table(&env), &player1, &299);
+    client.deposit(&table(&env), &player2, &299);
+    client.place_bet(&table(&env), &player1, &100);
+    client.place_bet(&table(&env), &player2, &100);
+    env.ledger().set_sequence_number(env.ledger().sequence() + 101);
+
+    client.reclaim(&table(&env));
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (symbol_short!("refunded"), player2.clone()).into_val(&env));
+    assert_eq!(i128::try_from_val(&env, &data).unwrap(), 300);
+
+    let state = client.get_game_state(&table(&env));
+    assert!(!state.is_active);
+    assert_eq!(state.pot, 0);
+    assert_eq!(state.players.get(0).unwrap().chips, 300);
+    assert_eq!(state.players.get(1).unwrap().chips, 300);
+    assert!(client.check_chip_invariant(&table(&env)));
+
+    // The deposits can now be withdrawn in full
+    client.withdraw(&table(&env), &player1, &299);
+    client.withdraw(&table(&env), &player2, &299);
+    assert_eq!(tok.balance(&player1), 500);
+    assert_eq!(tok.balance(&player2), 500);
+}
+
+#[test]
+fn test_reclaim_rejected_inside_window() {
+    let env = Env::default();
+    let (client, player1, _player2) = setup_showdown(&env);
+    assert_eq!(client.try_reclaim(&table(&env)), Err(Ok(PokerError::TimeoutNotConfigured.into())));
+
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.set_abandon_window(&admin, &100);
+    env.ledger().set_sequence_number(env.ledger().sequence() + 100);
+    assert_eq!(client.try_reclaim(&table(&env)), Err(Ok(PokerError::TimeoutNotReached.into())));
+
+    // Any action restarts the window
+    env.ledger().set_sequence_number(env.ledger().sequence() + 1);
+    client.place_bet(&table(&env), &player1, &100);
+    assert_eq!(client.try_reclaim(&table(&env)), Err(Ok(PokerError::TimeoutNotReached.into())));
+}
+
+#[test]
+fn test_reclaim_with_max_window_never_fires() {
+    let env = Env::default();
+    let (client, player1, _player2) = setup_showdown(&env);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.set_abandon_window(&admin, &u32::MAX);
+    env.ledger().set_sequence_number(1_000);
+    client.place_bet(&table(&env), &player1, &100);
+
+    // last action + window would overflow u32
+    env.ledger().set_sequence_number(2_000);
+    assert_eq!(client.try_reclaim(&table(&env)), Err(Ok(PokerError::TimeoutNotReached.into())));
+}
+
+#[test]
+fn test_whose_turn_follows_action() {
+    let env = Env::default();
+    let (client, player1, player2) = setup_showdown(&env);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.force_reset(&admin, &table(&env));
+    let player3 = Address::generate(&env);
+    client.init_game_multi(&table(&env), &Vec::from_array(&env, [player1.clone(), player2.clone(), player3.clone()]), &1000);
+
+    assert_eq!(client.whose_turn(&table(&env)), player1);
+    assert!(client.is_player_turn(&table(&env), &player1));
+    assert!(!client.is_player_turn(&table(&env), &player2));
+
+    client.place_bet(&table(&env), &player1, &100);
+    assert!(client.is_player_turn(&table(&env), &player2));
+    assert!(!client.is_player_turn(&table(&env), &player1));
+
+    // Folding in turn passes the action to the next seat
+    client.fold(&table(&env), &player2);
+    assert_eq!(client.get_current_player(&table(&env)), player3);
+    assert_eq!(client.whose_turn(&table(&env)), player3);
+    assert!(!client.is_player_turn(&table(&env), &player2));
+}
+
+#[test]
+fn test_fold_in_turn_lets_next_seat_time_out() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PokerGameContract);
+    let client = PokerGameContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let players = [Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+    env.mock_all_auths();
+
+    client.set_admin(&admin);
+    client.set_action_timeout(&admin, &10);
+    client.init_game_multi(&table(&env), &Vec::from_array(&env, players.clone()), &1000);
+    client.place_bet(&table(&env), &players[0], &100);
+    client.fold(&table(&env), &players[1]);
+    assert_eq!(client.get_current_player(&table(&env)), players[2]);
+
+    // The seat after the folder stalls and can be timed out
+    env.ledger().set_sequence_number(env.ledger().sequence() + 11);
+    client.force_timeout_fold(&table(&env), &players[2]);
+    let state = client.get_game_state(&table(&env));
+    assert!(!state.is_active);
+    assert_eq!(state.players.get(0).unwrap().chips, 1000);
+
+    // Folding needs a hand in progress
+    assert_eq!(client.try_fold(&table(&env), &players[0]), Err(Ok(PokerError::NotActive.into())));
+}
+
+#[test]
+fn test_timeout_after_voluntary_fold_skips_folded_seat() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PokerGameContract);
+    let client = PokerGameContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let players = [Address::generate(&env), Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+    env.mock_all_auths();
+
+    client.set_admin(&admin);
+    client.set_action_timeout(&admin, &10);
+    client.init_game_multi(&table(&env), &Vec::from_array(&env, players.clone()), &1000);
+
+    // Seat 3 opens preflop; seat 0 folds out of turn and is passed by
+    assert_eq!(client.get_current_player(&table(&env)), players[3]);
+    client.fold(&table(&env), &players[0]);
+    client.place_bet(&table(&env), &players[3], &100);
+    assert_eq!(client.get_current_player(&table(&env)), players[1]);
+
+    // A folded seat cannot be timed out, the seat to act can
+    env.ledger().set_sequence_number(env.ledger().sequence() + 11);
+    assert_eq!(
+        client.try_force_timeout_fold(&table(&env), &players[0]),
+        Err(Ok(PokerError::NotPlayersTurn.into()))
+    );
+    client.force_timeout_fold(&table(&env), &players[1]);
+
+    let state = client.get_game_state(&table(&env));
+    assert!(state.is_active);
+    assert_eq!(client.get_current_player(&table(&env)), players[2]);
+}
+
+#[test]
+fn test_rank_tie_on_full_board_goes_to_better_kicker() {
+    let env = Env::default();
+    let verifier_id = env.register_contract(None, test_helpers::kicker::KickerVerifier);
+    let contract_id = env.register_contract(None, PokerGameContract);
+    let client = PokerGameContractClient::new(&env, &contract_id);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    env.mock_all_auths();
+
+    client.set_verifier(&verifier_id);
+    client.init_game(&table(&env), &player1, &player2, &1000);
+    client.place_bet(&table(&env), &player1, &100);
+    client.place_bet(&table(&env), &player2, &100);
+    client.submit_commitment(&table(&env), &player1, &make_sha256_commitment(&env, [14, 13], [42u8; 32]));
+    client.submit_commitment(&table(&env), &player2, &make_sha256_commitment(&env, [7, 8], [99u8; 32]));
+
+    // Both make the same rank; the verifier ranks player 2 higher on kickers
+    client.reveal_flop(&table(&env), &(0, 27, 28));
+    client.reveal_turn(&table(&env), &40);
+    client.reveal_river(&table(&env), &51);
+
+    assert_eq!(resolve_with_ranks(&env, &client, 5, 5), player2);
+    let state = client.get_game_state(&table(&env));
+    assert_eq!(state.players.get(0).unwrap().chips, 900);
+    assert_eq!(state.players.get(1).unwrap().chips, 1100);
+}
+
+#[test]
+fn test_resolve_showdown_rejects_claim_above_cap() {
+    let env = Env::default();
+    let (client, player1, _player2) = setup_showdown(&env);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    assert_eq!(client.get_max_claimable_rank(), 9);
+    assert_eq!(client.try_set_max_claimable_rank(&admin, &10), Err(Ok(PokerError::InvalidRank.into())));
+    client.set_max_claimable_rank(&admin, &8);
+
+    let proof = BytesN::from_array(&env, &[1u8; 128]);
+    let result = client.try_resolve_showdown(
+        &table(&env),
+        &proof, &9,
+        &Bytes::from_array(&env, &[14u8, 13u8]),
+        &BytesN::from_array(&env, &[42u8; 32]),
+        &proof, &3,
+        &Bytes::from_array(&env, &[7u8, 8u8]),
+        &BytesN::from_array(&env, &[99u8; 32]),
+    );
+    assert_eq!(result, Err(Ok(PokerError::InvalidRank.into())));
+
+    // A claim at the cap still resolves
+    assert_eq!(resolve_with_ranks(&env, &client, 8, 3), player1);
+}
+
+#[test]
+fn test_claim_above_flag_rank_is_flagged_not_blocked() {
+    let env = Env::default();
+    let (client, player1, _player2) = setup_showdown(&env);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.set_flag_rank(&admin, &7);
+    assert_eq!(client.get_flag_rank(), Some(7));
+
+    assert_eq!(resolve_with_ranks(&env, &client, 9, 7), player1);
+
+    // Only the claim above the threshold is flagged; the 7 is not
+    let mut flagged: Vec<(Address, (BytesN<32>, u32))> = Vec::new(&env);
+    for (_, topics, data) in env.events().all().iter() {
+        if Symbol::try_from_val(&env, &topics.get(0).unwrap()) == Ok(symbol_short!("flagged")) {
+            let who = Address::try_from_val(&env, &topics.get(1).unwrap()).unwrap();
+            flagged.push_back((who, <(BytesN<32>, u32)>::try_from_val(&env, &data).unwrap()));
+        }
+    }
+    assert_eq!(flagged.len(), 1);
+    assert_eq!(flagged.get(0).unwrap(), (player1, (table(&env), 9)));
+}
+
+#[test]
+fn test_fixed_limit_bets_follow_the_street() {
+    let env = Env::default();
+    let (client, player1, player2) = setup_showdown(&env);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    let limits = LimitStructure { small_bet: 20, big_bet: 40 };
+    assert_eq!(
+        client.try_set_limit_structure(&admin, &Some(LimitStructure { small_bet: 40, big_bet: 20 })),
+        Err(Ok(PokerError::InvalidBetLimits.into()))
+    );
+    client.set_limit_structure(&admin, &Some(limits.clone()));
+    assert_eq!(client.get_limit_structure(), Some(limits));
+    client.force_reset(&admin, &table(&env));
+    client.init_game(&table(&env), &player1, &player2, &1000);
+
+    // Preflop and flop raise by the small bet
+    client.place_bet(&table(&env), &player1, &20);
+    client.place_bet(&table(&env), &player2, &20);
+    client.reveal_flop(&table(&env), &(0, 14, 28));
+    assert_eq!(client.try_place_bet(&table(&env), &player1, &40), Err(Ok(PokerError::BetAboveLimit.into())));
+    client.place_bet(&table(&env), &player1, &20);
+    client.place_bet(&table(&env), &player2, &20);
+
+    // Turn bets are the big bet; anything else is off-size
+    client.reveal_turn(&table(&env), &40);
+    assert_eq!(client.try_place_bet(&table(&env), &player1, &30), Err(Ok(PokerError::BetBelowMinimum.into())));
+    assert_eq!(client.try_place_bet(&table(&env), &player1, &60), Err(Ok(PokerError::BetAboveLimit.into())));
+    client.place_bet(&table(&env), &player1, &40);
+    assert_eq!(client.get_game_state(&table(&env)).pot, 120);
+}
+
+/// Helper: a heads-up showdown against `SkepticalVerifier`, with the
+/// given admin configured.
+fn setup_skeptical_showdown(env: &Env, admin: &Address) -> (PokerGameContractClient<'static>, Address, Address) {
+    let verifier_id = env.register_contract(None, test_helpers::skeptical::SkepticalVerifier);
+    let contract_id = env.register_contract(None, PokerGameContract);
+    let client = PokerGameContractClient::new(env, &contract_id);
+    let player1 = Address::generate(env);
+    let player2 = Address::generate(env);
+    env.mock_all_auths();
+
+    client.set_admin(admin);
+    client.set_verifier(&verifier_id);
+    client.init_game(&table(env), &player1, &player2, &1000);
+    client.place_bet(&table(env), &player1, &100);
+    client.place_bet(&table(env), &player2, &100);
+    client.submit_commitment(&table(env), &player1, &make_sha256_commitment(env, [14, 13], [42u8; 32]));
+    client.submit_commitment(&table(env), &player2, &make_sha256_commitment(env, [7, 8], [99u8; 32]));
+    (client, player1, player2)
+}
+
+#[test]
+fn test_failed_proof_reverts_showdown_by_default() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let (client, _player1, _player2) = setup_skeptical_showdown(&env, &admin);
+    assert!(!client.is_disqualify_on_fail());
+
+    let proof = BytesN::from_array(&env, &[1u8; 128]);
+    let result = client.try_resolve_showdown(
+        &table(&env),
+        &proof, &9,
+        &Bytes::from_array(&env, &[14u8, 13u8]),
+        &BytesN::from_array(&env, &[42u8; 32]),
+        &proof, &3,
+        &Bytes::from_array(&env, &[7u8, 8u8]),
+        &BytesN::from_array(&env, &[99u8; 32]),
+    );
+    assert!(result.is_err());
+    assert!(client.get_game_state(&table(&env)).is_active);
+}
+
+#[test]
+fn test_failed_proof_disqualifies_when_enabled() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let (client, player1, player2) = setup_skeptical_showdown(&env, &admin);
+    client.set_disqualify_on_fail(&admin, &true);
+
+    // Player 1's royal flush claim fails: player 2 wins by disqualification
+    assert_eq!(resolve_with_ranks(&env, &client, 9, 3), player2);
+    let state = client.get_game_state(&table(&env));
+    assert_eq!(state.players.get(0).unwrap().chips, 900);
+    assert_eq!(state.players.get(1).unwrap().chips, 1100);
+
+    let mut dq: Vec<(Address, BytesN<32>)> = Vec::new(&env);
+    for (_, topics, data) in env.events().all().iter() {
+        if Symbol::try_from_val(&env, &topics.get(0).unwrap()) == Ok(symbol_short!("dq")) {
+            let who = Address::try_from_val(&env, &topics.get(1).unwrap()).unwrap();
+            dq.push_back((who, BytesN::<32>::try_from_val(&env, &data).unwrap()));
+        }
+    }
+    assert_eq!(dq.len(), 1);
+    assert_eq!(dq.get(0).unwrap(), (player1, table(&env)));
+}
+
+#[test]
+fn test_verifier_fault_reverts_even_when_disqualifying() {
+    let env = Env::default();
+    let verifier_id = env.register_contract(None, test_helpers::consumed::ConsumedVerifier);
+    let contract_id = env.register_contract(None, PokerGameContract);
+    let client = PokerGameContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    env.mock_all_auths();
+
+    client.set_admin(&admin);
+    client.set_verifier(&verifier_id);
+    client.set_disqualify_on_fail(&admin, &true);
+    client.init_game(&table(&env), &player1, &player2, &1000);
+    client.place_bet(&table(&env), &player1, &100);
+    client.place_bet(&table(&env), &player2, &100);
+    client.submit_commitment(&table(&env), &player1, &make_sha256_commitment(&env, [14, 13], [42u8; 32]));
+    client.submit_commitment(&table(&env), &player2, &make_sha256_commitment(&env, [7, 8], [99u8; 32]));
+
+    // A proof burnt by someone else is not the player's failure
+    let proof = BytesN::from_array(&env, &[1u8; 128]);
+    let result = client.try_resolve_showdown(
+        &table(&env),
+        &proof, &5, &Bytes::from_array(&env, &[14u8, 13u8]), &BytesN::from_array(&env, &[42u8; 32]),
+        &proof, &3, &Bytes::from_array(&env, &[7u8, 8u8]), &BytesN::from_array(&env, &[99u8; 32]),
+    );
+    assert_eq!(result.err(), Some(Ok(TableError::VerifierFault.into())));
+    assert!(client.get_game_state(&table(&env)).is_active);
+}
+
+#[test]
+fn test_log_level_gates_debug_logs() {
+    let env = Env::default();
+    let (client, player1, player2) = setup_showdown(&env);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    assert_eq!(client.get_log_level(), 2);
+    let count = |needle: &str| env.logs().all().iter().filter(|line| line.contains(needle)).count();
+
+    // Quiet: neither summaries nor the showdown trace are logged
+    client.set_log_level(&admin, &0);
+    let commits = count("submit_commitment");
+    client.submit_commitment(&table(&env), &player1, &make_sha256_commitment(&env, [14, 13], [42u8; 32]));
+    assert_eq!(count("submit_commitment"), commits);
+
+    // Info: summaries come back, the step-by-step trace stays off
+    client.set_log_level(&admin, &1);
+    client.submit_commitment(&table(&env), &player2, &make_sha256_commitment(&env, [7, 8], [99u8; 32]));
+    assert_eq!(count("submit_commitment"), commits + 1);
+    resolve_with_ranks(&env, &client, 5, 3);
+    assert_eq!(count("[1/6]"), 0);
+    assert_eq!(count("resolve_showdown: winner"), 1);
+}
+
+#[test]
+fn test_unknown_player_is_rejected_everywhere() {
+    let env = Env::default();
+    let (client, player1, player2) = setup_showdown(&env);
+    let stranger = Address::generate(&env);
+    let not_found = Some(Ok(PokerError::PlayerNotFound.into()));
+
+    assert_eq!(client.player_index(&table(&env), &player2), 1);
+    assert_eq!(client.player_address(&table(&env), &0), player1);
+    assert_eq!(client.try_player_index(&table(&env), &stranger).err(), not_found);
+    assert_eq!(client.try_player_address(&table(&env), &2).err(), not_found);
+
+    let before = client.get_game_state(&table(&env));
+    let commitment = make_sha256_commitment(&env, [1, 2], [3u8; 32]);
+    assert_eq!(client.try_submit_commitment(&table(&env), &stranger, &commitment).err(), not_found);
+    assert_eq!(client.try_place_bet(&table(&env), &stranger, &100).err(), not_found);
+    assert_eq!(client.try_fold(&table(&env), &stranger).err(), not_found);
+    assert_eq!(client.try_end_game(&table(&env), &stranger).err(), not_found);
+
+    // None of the rejected calls touched the table or moved the turn
+    let after = client.get_game_state(&table(&env));
+    assert_eq!(after.pot, before.pot);
+    assert_eq!(after.current_player, before.current_player);
+    assert!(after.is_active);
+}
+
+#[test]
+fn test_simulate_showdown_matches_resolution() {
+    for (r1, r2) in [(7, 2), (1, 8), (4, 4), (0, 0), (9, 9)] {
+        let env = Env::default();
+        let (client, player1, player2) = setup_showdown(&env);
+
+        let predicted = client.simulate_showdown(&table(&env), &r1, &r2);
+        // Simulating leaves the table untouched
+        assert!(client.get_game_state(&table(&env)).is_active);
+        assert_eq!(client.get_game_state(&table(&env)).pot, 200);
+
+        let winner = resolve_with_ranks(&env, &client, r1, r2);
+        let state = client.get_game_state(&table(&env));
+        let (chips1, chips2) = (state.players.get(0).unwrap().chips, state.players.get(1).unwrap().chips);
+        match predicted {
+            Winner::Player1 => assert!(winner == player1 && chips1 == 1100),
+            Winner::Player2 => assert!(winner == player2 && chips2 == 1100),
+            Winner::Tie => assert!(chips1 == 1000 && chips2 == 1000),
+        }
+    }
+}
+
+#[test]
+fn test_simulate_showdown_awards_folded_pot_and_checks_ranks() {
+    let env = Env::default();
+    let (client, _player1, player2) = setup_showdown(&env);
+    assert_eq!(
+        client.try_simulate_showdown(&table(&env), &10, &3),
+        Err(Ok(PokerError::InvalidRank.into()))
+    );
+
+    client.fold(&table(&env), &player2);
+    assert_eq!(client.simulate_showdown(&table(&env), &0, &9), Winner::Player1);
+}
+
+/// Helper: `ShowdownInput` for a table dealt like `setup_showdown`.
+fn showdown_input(env: &Env, game_id: &BytesN<32>, p1_rank: u32, p2_rank: u32) -> ShowdownInput {
+    let proof = BytesN::from_array(env, &[1u8; 128]);
+    ShowdownInput {
+        game_id: game_id.clone(),
+        player1_proof: proof.clone(),
+        player1_rank: p1_rank,
+        player1_cards: Bytes::from_array(env, &[14u8, 13u8]),
+        player1_salt: BytesN::from_array(env, &[42u8; 32]),
+        player2_proof: proof,
+        player2_rank: p2_rank,
+        player2_cards: Bytes::from_array(env, &[7u8, 8u8]),
+        player2_salt: BytesN::from_array(env, &[99u8; 32]),
+    }
+}
+
+#[test]
+fn test_resolve_showdowns_batch_settles_each_table() {
+    let env = Env::default();
+    env.budget().reset_unlimited();
+    let (client, player1, _player2) = setup_showdown(&env);
+
+    // A second table with its own players and a bigger pot
+    let table2 = BytesN::from_array(&env, &[2u8; 32]);
+    let player3 = Address::generate(&env);
+    let player4 = Address::generate(&env);
+    client.init_game(&table2, &player3, &player4, &1000);
+    client.place_bet(&table2, &player3, &300);
+    client.place_bet(&table2, &player4, &300);
+    client.submit_commitment(&table2, &player3, &make_sha256_commitment(&env, [14, 13], [42u8; 32]));
+    client.submit_commitment(&table2, &player4, &make_sha256_commitment(&env, [7, 8], [99u8; 32]));
+
+    let batch = Vec::from_array(&env, [
+        showdown_input(&env, &table(&env), 6, 2),
+        showdown_input(&env, &table2, 1, 4),
+    ]);
+    let winners = client.resolve_showdowns_batch(&batch);
+    assert_eq!(winners, Vec::from_array(&env, [player1, player4]));
+
+    let first = client.get_game_state(&table(&env));
+    assert_eq!(first.players.get(0).unwrap().chips, 1100);
+    assert_eq!(first.players.get(1).unwrap().chips, 900);
+    let second = client.get_game_state(&table2);
+    assert_eq!(second.players.get(0).unwrap().chips, 700);
+    assert_eq!(second.players.get(1).unwrap().chips, 1300);
+}
+
+#[test]
+fn test_resolve_showdowns_batch_is_bounded() {
+    let env = Env::default();
+    let (client, _player1, _player2) = setup_showdown(&env);
+    let mut batch = Vec::new(&env);
+    for _ in 0..5 {
+        batch.push_back(showdown_input(&env, &table(&env), 6, 2));
+    }
+    assert_eq!(client.try_resolve_showdowns_batch(&batch), Err(Ok(TableError::BatchTooLarge.into())));
+}
+
+/// Helper: `cards || salt` encrypted under `key` as `submit_encrypted_cards` expects.
+fn encrypt_cards(env: &Env, key: [u8; 32], cards: &[u8], salt: [u8; 32]) -> Bytes {
+    let mut plain = Bytes::from_slice(env, cards);
+    plain.extend_from_array(&salt);
+    let mut out = Bytes::new(env);
+    for block in 0..plain.len().div_ceil(32) {
+        let mut seed = Bytes::from_array(env, &key);
+        seed.extend_from_array(&block.to_be_bytes());
+        let pad = env.crypto().sha256(&seed).to_array();
+        for (i, byte) in pad.iter().enumerate() {
+            let at = block * 32 + i as u32;
+            if at < plain.len() {
+                out.push_back(plain.get(at).unwrap() ^ byte);
+            }
+        }
+    }
+    out
+}
+
+#[test]
+fn test_encrypted_cards_stored_and_revealed_after_hand() {
+    let env = Env::default();
+    let (client, player1, _player2) = setup_showdown(&env);
+    let ciphertext = encrypt_cards(&env, [7u8; 32], &[14, 13], [42u8; 32]);
+    client.submit_encrypted_cards(&table(&env), &player1, &ciphertext);
+
+    let stored = client.get_encrypted_cards(&table(&env), &player1).unwrap();
+    assert_eq!(stored.ciphertext, ciphertext);
+    assert_eq!(stored.commitment, make_sha256_commitment(&env, [14, 13], [42u8; 32]));
+    assert_eq!(
+        client.try_reveal_encrypted_cards(&table(&env), &player1, &BytesN::from_array(&env, &[7u8; 32])).err(),
+        Some(Ok(PokerError::HandInProgress.into()))
+    );
+
+    resolve_with_ranks(&env, &client, 6, 2);
+    let cards = client.reveal_encrypted_cards(&table(&env), &player1, &BytesN::from_array(&env, &[7u8; 32]));
+    assert_eq!(cards, Bytes::from_array(&env, &[14, 13]));
+}
+
+#[test]
+fn test_encrypted_cards_follow_table_hole_cards() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let verifier_id = env.register_contract(None, test_helpers::MockNoirVerifier);
+    let contract_id = env.register_contract(None, PokerGameContract);
+    let client = PokerGameContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+
+    client.set_admin(&admin);
+    client.set_verifier(&verifier_id);
+    client.set_hole_cards(&admin, &4);
+    client.init_game(&table(&env), &player1, &player2, &1000);
+    client.place_bet(&table(&env), &player1, &100);
+    client.place_bet(&table(&env), &player2, &100);
+
+    let omaha = [14u8, 13, 40, 41];
+    let mut preimage = Bytes::from_array(&env, &omaha);
+    preimage.extend_from_array(&[42u8; 32]);
+    client.submit_commitment(&table(&env), &player1, &env.crypto().sha256(&preimage).into());
+    client.submit_commitment(&table(&env), &player2, &BytesN::from_array(&env, &[4u8; 32]));
+
+    // A Hold'em-sized ciphertext does not fit an Omaha table
+    assert_eq!(
+        client.try_submit_encrypted_cards(&table(&env), &player1, &encrypt_cards(&env, [7u8; 32], &[14, 13], [42u8; 32])),
+        Err(Ok(PokerError::WrongHoleCardCount.into()))
+    );
+    client.submit_encrypted_cards(&table(&env), &player1, &encrypt_cards(&env, [7u8; 32], &omaha, [42u8; 32]));
+
+    let proof = BytesN::from_array(&env, &[1u8; 128]);
+    client.resolve_showdown(
+        &table(&env),
+        &proof, &6, &Bytes::from_array(&env, &omaha), &BytesN::from_array(&env, &[42u8; 32]),
+        &proof, &2, &Bytes::from_array(&env, &[7u8, 8, 20, 21]), &BytesN::from_array(&env, &[99u8; 32]),
+    );
+    let cards = client.reveal_encrypted_cards(&table(&env), &player1, &BytesN::from_array(&env, &[7u8; 32]));
+    assert_eq!(cards, Bytes::from_array(&env, &omaha));
+}
+
+#[test]
+fn test_encrypted_cards_reject_wrong_reveal_key() {
+    let env = Env::default();
+    let (client, player1, player2) = setup_showdown(&env);
+    client.submit_encrypted_cards(&table(&env), &player1, &encrypt_cards(&env, [7u8; 32], &[14, 13], [42u8; 32]));
+    // Encrypted under the right key, but not the committed cards
+    client.submit_encrypted_cards(&table(&env), &player2, &encrypt_cards(&env, [8u8; 32], &[2, 3], [99u8; 32]));
+    assert_eq!(
+        client.try_submit_encrypted_cards(&table(&env), &player1, &Bytes::from_array(&env, &[1u8; 8])),
+        Err(Ok(PokerError::WrongHoleCardCount.into()))
+    );
+    resolve_with_ranks(&env, &client, 6, 2);
+
+    assert_eq!(
+        client.try_reveal_encrypted_cards(&table(&env), &player1, &BytesN::from_array(&env, &[8u8; 32])).err(),
+        Some(Ok(PokerError::CardsMismatch.into()))
+    );
+    assert_eq!(
+        client.try_reveal_encrypted_cards(&table(&env), &player2, &BytesN::from_array(&env, &[8u8; 32])).err(),
+        Some(Ok(PokerError::CardsMismatch.into()))
+    );
+}
+
+#[test]
+fn test_table_rules_reflect_configuration() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PokerGameContract);
+    let client = PokerGameContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    env.mock_all_auths();
+    client.set_admin(&admin);
+
+    let defaults = client.get_table_rules();
+    assert_eq!(defaults.max_bet, i128::MAX);
+    assert_eq!((defaults.small_bet, defaults.big_bet), (0, 0));
+    assert_eq!(defaults.rake_bps, 0);
+    assert_eq!(defaults.hand_ordering, HandOrdering::HighWins);
+    assert_eq!(defaults.hole_cards, 2);
+    assert!(defaults.require_attestation);
+    assert!(!defaults.require_reveal);
+
+    client.set_bet_limits(&admin, &10, &500);
+    client.set_limit_structure(&admin, &Some(LimitStructure { small_bet: 10, big_bet: 20 }));
+    client.set_ante(&admin, &5);
+    client.set_rake(&admin, &250, &Address::generate(&env));
+    client.set_hand_ordering(&admin, &HandOrdering::LowWins);
+    client.set_hole_cards(&admin, &4);
+    client.set_require_attestation(&admin, &false);
+    client.set_require_reveal(&admin, &true);
+
+    assert_eq!(client.get_table_rules(), TableRules {
+        min_bet: 10,
+        max_bet: 500,
+        small_bet: 10,
+        big_bet: 20,
+        ante: 5,
+        rake_bps: 250,
+        hand_ordering: HandOrdering::LowWins,
+        hole_cards: 4,
+        require_attestation: false,
+        require_reveal: true,
+    });
+
+    // New games pick up the same rules
+    let state = client.init_game(&table(&env), &Address::generate(&env), &Address::generate(&env), &1000);
+    assert_eq!((state.min_bet, state.max_bet, state.ante, state.hole_cards), (10, 500, 5, 4));
+    assert_eq!(state.hand_ordering, HandOrdering::LowWins);
+    assert!(!state.require_attestation);
+}
+
+#[test]
+fn test_zero_and_negative_bets_rejected() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PokerGameContract);
+    let client = PokerGameContractClient::new(&env, &contract_id);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    env.mock_all_auths();
+    client.init_game(&table(&env), &player1, &player2, &1000);
+
+    assert_eq!(client.try_place_bet(&table(&env), &player1, &0), Err(Ok(PokerError::InvalidAmount.into())));
+    assert_eq!(client.try_place_bet(&table(&env), &player1, &-500), Err(Ok(PokerError::InvalidAmount.into())));
+
+    let state = client.get_game_state(&table(&env));
+    assert_eq!(state.pot, 0);
+    assert_eq!(state.players.get(0).unwrap().chips, 1000);
+    assert_eq!(state.players.get(0).unwrap().current_bet, 0);
+}
+
+#[test]
+fn test_amount_to_call_after_uneven_bet() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PokerGameContract);
+    let client = PokerGameContractClient::new(&env, &contract_id);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    env.mock_all_auths();
+
+    client.init_game(&table(&env), &player1, &player2, &1000);
+    client.place_bet(&table(&env), &player1, &150);
+
+    assert_eq!(client.amount_to_call(&table(&env), &player2), 150);
+    assert_eq!(client.amount_to_call(&table(&env), &player1), 0);
+
+    client.place_bet(&table(&env), &player2, &150);
+    assert_eq!(client.amount_to_call(&table(&env), &player2), 0);
+    assert_eq!(
+        client.try_amount_to_call(&table(&env), &stranger).err(),
+        Some(Ok(PokerError::PlayerNotFound.into()))
+    );
+}
+
+fn setup_attested(env: &Env, min: u32) -> (PokerGameContractClient<'_>, test_helpers::attested::AttestedVerifierClient<'_>) {
+    let verifier_id = env.register_contract(None, test_helpers::attested::AttestedVerifier);
+    let contract_id = env.register_contract(None, PokerGameContract);
+    let client = PokerGameContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    env.mock_all_auths();
+
+    client.set_admin(&admin);
+    client.set_verifier(&verifier_id);
+    client.set_min_player_attestations(&admin, &min);
+    (client, test_helpers::attested::AttestedVerifierClient::new(env, &verifier_id))
+}
+
+#[test]
+fn test_player_below_attestation_threshold_is_rejected() {
+    let env = Env::default();
+    let (client, verifier) = setup_attested(&env, 3);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    verifier.set_count(&player1, &5);
+    verifier.set_count(&player2, &2);
+
+    assert_eq!(client.get_min_player_attestations(), 3);
+    assert_eq!(
+        client.try_init_game(&table(&env), &player1, &player2, &1000).err(),
+        Some(Ok(TableError::InsufficientAttestations.into()))
+    );
+}
+
+#[test]
+fn test_players_at_attestation_threshold_are_admitted() {
+    let env = Env::default();
+    let (client, verifier) = setup_attested(&env, 3);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    verifier.set_count(&player1, &3);
+    verifier.set_count(&player2, &7);
+
+    let state = client.init_game(&table(&env), &player1, &player2, &1000);
+    assert!(state.is_active);
+    assert_eq!(state.players.get(1).unwrap().address, player2);
+}
+
+#[test]
+fn test_attestation_threshold_requires_verifier() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, PokerGameContract);
+    let client = PokerGameContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    env.mock_all_auths();
+
+    client.set_admin(&admin);
+    client.set_min_player_attestations(&admin, &1);
+    assert_eq!(
+        client.try_init_game(&table(&env), &Address::generate(&env), &Address::generate(&env), &1000).err(),
+        Some(Ok(PokerError::VerifierNotConfigured.into()))
+    );
+}
+
+/// Helper: poker_game wired to a real noir_verifier, with a two-player
+/// hand bet and committed on `table`.
+fn setup_real_verifier(env: &Env) -> (PokerGameContractClient<'_>, noir_verifier::NoirVerifierClient<'_>, Address, Address) {
+    let verifier_id = env.register_contract(None, noir_verifier::NoirVerifier);
+    let contract_id = env.register_contract(None, PokerGameContract);
+    let client = PokerGameContractClient::new(env, &contract_id);
+    let verifier = noir_verifier::NoirVerifierClient::new(env, &verifier_id);
+    let player1 = Address::generate(env);
+    let player2 = Address::generate(env);
+    env.mock_all_auths();
+
+    let verifier_admin = Address::generate(env);
+    verifier.set_admin(&verifier_admin);
+    verifier.set_game_contract(&verifier_admin, &contract_id);
+    client.set_verifier(&verifier_id);
+    client.init_game(&table(env), &player1, &player2, &1000);
+    client.place_bet(&table(env), &player1, &100);
+    client.place_bet(&table(env), &player2, &100);
+    client.submit_commitment(&table(env), &player1, &make_sha256_commitment(env, [14, 13], [42u8; 32]));
+    client.submit_commitment(&table(env), &player2, &make_sha256_commitment(env, [7, 8], [99u8; 32]));
+    (client, verifier, player1, player2)
+}
+
+/// Helper: `player` records its own verified attestation for `proof_hash`.
+fn attest_self(env: &Env, verifier: &noir_verifier::NoirVerifierClient, player: &Address, proof_hash: u8, rank: u32) {
+    verifier.record_zkverify_attestation(
+        player,
+        &soroban_sdk::String::from_str(env, "self"),
+        &BytesN::from_array(env, &[proof_hash; 32]),
+        &verifier.player_id(player),
+        &table(env),
+        &rank,
+        &true,
+        &soroban_sdk::String::from_str(env, "0xabababababababababababababababababababababababababababababababab"),
+        &false,
+        &None,
+    );
+}
+
+/// Helper: 128-byte proof carrying `proof_hash` in its first 32 bytes.
+fn proof_with_hash(env: &Env, proof_hash: u8) -> BytesN<128> {
+    let mut proof = [0u8; 128];
+    proof[..32].copy_from_slice(&[proof_hash; 32]);
+    BytesN::from_array(env, &proof)
+}
+
+#[test]
+fn test_attestation_threshold_counts_real_verifier_records() {
+    let env = Env::default();
+    env.budget().reset_unlimited();
+    let verifier_id = env.register_contract(None, noir_verifier::NoirVerifier);
+    let contract_id = env.register_contract(None, PokerGameContract);
+    let client = PokerGameContractClient::new(&env, &contract_id);
+    let verifier = noir_verifier::NoirVerifierClient::new(&env, &verifier_id);
+    let admin = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    env.mock_all_auths();
+
+    client.set_admin(&admin);
+    client.set_verifier(&verifier_id);
+    client.set_min_player_attestations(&admin, &2);
+    attest_self(&env, &verifier, &player1, 1, 5);
+    attest_self(&env, &verifier, &player1, 2, 4);
+    attest_self(&env, &verifier, &player2, 3, 3);
+
+    assert_eq!(
+        client.try_init_game(&table(&env), &player1, &player2, &1000).err(),
+        Some(Ok(TableError::InsufficientAttestations.into()))
+    );
+
+    attest_self(&env, &verifier, &player2, 4, 6);
+    assert!(client.init_game(&table(&env), &player1, &player2, &1000).is_active);
+}
+
+#[test]
+fn test_self_recorded_attestations_verify_at_showdown() {
+    let env = Env::default();
+    env.budget().reset_unlimited();
+    let (client, verifier, player1, player2) = setup_real_verifier(&env);
+    attest_self(&env, &verifier, &player1, 1, 5);
+    attest_self(&env, &verifier, &player2, 2, 3);
+
+    let winner = client.resolve_showdown(
+        &table(&env),
+        &proof_with_hash(&env, 1), &5, &Bytes::from_array(&env, &[14u8, 13u8]), &BytesN::from_array(&env, &[42u8; 32]),
+        &proof_with_hash(&env, 2), &3, &Bytes::from_array(&env, &[7u8, 8u8]), &BytesN::from_array(&env, &[99u8; 32]),
+    );
+    assert_eq!(winner, player1);
+    assert!(verifier.is_proof_consumed(&BytesN::from_array(&env, &[1u8; 32])));
+}
+
+#[test]
+fn test_attestation_of_another_player_rejected_at_showdown() {
+    let env = Env::default();
+    env.budget().reset_unlimited();
+    let (client, verifier, player1, _player2) = setup_real_verifier(&env);
+    attest_self(&env, &verifier, &player1, 1, 5);
+
+    // Player 2 presents player 1's attested proof
+    let result = client.try_resolve_showdown(
+        &table(&env),
+        &proof_with_hash(&env, 1), &5, &Bytes::from_array(&env, &[14u8, 13u8]), &BytesN::from_array(&env, &[42u8; 32]),
+        &proof_with_hash(&env, 1), &5, &Bytes::from_array(&env, &[7u8, 8u8]), &BytesN::from_array(&env, &[99u8; 32]),
+    );
+    assert_eq!(result.err(), Some(Ok(noir_verifier::VerifierError::WrongPlayer.into())));
+    assert!(client.get_game_state(&table(&env)).is_active);
+}
+
+/// Mock noir_verifier for testing — always returns true
+mod test_helpers {
+    use soroban_sdk::{contract, contractimpl, Bytes, Env, BytesN};
+
+    #[contract]
+    pub struct MockNoirVerifier;
+
+    #[contractimpl]
+    impl MockNoirVerifier {
+        pub fn verify_proof(
+            _env: Env,
+            _hole_cards: BytesN<2>,
+            _salt: BytesN<32>,
+            _commitment: BytesN<32>,
+            _claimed_rank: u32,
+            _proof_bytes: BytesN<128>,
+            _player: BytesN<32>,
+            _game_id: BytesN<32>,
+        ) -> bool {
+            true
+        }
+
+        pub fn verify_and_get_rank(
+            _env: Env,
+            _hole_cards: BytesN<2>,
+            _salt: BytesN<32>,
+            _commitment: BytesN<32>,
+            claimed_rank: u32,
+            _proof_bytes: BytesN<128>,
+            _player: BytesN<32>,
+            _game_id: BytesN<32>,
+            _require_attestation: bool,
+        ) -> u32 {
+            claimed_rank
+        }
+
+        pub fn verify_proof_variant(
+            _env: Env,
+            hole_cards: Bytes,
+            expected_count: u32,
+            _salt: BytesN<32>,
+            _commitment: BytesN<32>,
+            _claimed_rank: u32,
+            _proof_bytes: BytesN<128>,
+            _player: BytesN<32>,
+            _game_id: BytesN<32>,
+            _require_attestation: bool,
+        ) -> bool {
+            hole_cards.len() == expected_count
+        }
+
+        pub fn verifier_version(_env: Env) -> u32 {
+            1
+        }
+
+        pub fn ping(_env: Env) -> u32 {
+            1
+        }
+    }
+
+    /// Verifier that confirms every claimed rank but always ranks the
+    /// second hand higher on kickers
+    pub mod kicker {
+        use soroban_sdk::{contract, contractimpl, Env, BytesN, Vec};
+
+        #[contract]
+        pub struct KickerVerifier;
+
+        #[contractimpl]
+        impl KickerVerifier {
+            pub fn verify_and_get_rank(
+                _env: Env,
+                _hole_cards: BytesN<2>,
+                _salt: BytesN<32>,
+                _commitment: BytesN<32>,
+                claimed_rank: u32,
+                _proof_bytes: BytesN<128>,
+                _player: BytesN<32>,
+                _game_id: BytesN<32>,
+                _require_attestation: bool,
+            ) -> u32 {
+                claimed_rank
+            }
+
+            pub fn compare_hands(_env: Env, _hole1: BytesN<2>, _hole2: BytesN<2>, _community: Vec<u32>) -> i32 {
+                -1
+            }
+        }
+    }
+
+    /// Verifier that reports a per-player attestation count set by the test
+    pub mod attested {
+        use soroban_sdk::{contract, contractimpl, Address, Env};
+
+        #[contract]
+        pub struct AttestedVerifier;
+
+        #[contractimpl]
+        impl AttestedVerifier {
+            pub fn set_count(env: Env, player: Address, count: u32) {
+                env.storage().instance().set(&player, &count);
+            }
+
+            pub fn get_player_attestation_count(env: Env, player: Address) -> u32 {
+                env.storage().instance().get(&player).unwrap_or(0)
+            }
+        }
+    }
+
+    /// Verifier that rejects any claim of a royal flush (rank 9) and
+    /// confirms every other rank
+    pub mod skeptical {
+        use soroban_sdk::{contract, contractimpl, panic_with_error, Env, BytesN, Error};
+
+        #[contract]
+        pub struct SkepticalVerifier;
+
+        #[contractimpl]
+        impl SkepticalVerifier {
+            pub fn verify_and_get_rank(
+                env: Env,
+                _hole_cards: BytesN<2>,
+                _salt: BytesN<32>,
+                _commitment: BytesN<32>,
+                claimed_rank: u32,
+                _proof_bytes: BytesN<128>,
+                _player: BytesN<32>,
+                _game_id: BytesN<32>,
+                _require_attestation: bool,
+            ) -> u32 {
+                if claimed_rank >= 9 {
+                    panic_with_error!(&env, Error::from_contract_error(7)); // VerificationFailed
+                }
+                claimed_rank
+            }
+        }
+    }
+
+    /// Verifier failing for a reason unrelated to the proof: reports the
+    /// proof as already consumed
+    pub mod consumed {
+        use soroban_sdk::{contract, contractimpl, panic_with_error, Env, BytesN, Error};
+
+        #[contract]
+        pub struct ConsumedVerifier;
+
+        #[contractimpl]
+        impl ConsumedVerifier {
+            pub fn verify_and_get_rank(
+                env: Env,
+                _hole_cards: BytesN<2>,
+                _salt: BytesN<32>,
+                _commitment: BytesN<32>,
+                _claimed_rank: u32,
+                _proof_bytes: BytesN<128>,
+                _player: BytesN<32>,
+                _game_id: BytesN<32>,
+                _require_attestation: bool,
+            ) -> u32 {
+                panic_with_error!(&env, Error::from_contract_error(28)) // ProofConsumed
+            }
+        }
+    }
+
+    /// Verifier reporting an interface version this build does not speak
+    pub mod incompatible {
+        use soroban_sdk::{contract, contractimpl, Env};
+
+        #[contract]
+        pub struct IncompatibleVerifier;
+
+        #[contractimpl]
+        impl IncompatibleVerifier {
+            pub fn verifier_version(_env: Env) -> u32 {
+                99
+            }
+        }
+    }
+
+    /// Verifier that accepts every proof and counts how often it was called
+    pub mod counting {
+        use soroban_sdk::{contract, contractimpl, symbol_short, Env, BytesN};
+
+        #[contract]
+        pub struct CountingVerifier;
+
+        #[contractimpl]
+        impl CountingVerifier {
+            pub fn verify_and_get_rank(
+                env: Env,
+                _hole_cards: BytesN<2>,
+                _salt: BytesN<32>,
+                _commitment: BytesN<32>,
+                claimed_rank: u32,
+                _proof_bytes: BytesN<128>,
+                _player: BytesN<32>,
+                _game_id: BytesN<32>,
+                _require_attestation: bool,
+            ) -> u32 {
+                let calls: u32 = env.storage().instance().get(&symbol_short!("CALLS")).unwrap_or(0);
+                env.storage().instance().set(&symbol_short!("CALLS"), &(calls + 1));
+                claimed_rank
+            }
+
+            pub fn calls(env: Env) -> u32 {
+                env.storage().instance().get(&symbol_short!("CALLS")).unwrap_or(0)
+            }
+        }
+    }
+
+    /// Verifier that reports a different rank than the one claimed
+    /// (`9 - claimed_rank`), to show the showdown trusts the verifier
+    pub mod overriding {
+        use soroban_sdk::{contract, contractimpl, Env, BytesN};
+
+        #[contract]
+        pub struct OverridingVerifier;
+
+        #[contractimpl]
+        impl OverridingVerifier {
+            pub fn verify_and_get_rank(
+                _env: Env,
+                _hole_cards: BytesN<2>,
+                _salt: BytesN<32>,
+                _commitment: BytesN<32>,
+                claimed_rank: u32,
+                _proof_bytes: BytesN<128>,
+                _player: BytesN<32>,
+                _game_id: BytesN<32>,
+                _require_attestation: bool,
+            ) -> u32 {
+                9 - claimed_rank
+            }
+        }
+    }
+
+    /// Malicious verifier that tries to re-enter poker_game.resolve_showdown
+    /// (own module — each contract needs a distinct `verify_proof` spec namespace)
+    pub mod reentrant {
+        use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, BytesN, Error, InvokeError};
+
+        #[contract]
+        pub struct ReentrantVerifier;
+
+        #[contractimpl]
+        impl ReentrantVerifier {
+            pub fn set_target(env: Env, target: Address, game_id: BytesN<32>) {
+                env.storage().instance().set(&symbol_short!("TARGET"), &(target, game_id));
+            }
+
+            pub fn verify_and_get_rank(
+                env: Env,
+                hole_cards: BytesN<2>,
+                salt: BytesN<32>,
+                _commitment: BytesN<32>,
+                claimed_rank: u32,
+                proof_bytes: BytesN<128>,
+                _player: BytesN<32>,
+                _game_id: BytesN<32>,
+                _require_attestation: bool,
+            ) -> u32 {
+                let (target, game_id): (Address, BytesN<32>) =
+                    env.storage().instance().get(&symbol_short!("TARGET")).unwrap();
+                let inner = crate::PokerGameContractClient::new(&env, &target).try_resolve_showdown(
+                    &game_id,
+                    &proof_bytes, &claimed_rank, &hole_cards.clone().into(), &salt,
+                    &proof_bytes, &claimed_rank, &hole_cards.into(), &salt,
+                );
+                // Surface the nested call's error to the test
+                // Stored as its raw payload: error values cannot be persisted
+                let inner: Option<u64> = inner.err().map(|e| match e {
+                    Ok(err) => err.to_val().get_payload(),
+                    Err(InvokeError::Contract(code)) => Error::from_contract_error(code).to_val().get_payload(),
+                    Err(InvokeError::Abort) => u64::MAX,
+                });
+                env.storage().instance().set(&symbol_short!("INNER"), &inner);
+                claimed_rank
+            }
+
+            pub fn inner_error(env: Env) -> Option<u64> {
+                env.storage().instance().get(&symbol_short!("INNER")).unwrap()
+            }
+        }
+    }
+
+    /// Verifier that has no attestations recorded: rejects every proof when
+    /// the caller requires attestation and accepts it otherwise
+    pub mod gated {
+        use soroban_sdk::{contract, contractimpl, Env, BytesN};
+
+        #[contract]
+        pub struct GatedVerifier;
+
+        #[contractimpl]
+        impl GatedVerifier {
+            pub fn verify_and_get_rank(
+                _env: Env,
+                _hole_cards: BytesN<2>,
+                _salt: BytesN<32>,
+                _commitment: BytesN<32>,
+                claimed_rank: u32,
+                _proof_bytes: BytesN<128>,
+                _player: BytesN<32>,
+                _game_id: BytesN<32>,
+                require_attestation: bool,
+            ) -> u32 {
+                assert!(!require_attestation, "No zkVerify attestation found");
+                claimed_rank
+            }
+        }
+    }
+
+    /// Verifier that rejects every variant proof
+    pub mod rejecting {
+        use soroban_sdk::{contract, contractimpl, Bytes, Env, BytesN};
+
+        #[contract]
+        pub struct RejectingVerifier;
+
+        #[contractimpl]
+        impl RejectingVerifier {
+            pub fn verify_proof_variant(
+                _env: Env,
+                _hole_cards: Bytes,
+                _expected_count: u32,
+                _salt: BytesN<32>,
+                _commitment: BytesN<32>,
+                _claimed_rank: u32,
+                _proof_bytes: BytesN<128>,
+                _player: BytesN<32>,
+                _game_id: BytesN<32>,
+                _require_attestation: bool,
+            ) -> bool {
+                false
+            }
         }
     }
 }